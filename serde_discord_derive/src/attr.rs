@@ -0,0 +1,99 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{meta::ParseNestedMeta, Attribute, Expr, ExprLit, ExprUnary, Lit, LitInt, LitStr, UnOp};
+
+/// The largest `min_length` and `max_length` Discord accepts for string options.
+const MAX_LENGTH: u16 = 6000;
+
+/// Returns the doc comment of an item as a single line, if it has one.
+pub(crate) fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta.require_name_value().ok()?.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(doc), ..
+            }) => Some(doc.value().trim().to_string()),
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}
+
+/// Parses the string value of `meta`, as in `name = "..."`.
+pub(crate) fn parse_string(meta: &ParseNestedMeta) -> syn::Result<String> {
+    Ok(meta.value()?.parse::<LitStr>()?.value())
+}
+
+/// Parses the numeric value of `meta` into a `NumericValue`, as in `min_value = -1.5`.
+pub(crate) fn parse_numeric_value(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let expr: Expr = meta.value()?.parse()?;
+    let lit = match &expr {
+        Expr::Lit(ExprLit { lit, .. }) => lit,
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => match inner.as_ref() {
+            Expr::Lit(ExprLit { lit, .. }) => lit,
+            _ => return Err(syn::Error::new_spanned(expr, "expected a number")),
+        },
+        _ => return Err(syn::Error::new_spanned(expr, "expected a number")),
+    };
+    match lit {
+        Lit::Int(int) if int.suffix().is_empty() => {
+            int.base10_parse::<i64>()?;
+            Ok(quote!(::serde_discord::register::NumericValue::Int(#expr)))
+        }
+        Lit::Float(float) if float.suffix().is_empty() => {
+            Ok(quote!(::serde_discord::register::NumericValue::Float(#expr)))
+        }
+        Lit::Int(_) | Lit::Float(_) => Err(unexpected_suffix(lit)),
+        _ => Err(syn::Error::new_spanned(expr, "expected a number")),
+    }
+}
+
+/// Parses the length value of `meta`, as in `max_length = 20`.
+pub(crate) fn parse_length(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let lit: LitInt = meta.value()?.parse()?;
+    if !lit.suffix().is_empty() {
+        return Err(unexpected_suffix(&Lit::Int(lit)));
+    }
+    let len = lit
+        .base10_parse::<u16>()
+        .ok()
+        .filter(|len| *len <= MAX_LENGTH)
+        .ok_or_else(|| syn::Error::new_spanned(&lit, "lengths must be between 0 and 6000"))?;
+    Ok(quote!(#len))
+}
+
+/// Returns the error for a numeric literal with a type suffix, such as `100u8`.
+///
+/// The type of the value is fixed by the option, so suffixes could only cause mismatches.
+fn unexpected_suffix(lit: &Lit) -> syn::Error {
+    syn::Error::new_spanned(
+        lit,
+        "numeric literals in `option` attributes must not have a type suffix",
+    )
+}
+
+/// Converts a `PascalCase` identifier to `snake_case`.
+pub(crate) fn to_snake_case(ident: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in ident.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
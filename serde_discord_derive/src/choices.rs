@@ -0,0 +1,114 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+use crate::attr::{parse_string, to_snake_case};
+
+/// The maximum length of choice names and string values.
+const MAX_CHOICE_LENGTH: usize = 100;
+
+/// Expands `#[derive(Choices)]` on `input`.
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "`Choices` can only be derived for enums",
+        ));
+    };
+
+    let mut names = Vec::new();
+    let mut values = Vec::new();
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`Choices` can only be derived for enums without fields",
+            ));
+        }
+        let mut name = variant.ident.to_string();
+        let mut value = to_snake_case(&name);
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("choice")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    name = parse_string(&meta)?;
+                } else if meta.path.is_ident("value") {
+                    value = parse_string(&meta)?;
+                } else {
+                    return Err(meta.error("unknown `choice` attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        // Validated here so the generated code can build the choices infallibly.
+        for (field, text) in [("name", &name), ("value", &value)] {
+            let len = text.chars().count();
+            if len == 0 || len > MAX_CHOICE_LENGTH {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    format!("choice {} must be between 1 and 100 characters", field),
+                ));
+            }
+        }
+        names.push(name);
+        values.push(value);
+        variants.push(&variant.ident);
+    }
+    if variants.len() > 25 {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "an option can have at most 25 choices",
+        ));
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::serde_discord::command::Choices for #ident #ty_generics #where_clause {
+            fn choices() -> ::std::vec::Vec<::serde_discord::register::Choice> {
+                ::std::vec![#(
+                    ::serde_discord::register::CommandOptionChoiceBuilder::new()
+                        .name(#names)
+                        .value(::serde_discord::register::ChoiceValue::String(
+                            ::std::string::String::from(#values),
+                        ))
+                        .build()
+                        .expect("choice names and values are validated by the derive macro"),
+                )*]
+            }
+
+            fn from_choice_value(value: &str) -> ::std::option::Option<Self> {
+                match value {
+                    #(#values => ::std::option::Option::Some(Self::#variants),)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn choice_value(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #values,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::serde_discord::command::OptionValue for #ident #ty_generics #where_clause {
+            const KIND: ::serde_discord::types::CommandOptionKind =
+                ::serde_discord::types::CommandOptionKind::String;
+
+            fn from_value(
+                value: &::serde_discord::types::MultiTypeValue,
+            ) -> ::std::option::Option<Self> {
+                match value {
+                    ::serde_discord::types::MultiTypeValue::String(value) => {
+                        <Self as ::serde_discord::command::Choices>::from_choice_value(value)
+                    }
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn choices() -> ::std::option::Option<::std::vec::Vec<::serde_discord::register::Choice>> {
+                ::std::option::Option::Some(<Self as ::serde_discord::command::Choices>::choices())
+            }
+        }
+    })
+}
@@ -0,0 +1,56 @@
+//! Derive macros for `serde_discord`.
+//!
+//! These macros are re-exported by `serde_discord` when its `derive` feature is enabled and
+//! should be used through it.
+
+mod attr;
+mod choices;
+mod slash_command;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `serde_discord::command::SlashCommand` for a struct with named fields.
+///
+/// The struct becomes a chat input command whose options are the fields of the struct.
+///
+/// # Attributes
+///
+/// On the struct, `#[command(...)]` accepts:
+/// - `name = "..."`: The name of the command. Defaults to the struct name in snake case.
+/// - `description = "..."`: The description of the command. Defaults to the doc comment.
+///
+/// On the fields, `#[option(...)]` accepts:
+/// - `name = "..."`: The name of the option. Defaults to the field name.
+/// - `description = "..."`: The description of the option. Defaults to the doc comment.
+/// - `min_value`, `max_value`: The range of numeric options.
+/// - `min_length`, `max_length`: The allowed length of string options.
+/// - `autocomplete`: Enables autocomplete for the option.
+///
+/// Fields can be of any type implementing `serde_discord::command::OptionValue`, including
+/// enums deriving [`Choices`](derive@Choices).
+#[proc_macro_derive(SlashCommand, attributes(command, option))]
+pub fn derive_slash_command(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    slash_command::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `serde_discord::command::Choices` for an enum without fields.
+///
+/// Each variant becomes a string choice, and the enum can be used as the type of a field of a
+/// struct deriving [`SlashCommand`](derive@SlashCommand).
+///
+/// # Attributes
+///
+/// On the variants, `#[choice(...)]` accepts:
+/// - `name = "..."`: The name shown to users. Defaults to the variant name.
+/// - `value = "..."`: The value sent with interactions. Defaults to the variant name in snake case.
+#[proc_macro_derive(Choices, attributes(choice))]
+pub fn derive_choices(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    choices::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,201 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Fields, GenericArgument, PathArguments, Type};
+
+use crate::attr::{doc_comment, parse_length, parse_numeric_value, parse_string, to_snake_case};
+
+/// Expands `#[derive(SlashCommand)]` on `input`.
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.iter().collect(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "`SlashCommand` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`SlashCommand` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut name = to_snake_case(&ident.to_string());
+    let mut description = doc_comment(&input.attrs);
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("command")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                name = parse_string(&meta)?;
+            } else if meta.path.is_ident("description") {
+                description = Some(parse_string(&meta)?);
+            } else {
+                return Err(meta.error("unknown `command` attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    let Some(description) = description else {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "missing command description, add a doc comment or `#[command(description = \"...\")]`",
+        ));
+    };
+
+    let options = fields
+        .iter()
+        .map(|field| Opt::parse(field))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let builders = options.iter().map(Opt::builder);
+    let extractors = options.iter().map(Opt::extractor);
+    let body = if matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Unit)) {
+        quote!(Self)
+    } else {
+        quote!(Self { #(#extractors,)* })
+    };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::serde_discord::command::SlashCommand for #ident #ty_generics #where_clause {
+            const NAME: &'static str = #name;
+
+            fn command() -> ::std::result::Result<
+                ::serde_discord::register::Command,
+                ::serde_discord::register::CommandError,
+            > {
+                ::serde_discord::register::CommandBuilder::new()
+                    .name(#name)
+                    .kind(::serde_discord::types::CommandKind::ChatInput)
+                    .description(#description)
+                    #(.option(#builders?))*
+                    .build()
+            }
+
+            fn from_options(
+                options: &::std::collections::HashMap<&str, &::serde_discord::types::MultiTypeValue>,
+            ) -> ::std::result::Result<Self, ::serde_discord::command::ExtractError> {
+                ::std::result::Result::Ok(#body)
+            }
+        }
+    })
+}
+
+/// A command option derived from a struct field.
+struct Opt<'a> {
+    field: &'a Field,
+    name: String,
+    description: String,
+    /// The type of the option value, without the `Option` wrapper of optional fields.
+    ty: &'a Type,
+    required: bool,
+    setters: Vec<TokenStream>,
+}
+
+impl<'a> Opt<'a> {
+    fn parse(field: &'a Field) -> syn::Result<Self> {
+        let ident = field.ident.as_ref().expect("fields are named");
+        let mut name = ident.to_string();
+        let mut description = doc_comment(&field.attrs);
+        let mut setters = Vec::new();
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("option")) {
+            attr.parse_nested_meta(|meta| {
+                let path = &meta.path;
+                if path.is_ident("name") {
+                    name = parse_string(&meta)?;
+                } else if path.is_ident("description") {
+                    description = Some(parse_string(&meta)?);
+                } else if path.is_ident("min_value") || path.is_ident("max_value") {
+                    let value = parse_numeric_value(&meta)?;
+                    setters.push(quote!(.#path(#value)));
+                } else if path.is_ident("min_length") || path.is_ident("max_length") {
+                    let value = parse_length(&meta)?;
+                    setters.push(quote!(.#path(#value)));
+                } else if path.is_ident("autocomplete") {
+                    setters.push(quote!(.autocomplete(true)));
+                } else {
+                    return Err(meta.error("unknown `option` attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        let Some(description) = description else {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "missing option description, add a doc comment or `#[option(description = \"...\")]`",
+            ));
+        };
+        let (ty, required) = match option_inner(&field.ty) {
+            Some(inner) => (inner, false),
+            None => (&field.ty, true),
+        };
+
+        Ok(Self {
+            field,
+            name,
+            description,
+            ty,
+            required,
+            setters,
+        })
+    }
+
+    /// Returns an expression building the `CommandOption` for registration.
+    fn builder(&self) -> TokenStream {
+        let Self {
+            name,
+            description,
+            ty,
+            setters,
+            ..
+        } = self;
+        let required = self.required.then(|| quote!(.required(true)));
+        quote! {{
+            let builder = ::serde_discord::register::CommandOptionBuilder::new()
+                .kind(<#ty as ::serde_discord::command::OptionValue>::KIND)
+                .name(#name)
+                .description(#description)
+                #required
+                #(#setters)*;
+            match <#ty as ::serde_discord::command::OptionValue>::choices() {
+                ::std::option::Option::Some(choices) => builder.choices(choices),
+                ::std::option::Option::None => builder,
+            }
+            .build()
+        }}
+    }
+
+    /// Returns a field initializer extracting the option value from `options`.
+    fn extractor(&self) -> TokenStream {
+        let ident = &self.field.ident;
+        let name = &self.name;
+        let ty = self.ty;
+        if self.required {
+            quote!(#ident: ::serde_discord::command::required_option::<#ty>(options, #name)?)
+        } else {
+            quote!(#ident: ::serde_discord::command::optional_option::<#ty>(options, #name)?)
+        }
+    }
+}
+
+/// Returns `T` if `ty` is `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,209 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use crate::{
+    interaction::CommandInteractionData,
+    register::{Choice, Command, CommandError},
+    types::{CommandOptionKind, MultiTypeValue},
+};
+pub use serde_discord_derive::{Choices, SlashCommand};
+
+/// A command defined once for both registration and interaction handling.
+///
+/// This trait is usually implemented with `#[derive(SlashCommand)]` on a struct whose
+/// fields are the options of the command. Fields of type `Option<T>` are optional, and
+/// every other field is required.
+///
+/// # Example
+///
+/// ```rust
+/// use serde_discord::{command::SlashCommand, interaction::CommandInteractionData};
+///
+/// #[derive(SlashCommand)]
+/// #[command(name = "roll", description = "Roll a die")]
+/// struct Roll {
+///     /// The number of sides of the die
+///     #[option(min_value = 2, max_value = 100)]
+///     sides: i64,
+///     /// How many dice to roll
+///     count: Option<i64>,
+/// }
+///
+/// // Registration
+/// let cmd = Roll::command().unwrap();
+/// assert_eq!(cmd.options().unwrap().len(), 2);
+///
+/// // Interaction handling
+/// let command_data: CommandInteractionData = serde_json::from_str(
+///     r#"{"name": "roll", "type": 1, "options": [{"name": "sides", "type": 4, "value": 20}]}"#,
+/// )
+/// .unwrap();
+/// let roll = Roll::from_data(&command_data).unwrap();
+/// assert_eq!(roll.sides, 20);
+/// assert_eq!(roll.count, None);
+/// ```
+pub trait SlashCommand: Sized {
+    /// The name of the command, used to route interactions to the matching type.
+    const NAME: &'static str;
+
+    /// Builds the definition of the command for registration.
+    ///
+    /// # Errors
+    /// Returns an error if the definition does not satisfy Discord's rules.
+    fn command() -> Result<Command, CommandError>;
+
+    /// Extracts the command from the option values of an interaction, keyed by name.
+    ///
+    /// # Errors
+    /// Returns an error if a required option is missing or a value has the wrong type.
+    fn from_options(options: &HashMap<&str, &MultiTypeValue>) -> Result<Self, ExtractError>;
+
+    /// Extracts the command from the data of a command interaction.
+    ///
+    /// # Errors
+    /// Returns an error if a required option is missing or a value has the wrong type.
+    fn from_data(data: &CommandInteractionData) -> Result<Self, ExtractError> {
+        Self::from_options(&data.options_map())
+    }
+}
+
+/// A type that can be used as the value of a command option.
+///
+/// The option type registered for a field is taken from `KIND`, and incoming values are
+/// converted with `from_value`.
+pub trait OptionValue: Sized {
+    /// The type of option registered for values of this type.
+    const KIND: CommandOptionKind;
+
+    /// Converts the value of an option, returning `None` if it has the wrong type.
+    fn from_value(value: &MultiTypeValue) -> Option<Self>;
+
+    /// Returns the fixed choices users can pick from, if the values are restricted.
+    fn choices() -> Option<Vec<Choice>> {
+        None
+    }
+}
+
+impl OptionValue for String {
+    const KIND: CommandOptionKind = CommandOptionKind::String;
+
+    fn from_value(value: &MultiTypeValue) -> Option<Self> {
+        value.as_str().map(str::to_owned)
+    }
+}
+
+impl OptionValue for i64 {
+    const KIND: CommandOptionKind = CommandOptionKind::Integer;
+
+    fn from_value(value: &MultiTypeValue) -> Option<Self> {
+        value.as_i64()
+    }
+}
+
+impl OptionValue for f64 {
+    const KIND: CommandOptionKind = CommandOptionKind::Number;
+
+    fn from_value(value: &MultiTypeValue) -> Option<Self> {
+        value.as_f64()
+    }
+}
+
+impl OptionValue for bool {
+    const KIND: CommandOptionKind = CommandOptionKind::Boolean;
+
+    fn from_value(value: &MultiTypeValue) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+/// A fixed set of string choices for a command option, mapped to a Rust enum.
+///
+/// This trait is usually implemented with `#[derive(Choices)]` on an enum without fields,
+/// which also implements [`OptionValue`] so the enum can be used as a field of a
+/// [`SlashCommand`].
+///
+/// # Example
+///
+/// ```rust
+/// use serde_discord::{command::Choices, register::ChoiceValue};
+///
+/// #[derive(Choices)]
+/// enum Coin {
+///     Heads,
+///     #[choice(name = "Tails", value = "t")]
+///     Tails,
+/// }
+///
+/// let choices = Coin::choices();
+/// assert_eq!(choices[1].name(), "Tails");
+/// assert!(matches!(choices[1].value(), ChoiceValue::String(value) if value == "t"));
+/// assert!(matches!(Coin::from_choice_value("heads"), Some(Coin::Heads)));
+/// assert_eq!(Coin::Tails.choice_value(), "t");
+/// ```
+pub trait Choices: Sized {
+    /// Returns the choices for registration, one for each variant.
+    fn choices() -> Vec<Choice>;
+
+    /// Returns the variant whose choice has the given value, if any.
+    fn from_choice_value(value: &str) -> Option<Self>;
+
+    /// Returns the value of the choice of this variant.
+    fn choice_value(&self) -> &'static str;
+}
+
+/// Represents an error encountered while extracting a command from an interaction.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ExtractError {
+    /// The required option `name` was not provided.
+    MissingOption { name: String },
+    /// The value of the option `name` does not have the expected type.
+    InvalidType {
+        name: String,
+        expected: CommandOptionKind,
+    },
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtractError::MissingOption { name } => write!(f, "missing option {:?}", name),
+            ExtractError::InvalidType { name, expected } => {
+                write!(f, "option {:?} is not of type {:?}", name, expected)
+            }
+        }
+    }
+}
+
+impl Error for ExtractError {}
+
+/// Returns the value of the required option `name`, converted to `T`.
+///
+/// # Errors
+/// Returns an error if the option is missing or its value cannot be converted.
+pub fn required_option<T: OptionValue>(
+    options: &HashMap<&str, &MultiTypeValue>,
+    name: &str,
+) -> Result<T, ExtractError> {
+    optional_option(options, name)?.ok_or_else(|| ExtractError::MissingOption {
+        name: name.to_string(),
+    })
+}
+
+/// Returns the value of the optional option `name`, converted to `T`, if it was provided.
+///
+/// # Errors
+/// Returns an error if the value cannot be converted.
+pub fn optional_option<T: OptionValue>(
+    options: &HashMap<&str, &MultiTypeValue>,
+    name: &str,
+) -> Result<Option<T>, ExtractError> {
+    match options.get(name) {
+        Some(value) => T::from_value(value)
+            .map(Some)
+            .ok_or_else(|| ExtractError::InvalidType {
+                name: name.to_string(),
+                expected: T::KIND,
+            }),
+        None => Ok(None),
+    }
+}
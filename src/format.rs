@@ -0,0 +1,29 @@
+use crate::types::Snowflake;
+
+/// Returns the markdown that renders as a clickable mention of a chat input command.
+///
+/// Commands with subcommands can only be mentioned through one of their subcommands, so
+/// `subcommands` holds the path to it: the name of the subcommand, preceded by the name of
+/// its group if it has one.
+///
+/// # Example
+///
+/// ```rust
+/// use serde_discord::{format::command_mention, types::Snowflake};
+///
+/// let id = Snowflake::new(123);
+/// assert_eq!(command_mention(id, "roll", &[]), "</roll:123>");
+/// assert_eq!(
+///     command_mention(id, "config", &["user", "reset"]),
+///     "</config user reset:123>"
+/// );
+/// ```
+pub fn command_mention(id: Snowflake, name: &str, subcommands: &[&str]) -> String {
+    let mut mention = format!("</{}", name);
+    for subcommand in subcommands {
+        mention.push(' ');
+        mention.push_str(subcommand);
+    }
+    mention.push_str(&format!(":{}>", id));
+    mention
+}
@@ -0,0 +1,518 @@
+#[cfg(feature = "blocking")]
+mod blocking;
+mod error;
+
+#[cfg(feature = "blocking")]
+pub use blocking::*;
+pub use error::*;
+
+use std::time::Duration;
+
+use reqwest::{
+    header::{HeaderMap, USER_AGENT},
+    Certificate, Proxy, RequestBuilder, Response, StatusCode, Url,
+};
+
+use tracing::{debug, warn};
+
+use crate::DISCORD_API_VERSION;
+
+/// The default base URL of the Discord API, without the version.
+pub const DEFAULT_BASE_URL: &str = "https://discord.com/api";
+/// The default `User-Agent` of requests, in the format Discord asks libraries to use.
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "DiscordBot (https://github.com/rmarinn/serde_discord, ",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+/// The default number of times a rate limited request is retried.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// The default longest delay the client is willing to wait before retrying a request.
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Controls how requests that hit Discord's rate limits are retried.
+///
+/// When Discord responds with `429 Too Many Requests`, the client waits for the delay
+/// indicated by the `Retry-After` or `X-RateLimit-Reset-After` header and sends the request
+/// again, up to `max_retries` times. Delays longer than `max_delay` are not waited out, and
+/// the rate limited response is returned instead.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use serde_discord::http::RetryPolicy;
+///
+/// let policy = RetryPolicy::new()
+///     .max_retries(5)
+///     .max_delay(Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a new `RetryPolicy` that retries up to 3 times, waiting at most 60 seconds.
+    pub fn new() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            max_delay: DEFAULT_MAX_DELAY,
+        }
+    }
+
+    /// Creates a `RetryPolicy` that never retries rate limited requests.
+    pub fn never() -> Self {
+        Self::new().max_retries(0)
+    }
+
+    /// Sets the maximum number of times a rate limited request is retried.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the longest delay to wait before retrying a request.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Returns the maximum number of times a rate limited request is retried.
+    pub fn get_max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the longest delay to wait before retrying a request.
+    pub fn get_max_delay(&self) -> Duration {
+        self.max_delay
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An HTTP client for the Discord API that transparently retries rate limited requests.
+///
+/// Cloning an `HttpClient` is cheap, and clones share the same connection pool. To reuse the
+/// connection pool, proxies, or TLS configuration of an existing `reqwest::Client`, pass it
+/// to [`HttpClientBuilder::client`] or convert it with `HttpClient::from`.
+///
+/// # Example
+///
+/// ```rust
+/// use serde_discord::http::HttpClient;
+///
+/// let client = reqwest::Client::new();
+/// let http = HttpClient::from(client.clone());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    client: reqwest::Client,
+    config: ClientConfig,
+}
+
+/// The settings shared by [`HttpClient`] and, with the `blocking` feature, the blocking
+/// client, both built by [`HttpClientBuilder`].
+#[derive(Debug, Clone)]
+pub(crate) struct ClientConfig {
+    base_url: String,
+    api_version: String,
+    user_agent: String,
+    timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+}
+
+impl ClientConfig {
+    /// Returns the full URL of the API endpoint at `path`.
+    pub(crate) fn url(&self, path: &str) -> String {
+        api_url(&self.base_url, &self.api_version, path)
+    }
+
+    /// Returns how long to wait before retrying a request that got a response with `status`
+    /// and `headers`, after `retries` retries. Returns `None` if the response is not rate
+    /// limited, if the retries are exhausted, or if the delay is longer than allowed.
+    pub(crate) fn retry_delay(
+        &self,
+        status: StatusCode,
+        headers: &HeaderMap,
+        retries: u32,
+    ) -> Option<Duration> {
+        if status != StatusCode::TOO_MANY_REQUESTS || retries >= self.retry_policy.max_retries {
+            return None;
+        }
+        retry_after(headers).filter(|delay| *delay <= self.retry_policy.max_delay)
+    }
+}
+
+impl HttpClient {
+    /// Creates a new `HttpClient` with the default retry policy.
+    ///
+    /// The underlying `reqwest::Client` uses the proxies configured in the environment, such
+    /// as `HTTPS_PROXY`. Use [`HttpClientBuilder::proxy`] to configure one explicitly.
+    pub fn new() -> Self {
+        HttpClientBuilder::new().with_client(reqwest::Client::new())
+    }
+
+    /// Returns the base URL of the API, without the version.
+    pub fn base_url(&self) -> &str {
+        &self.config.base_url
+    }
+
+    /// Returns the version of the API requests are sent to.
+    pub fn api_version(&self) -> &str {
+        &self.config.api_version
+    }
+
+    /// Returns the `User-Agent` sent with every request.
+    pub fn user_agent(&self) -> &str {
+        &self.config.user_agent
+    }
+
+    /// Returns the timeout applied to every request, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.config.timeout
+    }
+
+    /// Returns the full URL of the API endpoint at `path`.
+    pub(crate) fn url(&self, path: &str) -> String {
+        self.config.url(path)
+    }
+
+    /// Returns the retry policy used by the client.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.config.retry_policy
+    }
+
+    /// Returns the underlying `reqwest::Client`.
+    pub fn inner(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Sends `request`, retrying it according to the retry policy while it is rate limited.
+    ///
+    /// If the retries are exhausted, the last rate limited response is returned.
+    pub(crate) async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let mut request = request.header(USER_AGENT, &self.config.user_agent);
+        if let Some(timeout) = self.config.timeout {
+            request = request.timeout(timeout);
+        }
+        let mut retries = 0;
+        loop {
+            // Requests with streaming bodies cannot be retried.
+            let attempt = match request.try_clone() {
+                Some(attempt) => attempt.build()?,
+                None => return request.send().await,
+            };
+            debug!(
+                method = %attempt.method(),
+                route = %redacted_route(attempt.url()),
+                "sending request"
+            );
+            let response = self.client.execute(attempt).await?;
+            debug!(status = %response.status(), "received response");
+            let Some(delay) =
+                self.config
+                    .retry_delay(response.status(), response.headers(), retries)
+            else {
+                return Ok(response);
+            };
+            retries += 1;
+            warn!(?delay, retry = retries, "rate limited, retrying request");
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<reqwest::Client> for HttpClient {
+    /// Wraps an existing `reqwest::Client`, using the default retry policy.
+    fn from(client: reqwest::Client) -> Self {
+        HttpClientBuilder::new().with_client(client)
+    }
+}
+
+/// A builder for `HttpClient`, allowing construction with optional fields.
+pub struct HttpClientBuilder {
+    client: Option<reqwest::Client>,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    base_url: String,
+    api_version: String,
+    user_agent: String,
+    timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+}
+
+impl HttpClientBuilder {
+    /// Creates a new instance of the `HttpClientBuilder`.
+    pub fn new() -> Self {
+        Self {
+            client: None,
+            proxies: Vec::new(),
+            root_certificates: Vec::new(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_version: DISCORD_API_VERSION.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            timeout: None,
+            retry_policy: RetryPolicy::new(),
+        }
+    }
+
+    /// Sets the `User-Agent` sent with every request.
+    ///
+    /// Defaults to [`DEFAULT_USER_AGENT`]. Discord asks bots to identify themselves with a
+    /// value in the format `DiscordBot ($url, $version)`. An invalid header value makes
+    /// every request fail.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Sets the timeout applied to every request, from sending it until the response body
+    /// has been read.
+    ///
+    /// Unlike timeouts configured on a `reqwest::Client`, this also applies when a client is
+    /// set with [`HttpClientBuilder::client`]. Each retry of a rate limited request gets the
+    /// full timeout. By default, requests don't time out.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the base URL of the API, without the version.
+    ///
+    /// Defaults to [`DEFAULT_BASE_URL`]. This is useful to send requests to a mock server or
+    /// through a proxy that mirrors the Discord API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the version of the API requests are sent to, such as `"v10"`.
+    ///
+    /// Defaults to [`DISCORD_API_VERSION`].
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+
+    /// Sets the `reqwest::Client` used to send requests.
+    ///
+    /// If not set, a new client is created with the configured proxies and root certificates.
+    /// These are ignored when a client is set, since it already carries its own configuration.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Adds a proxy to route requests through.
+    ///
+    /// Setting a proxy disables the proxies configured in the environment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serde_discord::http::HttpClientBuilder;
+    ///
+    /// let http = HttpClientBuilder::new()
+    ///     .proxy(reqwest::Proxy::https("http://proxy.corp.example:8080").unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Adds a trusted root certificate, such as the one of a TLS-intercepting proxy.
+    pub fn root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Sets the policy for retrying rate limited requests.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Builds and returns the `HttpClient`.
+    ///
+    /// # Errors
+    /// Returns an error if no client was set and the `reqwest::Client` cannot be created,
+    /// e.g. because the TLS backend fails to initialize.
+    pub fn build(mut self) -> Result<HttpClient, reqwest::Error> {
+        let client = match self.client.take() {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder();
+                for proxy in self.proxies.drain(..) {
+                    builder = builder.proxy(proxy);
+                }
+                for certificate in self.root_certificates.drain(..) {
+                    builder = builder.add_root_certificate(certificate);
+                }
+                builder.build()?
+            }
+        };
+        Ok(self.with_client(client))
+    }
+
+    /// Builds and returns a `BlockingHttpClient` with the same settings.
+    ///
+    /// The client set with [`HttpClientBuilder::client`] is async and thus ignored. The
+    /// blocking client is created with the configured proxies and root certificates instead.
+    ///
+    /// # Errors
+    /// Returns an error if the `reqwest::blocking::Client` cannot be created, e.g. because
+    /// the TLS backend fails to initialize.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(mut self) -> Result<BlockingHttpClient, reqwest::Error> {
+        let mut builder = reqwest::blocking::Client::builder();
+        for proxy in self.proxies.drain(..) {
+            builder = builder.proxy(proxy);
+        }
+        for certificate in self.root_certificates.drain(..) {
+            builder = builder.add_root_certificate(certificate);
+        }
+        Ok(BlockingHttpClient::new_with(
+            builder.build()?,
+            self.into_config(),
+        ))
+    }
+
+    /// Returns the `HttpClient` sending requests with `client`.
+    fn with_client(self, client: reqwest::Client) -> HttpClient {
+        HttpClient {
+            client,
+            config: self.into_config(),
+        }
+    }
+
+    /// Returns the settings shared by the async and blocking clients.
+    fn into_config(self) -> ClientConfig {
+        ClientConfig {
+            base_url: self.base_url.trim_end_matches('/').to_string(),
+            api_version: self.api_version,
+            user_agent: self.user_agent,
+            timeout: self.timeout,
+            retry_policy: self.retry_policy,
+        }
+    }
+}
+
+impl Default for HttpClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the full URL of the API endpoint at `path`.
+pub(crate) fn api_url(base_url: &str, api_version: &str, path: &str) -> String {
+    format!(
+        "{}/{}/{}",
+        base_url,
+        api_version,
+        path.trim_start_matches('/')
+    )
+}
+
+/// Returns the path of `url` with IDs and tokens replaced by placeholders, so that it can be
+/// logged without leaking the interaction tokens embedded in webhook and callback URLs.
+///
+/// The query and the segment following `webhooks/{id}` or `interactions/{id}` are dropped or
+/// replaced, since they may hold credentials.
+pub(crate) fn redacted_route(url: &Url) -> String {
+    let segments: Vec<&str> = url.path_segments().into_iter().flatten().collect();
+    let mut route = String::new();
+    for (i, segment) in segments.iter().enumerate() {
+        let parent = i.checked_sub(2).map(|parent| segments[parent]);
+        let segment = if matches!(parent, Some("webhooks" | "interactions")) {
+            "{token}"
+        } else if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+            "{id}"
+        } else {
+            segment
+        };
+        route.push('/');
+        route.push_str(segment);
+    }
+    route
+}
+
+/// Returns how long to wait before retrying a rate limited request.
+///
+/// Discord sends the delay in seconds in the `Retry-After` header and, with millisecond
+/// precision, in the `X-RateLimit-Reset-After` header. Values that are negative, not finite,
+/// or too large for a `Duration` are ignored.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    ["x-ratelimit-reset-after", "retry-after"]
+        .iter()
+        .filter_map(|name| headers.get(*name)?.to_str().ok()?.parse::<f64>().ok())
+        .find_map(|secs| Duration::try_from_secs_f64(secs).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (*name, HeaderValue::from_static(value)))
+            .map(|(name, value)| (name.parse().unwrap(), value))
+            .collect()
+    }
+
+    #[test]
+    fn retry_after_prefers_precise_header() {
+        let headers = headers(&[("retry-after", "2"), ("x-ratelimit-reset-after", "1.5")]);
+        assert_eq!(retry_after(&headers), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn retry_after_ignores_unrepresentable_delays() {
+        for value in ["1e300", "-1", "NaN", "inf", "soon"] {
+            let headers = headers(&[("retry-after", value)]);
+            assert_eq!(retry_after(&headers), None, "{}", value);
+        }
+        let headers = headers(&[("x-ratelimit-reset-after", "1e300"), ("retry-after", "3")]);
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+    }
+
+    fn route(url: &str) -> String {
+        redacted_route(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn redacted_route_hides_tokens_and_ids() {
+        assert_eq!(
+            route("https://discord.com/api/v10/interactions/123/aW50ZXJhY3Rpb24/callback?with_response=true"),
+            "/api/v10/interactions/{id}/{token}/callback"
+        );
+        assert_eq!(
+            route("https://discord.com/api/v10/webhooks/456/aW50ZXJhY3Rpb24/messages/@original"),
+            "/api/v10/webhooks/{id}/{token}/messages/@original"
+        );
+        assert_eq!(
+            route("https://discord.com/api/v10/webhooks/456/aW50ZXJhY3Rpb24?wait=true"),
+            "/api/v10/webhooks/{id}/{token}"
+        );
+        assert_eq!(
+            route("https://discord.com/api/v10/applications/456/guilds/789/commands"),
+            "/api/v10/applications/{id}/guilds/{id}/commands"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use std::thread;
+
+use reqwest::{
+    blocking::{Client, RequestBuilder, Response},
+    header::USER_AGENT,
+};
+
+use tracing::{debug, warn};
+
+use super::{redacted_route, ClientConfig, HttpClientBuilder, RetryPolicy};
+
+/// A blocking HTTP client for the Discord API that transparently retries rate limited
+/// requests.
+///
+/// It is built by [`HttpClientBuilder::build_blocking`] and shares the settings of
+/// [`HttpClient`](super::HttpClient): the base URL, API version, `User-Agent`, timeout, and
+/// retry policy. It must not be used from within an async runtime.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use serde_discord::http::{HttpClientBuilder, RetryPolicy};
+///
+/// let http = HttpClientBuilder::new()
+///     .timeout(Duration::from_secs(10))
+///     .retry_policy(RetryPolicy::never())
+///     .build_blocking()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct BlockingHttpClient {
+    client: Client,
+    config: ClientConfig,
+}
+
+impl BlockingHttpClient {
+    /// Creates a new `BlockingHttpClient` with the default settings.
+    ///
+    /// # Panics
+    /// Panics if the underlying `reqwest::blocking::Client` cannot be created, like
+    /// `reqwest::blocking::Client::new`.
+    pub fn new() -> Self {
+        HttpClientBuilder::new()
+            .build_blocking()
+            .expect("failed to create the blocking HTTP client")
+    }
+
+    /// Returns the client sending requests with `client` and the given settings.
+    pub(super) fn new_with(client: Client, config: ClientConfig) -> Self {
+        Self { client, config }
+    }
+
+    /// Returns the base URL of the API, without the version.
+    pub fn base_url(&self) -> &str {
+        &self.config.base_url
+    }
+
+    /// Returns the version of the API requests are sent to.
+    pub fn api_version(&self) -> &str {
+        &self.config.api_version
+    }
+
+    /// Returns the `User-Agent` sent with every request.
+    pub fn user_agent(&self) -> &str {
+        &self.config.user_agent
+    }
+
+    /// Returns the timeout applied to every request, if any.
+    pub fn timeout(&self) -> Option<std::time::Duration> {
+        self.config.timeout
+    }
+
+    /// Returns the retry policy used by the client.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.config.retry_policy
+    }
+
+    /// Returns the underlying `reqwest::blocking::Client`.
+    pub fn inner(&self) -> &Client {
+        &self.client
+    }
+
+    /// Returns the full URL of the API endpoint at `path`.
+    pub(crate) fn url(&self, path: &str) -> String {
+        self.config.url(path)
+    }
+
+    /// Sends `request`, retrying it according to the retry policy while it is rate limited.
+    ///
+    /// If the retries are exhausted, the last rate limited response is returned.
+    pub(crate) fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let mut request = request.header(USER_AGENT, &self.config.user_agent);
+        if let Some(timeout) = self.config.timeout {
+            request = request.timeout(timeout);
+        }
+        let mut retries = 0;
+        loop {
+            // Requests with streaming bodies cannot be retried.
+            let attempt = match request.try_clone() {
+                Some(attempt) => attempt.build()?,
+                None => return request.send(),
+            };
+            debug!(
+                method = %attempt.method(),
+                route = %redacted_route(attempt.url()),
+                "sending request"
+            );
+            let response = self.client.execute(attempt)?;
+            debug!(status = %response.status(), "received response");
+            let Some(delay) =
+                self.config
+                    .retry_delay(response.status(), response.headers(), retries)
+            else {
+                return Ok(response);
+            };
+            retries += 1;
+            warn!(?delay, retry = retries, "rate limited, retrying request");
+            thread::sleep(delay);
+        }
+    }
+}
+
+impl Default for BlockingHttpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
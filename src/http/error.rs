@@ -0,0 +1,209 @@
+use std::{fmt, time::Duration};
+
+use reqwest::{header::HeaderMap, Response, StatusCode};
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::warn;
+
+use super::retry_after;
+
+/// The JSON error code Discord uses for request bodies that fail validation.
+pub const INVALID_FORM_BODY: u32 = 50035;
+
+/// Represents an error response returned by the Discord API.
+///
+/// # Fields
+/// - `status`: The HTTP status code of the response.
+/// - `code`: Discord's JSON error code, if the body could be parsed.
+/// - `message`: Discord's error message, or the raw response body if it could not be parsed.
+/// - `field_errors`: The errors reported for individual fields of the request body.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ApiError {
+    status: StatusCode,
+    code: Option<u32>,
+    message: String,
+    field_errors: Vec<FieldError>,
+}
+
+/// The JSON body of a Discord error response.
+#[derive(Deserialize)]
+struct ErrorBody {
+    code: u32,
+    message: String,
+    #[serde(default)]
+    errors: Value,
+}
+
+impl ApiError {
+    /// Creates an `ApiError` from the status and body of an error response.
+    pub(crate) fn from_body(status: StatusCode, body: String) -> Self {
+        match serde_json::from_str::<ErrorBody>(&body) {
+            Ok(parsed) => {
+                let mut field_errors = Vec::new();
+                collect_field_errors(&parsed.errors, String::new(), &mut field_errors);
+                Self {
+                    status,
+                    code: Some(parsed.code),
+                    message: parsed.message,
+                    field_errors,
+                }
+            }
+            Err(_) => Self {
+                status,
+                code: None,
+                message: body,
+                field_errors: Vec::new(),
+            },
+        }
+    }
+
+    /// Returns the HTTP status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns Discord's JSON error code, if the response body could be parsed.
+    pub fn code(&self) -> Option<u32> {
+        self.code
+    }
+
+    /// Returns Discord's error message, or the raw response body if it could not be parsed.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the errors reported for individual fields of the request body.
+    pub fn field_errors(&self) -> &[FieldError] {
+        &self.field_errors
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Discord responded with {}", self.status)?;
+        if let Some(code) = self.code {
+            write!(f, " (code {})", code)?;
+        }
+        write!(f, ": {}", self.message)?;
+        for err in &self.field_errors {
+            write!(f, "\n  {}: {}", err.path, err.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Represents an error Discord reported for a single field of a request body.
+///
+/// # Fields
+/// - `path`: The dot-separated path to the field, such as `0.options.1.name`.
+/// - `code`: Discord's code for the error, such as `BASE_TYPE_MAX_LENGTH`.
+/// - `message`: A human-readable description of the error.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct FieldError {
+    path: String,
+    code: String,
+    message: String,
+}
+
+impl FieldError {
+    /// Returns the dot-separated path to the field.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns Discord's code for the error.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns a human-readable description of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Flattens Discord's nested `errors` object into a list of field errors.
+///
+/// Each level of the object is keyed by a field name or array index, and the errors for a
+/// field are listed under its `_errors` key.
+fn collect_field_errors(value: &Value, path: String, out: &mut Vec<FieldError>) {
+    let Value::Object(map) = value else {
+        return;
+    };
+    for (key, value) in map {
+        if key == "_errors" {
+            for err in value.as_array().into_iter().flatten() {
+                out.push(FieldError {
+                    path: path.clone(),
+                    code: err["code"].as_str().unwrap_or_default().to_string(),
+                    message: err["message"].as_str().unwrap_or_default().to_string(),
+                });
+            }
+        } else if path.is_empty() {
+            collect_field_errors(value, key.clone(), out);
+        } else {
+            collect_field_errors(value, format!("{}.{}", path, key), out);
+        }
+    }
+}
+
+/// The ways in which a response can fail, shared by the error types of the modules that
+/// send requests.
+pub(crate) enum StatusError {
+    /// Discord kept rate limiting the request after all retries were used.
+    RateLimited { retry_after: Option<Duration> },
+    /// Discord responded with a non-success status code.
+    Api(ApiError),
+    /// The body of the error response could not be read.
+    Http(reqwest::Error),
+}
+
+/// Returns `response` if it has a success status, or the error it describes otherwise.
+pub(crate) async fn error_for_status(response: Response) -> Result<Response, StatusError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    if let Some(err) = rate_limited(status, response.headers()) {
+        return Err(err);
+    }
+    let body = response.text().await.map_err(StatusError::Http)?;
+    Err(rejected(status, body))
+}
+
+/// Returns `response` if it has a success status, or the error it describes otherwise.
+///
+/// This is the blocking counterpart of `error_for_status`.
+#[cfg(feature = "blocking")]
+pub(crate) fn blocking_error_for_status(
+    response: reqwest::blocking::Response,
+) -> Result<reqwest::blocking::Response, StatusError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    if let Some(err) = rate_limited(status, response.headers()) {
+        return Err(err);
+    }
+    let body = response.text().map_err(StatusError::Http)?;
+    Err(rejected(status, body))
+}
+
+/// Returns the error for a response that is still rate limited, if it is.
+fn rate_limited(status: StatusCode, headers: &HeaderMap) -> Option<StatusError> {
+    if status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    let retry_after = retry_after(headers);
+    warn!(?retry_after, "request is still rate limited, giving up");
+    Some(StatusError::RateLimited { retry_after })
+}
+
+/// Returns the error for a response Discord rejected with `status` and `body`.
+fn rejected(status: StatusCode, body: String) -> StatusError {
+    let err = ApiError::from_body(status, body);
+    warn!(%status, code = ?err.code(), message = err.message(), "Discord rejected the request");
+    StatusError::Api(err)
+}
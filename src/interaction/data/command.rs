@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 use crate::types::{CommandKind, CommandOptionKind, MultiTypeValue, Snowflake};
@@ -51,12 +53,24 @@ impl CommandInteractionData {
     }
 
     /// Returns an optional value associated with the command, if present.
-    pub fn value(&self) -> &Option<MultiTypeValue> {
+    pub fn value(&self) -> Option<&MultiTypeValue> {
+        self.value.as_ref()
+    }
+
+    /// Returns an optional value associated with the command, if present.
+    #[deprecated(note = "use `value`, which returns `Option<&MultiTypeValue>`")]
+    pub fn legacy_value(&self) -> &Option<MultiTypeValue> {
         &self.value
     }
 
     /// Returns any nested options for this command, such as subcommands or option lists.
-    pub fn options(&self) -> &Option<Vec<CommandInteractionData>> {
+    pub fn options(&self) -> Option<&[CommandInteractionData]> {
+        self.options.as_deref()
+    }
+
+    /// Returns any nested options for this command, such as subcommands or option lists.
+    #[deprecated(note = "use `options`, which returns `Option<&[CommandInteractionData]>`")]
+    pub fn legacy_options(&self) -> &Option<Vec<CommandInteractionData>> {
         &self.options
     }
 
@@ -65,10 +79,13 @@ impl CommandInteractionData {
     /// This method searches the `options` list for an option with the specified name.
     /// # Example
     /// ```rust
+    /// # use serde_discord::interaction::CommandInteractionData;
+    /// # fn example(command_data: &CommandInteractionData) {
     /// let option = command_data.option("subcommand_name");
     /// if let Some(subcommand) = option {
     ///     // Handle the subcommand
     /// }
+    /// # }
     /// ```
     pub fn option(&self, name: &str) -> Option<&CommandInteractionData> {
         if let Some(opts) = self.options() {
@@ -80,9 +97,60 @@ impl CommandInteractionData {
     }
 
     /// Returns whether this option is focused, typically used in autocomplete interactions.
-    pub fn focused(&self) -> &Option<bool> {
+    pub fn focused(&self) -> Option<bool> {
+        self.focused
+    }
+
+    /// Returns whether this option is focused, typically used in autocomplete interactions.
+    #[deprecated(note = "use `focused`, which returns `Option<bool>`")]
+    pub fn legacy_focused(&self) -> &Option<bool> {
         &self.focused
     }
+
+    /// Returns the values of the nested options keyed by their names.
+    ///
+    /// Subcommands and subcommand groups are flattened, so the returned map contains the
+    /// values of the options passed to the invoked subcommand.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::{interaction::CommandInteractionData, types::MultiTypeValue};
+    /// # fn example(command_data: &CommandInteractionData) {
+    /// let options = command_data.options_map();
+    /// if let Some(MultiTypeValue::Integer(max)) = options.get("max") {
+    ///     // Handle the option value
+    /// }
+    /// # }
+    /// ```
+    pub fn options_map(&self) -> HashMap<&str, &MultiTypeValue> {
+        let mut map = HashMap::new();
+        if let Some(opts) = self.options() {
+            collect_option_values(opts, &mut map);
+        }
+        map
+    }
+}
+
+/// Recursively collects the values of `options` into `map`, descending into subcommands
+/// and subcommand groups.
+fn collect_option_values<'a>(
+    options: &'a [CommandInteractionData],
+    map: &mut HashMap<&'a str, &'a MultiTypeValue>,
+) {
+    for opt in options {
+        match opt.kind() {
+            CommandOptionKind::SubCommand | CommandOptionKind::SubCommandGroup => {
+                if let Some(nested) = opt.options() {
+                    collect_option_values(nested, map);
+                }
+            }
+            _ => {
+                if let Some(value) = opt.value() {
+                    map.insert(opt.name(), value);
+                }
+            }
+        }
+    }
 }
 
 /// Represents the overall data structure for a command interaction.
@@ -126,7 +194,13 @@ impl CommandData {
     }
 
     /// Returns any options or subcommands associated with the command, if present.
-    pub fn options(&self) -> &Option<Vec<CommandInteractionData>> {
+    pub fn options(&self) -> Option<&[CommandInteractionData]> {
+        self.options.as_deref()
+    }
+
+    /// Returns any options or subcommands associated with the command, if present.
+    #[deprecated(note = "use `options`, which returns `Option<&[CommandInteractionData]>`")]
+    pub fn legacy_options(&self) -> &Option<Vec<CommandInteractionData>> {
         &self.options
     }
 
@@ -135,10 +209,13 @@ impl CommandData {
     /// This method searches the `options` list for an option with the specified name.
     /// # Example
     /// ```rust
+    /// # use serde_discord::interaction::CommandData;
+    /// # fn example(command_data: &CommandData) {
     /// let option = command_data.option("option_name");
     /// if let Some(option) = option {
     ///     // Handle the option
     /// }
+    /// # }
     /// ```
     pub fn option(&self, name: &str) -> Option<&CommandInteractionData> {
         if let Some(opts) = self.options() {
@@ -150,12 +227,36 @@ impl CommandData {
     }
 
     /// Returns the ID of the guild where the command was invoked, if applicable.
-    pub fn guild_id(&self) -> &Option<Snowflake> {
+    pub fn guild_id(&self) -> Option<Snowflake> {
+        self.guild_id
+    }
+
+    /// Returns the ID of the guild where the command was invoked, if applicable.
+    #[deprecated(note = "use `guild_id`, which returns `Option<Snowflake>`")]
+    pub fn legacy_guild_id(&self) -> &Option<Snowflake> {
         &self.guild_id
     }
 
     /// Returns the ID of the target (user or message) involved in the command, if applicable.
-    pub fn target_id(&self) -> &Option<Snowflake> {
+    pub fn target_id(&self) -> Option<Snowflake> {
+        self.target_id
+    }
+
+    /// Returns the ID of the target (user or message) involved in the command, if applicable.
+    #[deprecated(note = "use `target_id`, which returns `Option<Snowflake>`")]
+    pub fn legacy_target_id(&self) -> &Option<Snowflake> {
         &self.target_id
     }
+
+    /// Returns the values of the command's options keyed by their names.
+    ///
+    /// Subcommands and subcommand groups are flattened, so the returned map contains the
+    /// values of the options passed to the invoked subcommand.
+    pub fn options_map(&self) -> HashMap<&str, &MultiTypeValue> {
+        let mut map = HashMap::new();
+        if let Some(opts) = self.options() {
+            collect_option_values(opts, &mut map);
+        }
+        map
+    }
 }
@@ -0,0 +1,69 @@
+use std::num::ParseIntError;
+
+use serde::Deserialize;
+
+use crate::types::{ComponentType, CustomId, CustomIdError, Snowflake};
+
+/// Represents the data associated with a message component interaction.
+///
+/// This struct is sent when a user interacts with a component, such as clicking a button
+/// or submitting a selection in a select menu.
+///
+/// # Fields
+/// - `custom_id`: The developer-defined identifier of the component.
+/// - `component_type`: The type of the component that was interacted with.
+/// - `values`: The values selected by the user, present only for select menus.
+#[derive(Deserialize, Debug)]
+#[non_exhaustive]
+pub struct MessageComponentData {
+    custom_id: String,
+    component_type: ComponentType,
+    values: Option<Vec<String>>,
+}
+
+impl MessageComponentData {
+    /// Returns the developer-defined `custom_id` of the component.
+    pub fn custom_id(&self) -> &str {
+        &self.custom_id
+    }
+
+    /// Decodes the state stored in the `custom_id` of the component.
+    ///
+    /// # Errors
+    /// Returns an error if the `custom_id` was not encoded from a `T`.
+    pub fn state<T: CustomId>(&self) -> Result<T, CustomIdError> {
+        T::decode(&self.custom_id)
+    }
+
+    /// Returns the type of the component.
+    pub fn component_type(&self) -> ComponentType {
+        self.component_type
+    }
+
+    /// Returns the numeric type of the component.
+    #[deprecated(note = "use `component_type`, which returns `ComponentType`")]
+    pub fn legacy_component_type(&self) -> u8 {
+        self.component_type as u8
+    }
+
+    /// Returns the raw values selected by the user, if the component is a select menu.
+    pub fn values(&self) -> Option<&[String]> {
+        self.values.as_deref()
+    }
+
+    /// Parses the selected values as snowflakes.
+    ///
+    /// User, role, mentionable, and channel select menus submit the IDs of the selected
+    /// entities as strings. This method parses each of them into a `Snowflake`. If the
+    /// component carried no values, an empty vector is returned.
+    ///
+    /// # Errors
+    /// Returns an error if any of the values is not a valid snowflake, such as the values
+    /// submitted by a string select menu.
+    pub fn snowflake_values(&self) -> Result<Vec<Snowflake>, ParseIntError> {
+        match &self.values {
+            Some(values) => values.iter().map(|v| v.parse::<Snowflake>()).collect(),
+            None => Ok(Vec::new()),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 mod command;
+mod component;
 
 pub use command::*;
+pub use component::*;
 use serde::Deserialize;
 
 /// Represents the data associated with different types of interactions from Discord.
@@ -1,20 +1,220 @@
 use serde::{de, Deserialize};
 
-use super::data::CommandInteractionData;
+use super::data::{CommandInteractionData, MessageComponentData};
+use crate::types::{
+    Entitlement, InteractionContextType, Member, Message, PartialChannel, PartialGuild,
+    Permissions, Snowflake, User,
+};
+
+/// Represents an incoming Discord interaction.
+///
+/// The `Interaction` struct holds the information shared by every interaction event, such as
+/// where it was invoked and by whom, along with its kind-specific data.
+///
+/// # Fields
+/// - `id`: The unique ID of the interaction.
+/// - `application_id`: The ID of the application this interaction is for.
+/// - `kind`: The kind of interaction, carrying its associated data.
+/// - `token`: A continuation token for responding to the interaction.
+/// - `guild`: The partial guild the interaction was sent from, if any.
+/// - `guild_id`: The ID of the guild the interaction was sent from, if any.
+/// - `channel`: The partial channel the interaction was sent from, if any.
+/// - `channel_id`: The ID of the channel the interaction was sent from, if any.
+/// - `message`: The message the component was attached to, for component interactions.
+/// - `member`: The guild member who invoked the interaction, present when invoked in a guild.
+/// - `user`: The user who invoked the interaction, present when invoked in a DM.
+/// - `app_permissions`: The permissions of the app in the channel the interaction was sent from.
+/// - `context`: The context where the interaction was triggered from.
+/// - `entitlements`: The entitlements of the invoking user or guild, for monetized apps.
+/// - `attachment_size_limit`: The maximum size of attachments in bytes for the invoking context.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Interaction {
+    id: Snowflake,
+    application_id: Snowflake,
+    kind: InteractionKind,
+    token: String,
+    guild: Option<PartialGuild>,
+    guild_id: Option<Snowflake>,
+    channel: Option<PartialChannel>,
+    channel_id: Option<Snowflake>,
+    message: Option<Message>,
+    member: Option<Member>,
+    user: Option<User>,
+    app_permissions: Option<Permissions>,
+    context: Option<InteractionContextType>,
+    entitlements: Vec<Entitlement>,
+    attachment_size_limit: Option<u64>,
+}
+
+impl Interaction {
+    /// Returns the unique ID of the interaction.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the ID of the application this interaction is for.
+    pub fn application_id(&self) -> Snowflake {
+        self.application_id
+    }
+
+    /// Returns the kind of interaction along with its associated data.
+    pub fn kind(&self) -> &InteractionKind {
+        &self.kind
+    }
+
+    /// Returns the continuation token for responding to the interaction.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns the partial guild the interaction was sent from, if any.
+    ///
+    /// The guild's features can be used to tailor the response, e.g. whether it is a
+    /// `"COMMUNITY"` guild.
+    pub fn guild(&self) -> Option<&PartialGuild> {
+        self.guild.as_ref()
+    }
+
+    /// Returns the ID of the guild the interaction was sent from, if any.
+    pub fn guild_id(&self) -> Option<Snowflake> {
+        self.guild_id
+    }
+
+    /// Returns the partial channel the interaction was sent from, if any.
+    ///
+    /// Its type can be used to tailor the response, e.g. whether the interaction was sent
+    /// from a thread.
+    pub fn channel(&self) -> Option<&PartialChannel> {
+        self.channel.as_ref()
+    }
+
+    /// Returns the ID of the channel the interaction was sent from, if any.
+    pub fn channel_id(&self) -> Option<Snowflake> {
+        self.channel_id
+    }
+
+    /// Returns the message the component was attached to, for component interactions.
+    pub fn message(&self) -> Option<&Message> {
+        self.message.as_ref()
+    }
+
+    /// Returns the guild member who invoked the interaction, if it was invoked in a guild.
+    pub fn member(&self) -> Option<&Member> {
+        self.member.as_ref()
+    }
+
+    /// Returns the user who invoked the interaction, if it was invoked in a DM.
+    ///
+    /// Use [`Interaction::invoker`] to get the invoking user regardless of where the
+    /// interaction happened.
+    pub fn user(&self) -> Option<&User> {
+        self.user.as_ref()
+    }
+
+    /// Returns the permissions of the app in the channel the interaction was sent from, if
+    /// provided.
+    ///
+    /// This can be checked before responding with something the app may not be allowed to
+    /// do, such as sending embeds or attaching files.
+    pub fn app_permissions(&self) -> Option<Permissions> {
+        self.app_permissions
+    }
+
+    /// Returns the context where the interaction was triggered from, if provided.
+    pub fn context(&self) -> Option<InteractionContextType> {
+        self.context
+    }
+
+    /// Returns whether the interaction was invoked inside a guild.
+    ///
+    /// The `context` field is used when present, falling back to checking for a `guild_id`.
+    pub fn in_guild(&self) -> bool {
+        match self.context {
+            Some(context) => context == InteractionContextType::Guild,
+            None => self.guild_id.is_some(),
+        }
+    }
+
+    /// Returns whether the interaction was invoked inside a DM or group DM.
+    pub fn is_dm(&self) -> bool {
+        !self.in_guild()
+    }
+
+    /// Returns the user who invoked the interaction.
+    ///
+    /// Discord sends the invoking user under `member.user` for guild interactions and under
+    /// `user` for DM interactions. This method checks both.
+    pub fn invoker(&self) -> Option<&User> {
+        self.member
+            .as_ref()
+            .and_then(Member::user)
+            .or(self.user.as_ref())
+    }
+
+    /// Returns the ID of the user who invoked the interaction.
+    pub fn invoker_id(&self) -> Option<Snowflake> {
+        self.invoker().map(User::id)
+    }
+
+    /// Returns the maximum size of attachments in bytes for the invoking context, if provided.
+    ///
+    /// The limit depends on where the interaction was invoked, e.g. boosted guilds allow
+    /// larger uploads. Responses with attachments exceeding it are rejected by Discord.
+    pub fn attachment_size_limit(&self) -> Option<u64> {
+        self.attachment_size_limit
+    }
+
+    /// Returns the entitlements of the invoking user or guild.
+    ///
+    /// This includes entitlements that are no longer active. Use
+    /// [`Interaction::active_entitlements`] to only get those that currently grant access.
+    pub fn entitlements(&self) -> &[Entitlement] {
+        &self.entitlements
+    }
+
+    /// Returns the entitlements that currently grant access to their SKU.
+    ///
+    /// Deleted, consumed, not yet started, and expired entitlements are excluded.
+    pub fn active_entitlements(&self) -> Vec<&Entitlement> {
+        self.entitlements
+            .iter()
+            .filter(|entitlement| entitlement.is_active())
+            .collect()
+    }
+
+    /// Returns whether the invoking user or guild has an active entitlement to the given SKU.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::{interaction::Interaction, types::Snowflake};
+    /// # const PREMIUM_SKU_ID: Snowflake = Snowflake::new(1);
+    /// # fn example(interaction: &Interaction) {
+    /// if !interaction.has_entitlement(PREMIUM_SKU_ID) {
+    ///     // Ask the user to upgrade
+    /// }
+    /// # }
+    /// ```
+    pub fn has_entitlement(&self, sku_id: Snowflake) -> bool {
+        self.entitlements
+            .iter()
+            .any(|entitlement| entitlement.sku_id() == sku_id && entitlement.is_active())
+    }
+}
 
 /// Represents different types of incoming Discord interactions.
 ///
-/// The `Interaction` enum contains variants for various interaction events
+/// The `InteractionKind` enum contains variants for various interaction events
 /// that Discord sends, including ping events, command invocations, and component interactions.
 ///
 /// # Variants
 /// - `Ping`: Represents a basic "ping" interaction, typically used for confirming bot availability.
 /// - `Command`: Represents a slash command invocation with associated data. This variant carries a `CommandInteractionData` value.
-/// - `MessageComponent`: Represents an interaction with a message component, such as a button or select menu.
+/// - `MessageComponent`: Represents an interaction with a message component, such as a button or select menu. This variant carries a `MessageComponentData` value.
 /// - `CommandAutocomplete`: Represents an autocomplete interaction for command input suggestions.
 /// - `ModalSubmit`: Represents an interaction when a modal is submitted by a user.
 #[derive(Debug)]
-pub enum Interaction {
+pub enum InteractionKind {
     /// Ping interaction for health checks.
     Ping,
 
@@ -22,7 +222,7 @@ pub enum Interaction {
     Command(CommandInteractionData),
 
     /// Interaction with a message component, such as a button or select menu.
-    MessageComponent,
+    MessageComponent(MessageComponentData),
 
     /// Command autocomplete interaction for providing suggestions to the user.
     CommandAutocomplete,
@@ -34,36 +234,62 @@ pub enum Interaction {
 /// Helper struct to represent the raw data received for an interaction.
 ///
 /// This struct is used internally to deserialize the incoming interaction data
-/// before mapping it to an appropriate variant of the `Interaction` enum.
+/// before mapping it to an `Interaction` with the appropriate `InteractionKind`.
 ///
 /// # Fields
 /// - `kind`: A numeric value representing the type of interaction. This field is renamed from `type` in the original JSON.
 /// - `data`: Optional interaction data in raw JSON format. Depending on the interaction type, this may be present.
+/// - The remaining fields are shared by all interaction types and are copied to `Interaction` as is.
 #[derive(Deserialize)]
 struct InteractionRaw {
+    id: Snowflake,
+    application_id: Snowflake,
     #[serde(rename = "type")]
     kind: u8,
     data: Option<serde_json::Value>,
+    token: String,
+    guild: Option<PartialGuild>,
+    #[serde(default)]
+    guild_id: Option<Snowflake>,
+    channel: Option<PartialChannel>,
+    #[serde(default)]
+    channel_id: Option<Snowflake>,
+    message: Option<Message>,
+    member: Option<Member>,
+    user: Option<User>,
+    app_permissions: Option<Permissions>,
+    context: Option<InteractionContextType>,
+    #[serde(default)]
+    entitlements: Vec<Entitlement>,
+    attachment_size_limit: Option<u64>,
 }
 
 impl<'de> Deserialize<'de> for Interaction {
-    /// Custom deserialization logic for the `Interaction` enum.
+    /// Custom deserialization logic for the `Interaction` struct.
     ///
-    /// This method takes raw JSON data and maps it to the appropriate variant of `Interaction`
-    /// based on the `type` field. If the type corresponds to a command, it also attempts to
-    /// deserialize the `data` field into `CommandInteractionData`.
+    /// This method takes raw JSON data and maps it to the appropriate variant of `InteractionKind`
+    /// based on the `type` field. If the type corresponds to a command or a message component,
+    /// it also attempts to deserialize the `data` field into `CommandInteractionData` or
+    /// `MessageComponentData` respectively.
     ///
     /// # Errors
     /// - Returns an error if the interaction type is unknown.
     /// - Returns an error if `CommandInteractionData` fails to deserialize when expected.
+    /// - Returns an error if `MessageComponentData` fails to deserialize when expected.
+    ///
+    /// Errors in the `data` field report the location of the offending value, such as
+    /// `data.options[2].value`.
     ///
     /// # Example
     /// ```rust
-    /// use my_crate::interaction::Interaction;
+    /// use serde_discord::interaction::Interaction;
     ///
     /// let json_str = r#"{
+    ///     "id": "1",
+    ///     "application_id": "2",
     ///     "type": 2,
-    ///     "data": { "name": "example_command" }
+    ///     "token": "token",
+    ///     "data": { "name": "example_command", "type": 1 }
     /// }"#;
     ///
     /// let interaction: Interaction = serde_json::from_str(json_str).unwrap();
@@ -74,31 +300,78 @@ impl<'de> Deserialize<'de> for Interaction {
     {
         let raw = InteractionRaw::deserialize(deserializer)?;
 
-        match raw.kind {
-            1 => Ok(Interaction::Ping),
+        let kind = match raw.kind {
+            1 => InteractionKind::Ping,
             2 => {
                 if let Some(data) = raw.data {
-                    let data: CommandInteractionData =
-                        serde_json::from_value(data).map_err(|e| {
-                            de::Error::custom(format!(
-                                "error deserializing `CommandInteractionData`: {:?}",
-                                e
-                            ))
-                        })?;
-                    Ok(Interaction::Command(data))
+                    InteractionKind::Command(deserialize_data(data, "CommandInteractionData")?)
                 } else {
-                    Err(de::Error::custom(
+                    return Err(de::Error::custom(
                         "error deserializing `CommandInteractionData`: no data",
-                    ))
+                    ));
                 }
             }
-            3 => Ok(Interaction::MessageComponent),
-            4 => Ok(Interaction::CommandAutocomplete),
-            5 => Ok(Interaction::ModalSumbit),
-            other => Err(de::Error::unknown_variant(
-                &other.to_string(),
-                &["1", "2", "3", "4", "5"],
-            )),
-        }
+            3 => {
+                if let Some(data) = raw.data {
+                    InteractionKind::MessageComponent(deserialize_data(
+                        data,
+                        "MessageComponentData",
+                    )?)
+                } else {
+                    return Err(de::Error::custom(
+                        "error deserializing `MessageComponentData`: no data",
+                    ));
+                }
+            }
+            4 => InteractionKind::CommandAutocomplete,
+            5 => InteractionKind::ModalSumbit,
+            other => {
+                return Err(de::Error::unknown_variant(
+                    &other.to_string(),
+                    &["1", "2", "3", "4", "5"],
+                ))
+            }
+        };
+
+        Ok(Interaction {
+            id: raw.id,
+            application_id: raw.application_id,
+            kind,
+            token: raw.token,
+            guild: raw.guild,
+            guild_id: raw.guild_id,
+            channel: raw.channel,
+            channel_id: raw.channel_id,
+            message: raw.message,
+            member: raw.member,
+            user: raw.user,
+            app_permissions: raw.app_permissions,
+            context: raw.context,
+            entitlements: raw.entitlements,
+            attachment_size_limit: raw.attachment_size_limit,
+        })
     }
 }
+
+/// Deserializes the `data` field of an interaction into `T`.
+///
+/// On failure, the returned error includes the path to the value that could not be
+/// deserialized, relative to the interaction payload (e.g. `data.options[2].value`).
+fn deserialize_data<T, E>(data: serde_json::Value, type_name: &str) -> Result<T, E>
+where
+    T: de::DeserializeOwned,
+    E: de::Error,
+{
+    serde_path_to_error::deserialize(data).map_err(|e| {
+        let path = match e.path().to_string().as_str() {
+            "." => "data".to_string(),
+            path => format!("data.{}", path),
+        };
+        E::custom(format!(
+            "error deserializing `{}` at `{}`: {}",
+            type_name,
+            path,
+            e.inner()
+        ))
+    })
+}
@@ -16,12 +16,14 @@
 //! # Example
 //!
 //! ```rust
-//! use my_crate::interaction::Interaction;
+//! use serde_discord::interaction::Interaction;
 //!
+//! # fn example(interaction_json: String) {
 //! // Assume `interaction_json` is a JSON string received from Discord's API.
 //! let interaction: Interaction = serde_json::from_str(&interaction_json).unwrap();
 //!
 //! // Now `interaction` can be used to handle the interaction event.
+//! # }
 //! ```
 //!
 //! # Modules
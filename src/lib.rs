@@ -7,6 +7,11 @@
 //!
 //! ## Modules
 //!
+//! - `command`: Defines commands once, with `#[derive(SlashCommand)]`, for both
+//!   registration and interaction handling.
+//! - `format`: Formats Discord markdown, such as clickable command mentions.
+//! - `http`: Provides the HTTP client used to send requests to the Discord API,
+//!   including the handling of rate limits.
 //! - `interaction`: Handles incoming interactions from Discord, allowing for
 //!   processing and responding to user inputs.
 //! - `register`: Manages the registration of commands, ensuring they are set
@@ -15,14 +20,28 @@
 //!   including various interaction response types.
 //! - `types`: Defines the types and enums used throughout the library for
 //!   representing commands, options, and responses.
+//! - `verify`: Verifies the signatures of the interactions Discord sends to an HTTP
+//!   endpoint.
 
 /// The version of the Discord API being used.
 ///
-/// This constant is used to construct API endpoint URLs.
-/// If the Discord API version changes, update this constant
-/// accordingly to maintain compatibility.
+/// This constant is used to construct API endpoint URLs, unless another
+/// version is configured on the `http::HttpClient`. If the Discord API version
+/// changes, update this constant accordingly to maintain compatibility.
 pub const DISCORD_API_VERSION: &str = "v10";
 
+/// Module for defining commands once for both registration and interaction handling.
+#[cfg(feature = "derive")]
+pub mod command;
+
+/// Module for formatting Discord markdown, such as command mentions.
+#[cfg(feature = "types")]
+pub mod format;
+
+/// Module providing the HTTP client used to talk to the Discord API.
+#[cfg(any(feature = "register", feature = "response"))]
+pub mod http;
+
 /// Module for handling incoming interactions from Discord.
 #[cfg(feature = "interaction")]
 pub mod interaction;
@@ -38,3 +57,7 @@ pub mod response;
 /// Module defining types and enums used throughout the library.
 #[cfg(feature = "types")]
 pub mod types;
+
+/// Module for verifying the signatures of incoming interactions.
+#[cfg(feature = "verify")]
+pub mod verify;
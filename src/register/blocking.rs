@@ -0,0 +1,130 @@
+//! Blocking versions of the registration functions.
+//!
+//! These functions are built on `reqwest::blocking` and do not require an async runtime,
+//! which makes them suitable for synchronous deployment scripts. They must not be called
+//! from within an async runtime.
+//!
+//! The `_with` variants send requests with a [`BlockingHttpClient`], which is configured
+//! with the same [`HttpClientBuilder`](crate::http::HttpClientBuilder) as the async client.
+
+use reqwest::blocking::Response;
+
+use tracing::info;
+
+use super::{
+    auth_headers, collect_commands, Command, RegisterOutcome, RegisteredCommand, RegistrationError,
+};
+use crate::http::{blocking_error_for_status, BlockingHttpClient};
+
+/// Returns the endpoint for the global commands of the specified application.
+fn commands_endpoint(http: &BlockingHttpClient, app_id: &str) -> String {
+    http.url(&format!("applications/{}/commands", app_id))
+}
+
+/// Registers Discord commands with the specified application, blocking until the request
+/// completes.
+///
+/// This overwrites every global command of the application and returns a summary with the
+/// commands as registered by Discord, including their IDs.
+///
+/// Rate limited requests are retried according to the default
+/// [`RetryPolicy`](crate::http::RetryPolicy).
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The bot token for authentication.
+/// * `cmds` - The commands to be registered. These are validated as they are consumed, like
+///   in the async [`register_commands`](super::register_commands).
+///
+/// # Errors
+///
+/// Returns an error if a command is invalid or if the request to register commands fails.
+/// If Discord rejects the commands, the error contains the status code and the paths of the
+/// offending fields.
+pub fn register_commands(
+    app_id: &str,
+    token: &str,
+    cmds: impl IntoIterator<Item = Command>,
+) -> Result<RegisterOutcome, RegistrationError> {
+    register_commands_with(&BlockingHttpClient::new(), app_id, token, cmds)
+}
+
+/// Registers Discord commands with the specified application using `http`, blocking until
+/// the request completes.
+///
+/// Unlike [`register_commands`], which creates a new client for every call, this reuses the
+/// connection pool and configuration of `http`. Rate limited requests are retried according
+/// to its retry policy.
+///
+/// # Errors
+///
+/// Returns an error if a command is invalid, if the request to register commands fails, if
+/// it is still rate limited after all retries were used, or if the response cannot be
+/// deserialized.
+pub fn register_commands_with(
+    http: &BlockingHttpClient,
+    app_id: &str,
+    token: &str,
+    cmds: impl IntoIterator<Item = Command>,
+) -> Result<RegisterOutcome, RegistrationError> {
+    let cmds = collect_commands(cmds)?;
+    let request = http
+        .inner()
+        .put(commands_endpoint(http, app_id))
+        .headers(auth_headers(token)?)
+        .json(&cmds);
+    let response = check_response(http.send(request)?)?;
+    let commands = response.json::<Vec<RegisteredCommand>>()?;
+    info!(
+        app_id,
+        submitted = cmds.len(),
+        registered = commands.len(),
+        "registered commands"
+    );
+    Ok(RegisterOutcome::new(cmds.len(), commands))
+}
+
+/// Fetches the global commands currently registered for the specified application, blocking
+/// until the request completes.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The bot token for authentication.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or if the response cannot be deserialized.
+pub fn get_commands(
+    app_id: &str,
+    token: &str,
+) -> Result<Vec<RegisteredCommand>, RegistrationError> {
+    get_commands_with(&BlockingHttpClient::new(), app_id, token)
+}
+
+/// Fetches the global commands currently registered for the specified application using
+/// `http`, blocking until the request completes.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, if it is still rate limited after all retries
+/// were used, or if the response cannot be deserialized.
+pub fn get_commands_with(
+    http: &BlockingHttpClient,
+    app_id: &str,
+    token: &str,
+) -> Result<Vec<RegisteredCommand>, RegistrationError> {
+    let request = http
+        .inner()
+        .get(commands_endpoint(http, app_id))
+        .query(&[("with_localizations", true)])
+        .headers(auth_headers(token)?);
+    let response = check_response(http.send(request)?)?;
+    Ok(response.json::<Vec<RegisteredCommand>>()?)
+}
+
+/// Returns `response` if it has a success status, or the error it describes otherwise.
+fn check_response(response: Response) -> Result<Response, RegistrationError> {
+    Ok(blocking_error_for_status(response)?)
+}
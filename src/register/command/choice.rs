@@ -1,8 +1,14 @@
-use serde::{ser::Error, ser::SerializeStruct, Serialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+
+use super::{validate::*, CommandError};
+use crate::types::{Locale, LocalizationMap};
 
 /// Represents the possible values for a command option choice.
 /// It can be an integer, a float, or a string.
-#[derive(Serialize)]
+///
+/// Integers that do not fit in an `i32` are deserialized as `Float`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
 pub enum ChoiceValue {
     Int(i32),
     Float(f64),
@@ -10,32 +16,67 @@ pub enum ChoiceValue {
 }
 
 /// Represents a choice for a command option, which contains a name and a value.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[non_exhaustive]
 pub struct Choice {
     /// The name of the command option choice.
     name: String,
+    /// Localized names of the command option choice, keyed by locale.
+    name_localizations: Option<LocalizationMap>,
     /// The value associated with the command option choice.
     value: ChoiceValue,
 }
 
+impl Choice {
+    /// Returns the name of the command option choice.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the localized names of the command option choice, if any.
+    pub fn name_localizations(&self) -> Option<&LocalizationMap> {
+        self.name_localizations.as_ref()
+    }
+
+    /// Returns the value of the command option choice.
+    pub fn value(&self) -> &ChoiceValue {
+        &self.value
+    }
+
+    /// Validates that the name and its localizations are between 1 and 100 characters, and
+    /// that a string value is at most 100 characters.
+    pub(super) fn validate(&self) -> Result<(), CommandError> {
+        validate_name_length(&self.name, MAX_CHOICE_NAME_LENGTH)?;
+        validate_localized_names(self.name_localizations.as_ref(), |name| {
+            validate_name_length(name, MAX_CHOICE_NAME_LENGTH)
+        })?;
+        if let ChoiceValue::String(value) = &self.value {
+            if value.chars().count() > MAX_CHOICE_NAME_LENGTH {
+                return Err(CommandError::InvalidField {
+                    field: "value",
+                    reason: "cannot be longer than 100 characters",
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Serialize for Choice {
-    /// Custom serializer for `CommandOptionChoice` that ensures string values do not exceed 100 characters.
+    /// Serializes the choice, omitting `name_localizations` when unset. Lengths are
+    /// validated by the builder.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut s = serializer.serialize_struct("Choice", 2)?;
+        let mut s = serializer.serialize_struct("Choice", 3)?;
         s.serialize_field("name", &self.name)?;
-        match &self.value {
-            ChoiceValue::Int(val) => s.serialize_field("value", val)?,
-            ChoiceValue::Float(val) => s.serialize_field("value", val)?,
-            ChoiceValue::String(val) => {
-                if val.len() > 100 {
-                    return Err(Error::custom("Value cannot be longer than 100 chars"));
-                }
-                s.serialize_field("value", val)?;
-            }
-        };
+        if let Some(name_localizations) = &self.name_localizations {
+            s.serialize_field("name_localizations", name_localizations)?;
+        } else {
+            s.skip_field("name_localizations")?;
+        }
+        s.serialize_field("value", &self.value)?;
         s.end()
     }
 }
@@ -43,6 +84,7 @@ impl Serialize for Choice {
 /// A builder for `CommandOptionChoice`, allowing construction with optional fields.
 pub struct CommandOptionChoiceBuilder {
     name: Option<String>,
+    name_localizations: Option<LocalizationMap>,
     value: Option<ChoiceValue>,
 }
 
@@ -51,6 +93,7 @@ impl CommandOptionChoiceBuilder {
     pub fn new() -> Self {
         Self {
             name: None,
+            name_localizations: None,
             value: None,
         }
     }
@@ -61,6 +104,20 @@ impl CommandOptionChoiceBuilder {
         self
     }
 
+    /// Sets the name of the command option choice for a specific locale.
+    pub fn name_localized(mut self, locale: Locale, name: impl Into<String>) -> Self {
+        self.name_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, name.into());
+        self
+    }
+
+    /// Sets all the localized names of the command option choice, replacing any previously set.
+    pub fn name_localizations(mut self, name_localizations: LocalizationMap) -> Self {
+        self.name_localizations = Some(name_localizations);
+        self
+    }
+
     /// Sets the value for the command option choice.
     pub fn value(mut self, value: ChoiceValue) -> Self {
         self.value = Some(value);
@@ -70,27 +127,50 @@ impl CommandOptionChoiceBuilder {
     /// Builds and returns a `CommandOptionChoice` if all required fields are set.
     ///
     /// # Errors
-    /// Returns an error if `name` or `value` are not set, or if a string value exceeds 100 characters.
-    pub fn build(self) -> Result<Choice, Box<dyn std::error::Error>> {
-        if self.name.is_none() {
-            return Err("`name` must be set".into());
-        }
-        if let Some(value) = &self.value {
-            match value {
-                ChoiceValue::String(string_val) => {
-                    if string_val.len() > 100 {
-                        return Err("`value` cannot be longer than 100 chars".into());
-                    }
-                }
-                _ => (),
-            }
-        } else {
-            return Err("`name` must be set".into());
-        }
+    /// Returns an error if `name` or `value` are not set, if the name or any of its
+    /// localizations is not between 1 and 100 characters, or if a string value exceeds
+    /// 100 characters.
+    pub fn build(self) -> Result<Choice, CommandError> {
+        let Some(name) = self.name else {
+            return Err(CommandError::MissingField("name"));
+        };
+        let Some(value) = self.value else {
+            return Err(CommandError::MissingField("value"));
+        };
+        let choice = Choice {
+            name,
+            name_localizations: self.name_localizations,
+            value,
+        };
+        choice.validate()?;
+        Ok(choice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn choice(value: &str) -> Result<Choice, CommandError> {
+        CommandOptionChoiceBuilder::new()
+            .name("Choice")
+            .value(ChoiceValue::String(value.to_string()))
+            .build()
+    }
+
+    #[test]
+    fn multi_byte_string_values_are_counted_in_characters() {
+        // 100 characters, but 300 bytes.
+        let valid = choice(&"日".repeat(100)).unwrap();
+        let json = serde_json::to_value(&valid).unwrap();
+        assert_eq!(json["value"], "日".repeat(100));
+
+        assert!(choice(&"日".repeat(101)).is_err());
+    }
 
-        Ok(Choice {
-            name: self.name.unwrap(),
-            value: self.value.unwrap(),
-        })
+    #[test]
+    fn ascii_string_values_are_limited_to_100_characters() {
+        assert!(choice(&"a".repeat(100)).is_ok());
+        assert!(choice(&"a".repeat(101)).is_err());
     }
 }
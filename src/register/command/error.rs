@@ -0,0 +1,111 @@
+use std::{error::Error, fmt};
+
+use crate::types::{CommandKind, Locale};
+
+/// Represents an error encountered while building a command, option, or choice.
+///
+/// These errors mirror the validation Discord performs on registration, so invalid
+/// definitions fail locally instead of when the commands are sent to the API.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CommandError {
+    /// A required field was not set.
+    MissingField(&'static str),
+    /// A name is empty or longer than `max` characters.
+    NameLength { name: String, max: usize },
+    /// A chat input command or option name contains uppercase letters.
+    NameNotLowercase { name: String },
+    /// A chat input command or option name contains characters Discord does not allow.
+    NameInvalidCharacters { name: String },
+    /// The description of the command or option `name` is empty or longer than 100 characters.
+    DescriptionLength { name: String, len: usize },
+    /// The user or message command `name` has a non-empty description.
+    UnexpectedDescription { name: String },
+    /// The command or option `name` has more than 25 options.
+    TooManyOptions { name: String, count: usize },
+    /// The option `name` has more than 25 choices.
+    TooManyChoices { name: String, count: usize },
+    /// The option `name` is nested somewhere Discord does not allow it.
+    InvalidNesting { name: String, reason: &'static str },
+    /// The required option `name` follows an optional option.
+    RequiredAfterOptional { name: String },
+    /// The combined length of the names, descriptions, and choice values of a command exceeds
+    /// 4000 characters. `locale` is `None` for the default, unlocalized strings.
+    TooManyCharacters {
+        locale: Option<Locale>,
+        count: usize,
+    },
+    /// More than `max` commands of `kind` were registered at once.
+    TooManyCommands { kind: CommandKind, max: usize },
+    /// A field was set to a value Discord does not accept.
+    InvalidField {
+        field: &'static str,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::MissingField(field) => write!(f, "`{}` must be set", field),
+            CommandError::NameLength { name, max } => {
+                write!(
+                    f,
+                    "name {:?} must be between 1 and {} characters",
+                    name, max
+                )
+            }
+            CommandError::NameNotLowercase { name } => {
+                write!(f, "name {:?} must be lowercase", name)
+            }
+            CommandError::NameInvalidCharacters { name } => write!(
+                f,
+                "name {:?} may only contain letters, numbers, `-`, `_`, and `'`",
+                name
+            ),
+            CommandError::DescriptionLength { name, len } => write!(
+                f,
+                "description of {:?} must be between 1 and 100 characters, got {}",
+                name, len
+            ),
+            CommandError::UnexpectedDescription { name } => write!(
+                f,
+                "description of {:?} must be empty for user and message commands",
+                name
+            ),
+            CommandError::TooManyOptions { name, count } => write!(
+                f,
+                "{:?} has {} options, but at most 25 are allowed",
+                name, count
+            ),
+            CommandError::TooManyChoices { name, count } => write!(
+                f,
+                "{:?} has {} choices, but at most 25 are allowed",
+                name, count
+            ),
+            CommandError::InvalidNesting { name, reason } => write!(f, "{:?} {}", name, reason),
+            CommandError::RequiredAfterOptional { name } => write!(
+                f,
+                "required option {:?} must come before optional options",
+                name
+            ),
+            CommandError::TooManyCharacters { locale, count } => write!(
+                f,
+                "command has {} characters{}, but at most 4000 are allowed",
+                count,
+                match locale {
+                    Some(locale) => format!(" in locale `{}`", locale.as_str()),
+                    None => String::new(),
+                }
+            ),
+            CommandError::TooManyCommands { kind, max } => write!(
+                f,
+                "at most {} commands of type {:?} can be registered",
+                max, kind
+            ),
+            CommandError::InvalidField { field, reason } => write!(f, "`{}` {}", field, reason),
+        }
+    }
+}
+
+impl Error for CommandError {}
@@ -1,35 +1,177 @@
 mod choice;
+mod error;
 mod option;
+mod registered;
+mod validate;
 
-use serde::{ser::SerializeStruct, Serialize};
-use std::error::Error;
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
 
-use crate::types::CommandKind;
+use crate::types::{
+    ApplicationIntegrationType, CommandKind, CommandOptionKind, EntryPointHandler,
+    InteractionContextType, Locale, LocalizationMap, Permissions,
+};
 pub use choice::*;
+pub use error::*;
 pub use option::*;
+pub use registered::*;
+use validate::*;
 
 /// Represents a command with a name, type, description, and optional list of command options.
 ///
 /// This struct is used to define the properties of a command in Discord, such as slash commands.
-/// It implements `serde::Serialize` to allow it to be serialized for sending to Discord's API.
+/// It implements `serde::Serialize` to allow it to be serialized for sending to Discord's API,
+/// and `serde::Deserialize` to parse command definitions, such as those returned by Discord
+/// or stored in a manifest. Serializing and deserializing a command yields an equal command.
 ///
 /// # Fields
 /// - `name`: The name of the command.
+/// - `name_localizations`: Localized names of the command, keyed by locale.
 /// - `kind`: The type of command (e.g., slash command, user command, etc.).
 /// - `description`: A brief description of the command.
+/// - `description_localizations`: Localized descriptions of the command, keyed by locale.
 /// - `options`: A list of optional command options (e.g., subcommands or arguments).
+/// - `contexts`: The interaction contexts where the command can be used.
+/// - `integration_types`: The installation types where the command is available.
+/// - `handler`: How interactions with a primary entry point command are handled.
+/// - `default_member_permissions`: The permissions members need to use the command by default.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Command {
     name: String,
+    name_localizations: Option<LocalizationMap>,
+    #[serde(rename = "type", default = "default_command_kind")]
     kind: CommandKind,
+    #[serde(default)]
     description: String,
+    description_localizations: Option<LocalizationMap>,
     options: Option<Vec<CommandOption>>,
+    contexts: Option<Vec<InteractionContextType>>,
+    integration_types: Option<Vec<ApplicationIntegrationType>>,
+    handler: Option<EntryPointHandler>,
+    default_member_permissions: Option<Permissions>,
+}
+
+/// Discord defaults the `type` of a command to `ChatInput` when omitted.
+fn default_command_kind() -> CommandKind {
+    CommandKind::ChatInput
+}
+
+impl Command {
+    /// Returns the name of the command.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the localized names of the command, if any.
+    pub fn name_localizations(&self) -> Option<&LocalizationMap> {
+        self.name_localizations.as_ref()
+    }
+
+    /// Returns the type of the command.
+    pub fn kind(&self) -> &CommandKind {
+        &self.kind
+    }
+
+    /// Returns the description of the command.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the localized descriptions of the command, if any.
+    pub fn description_localizations(&self) -> Option<&LocalizationMap> {
+        self.description_localizations.as_ref()
+    }
+
+    /// Returns the options of the command, if any.
+    pub fn options(&self) -> Option<&[CommandOption]> {
+        self.options.as_deref()
+    }
+
+    /// Validates the command and all of its options and choices against Discord's rules.
+    ///
+    /// This runs the same checks as `CommandBuilder::build`, and can be used to validate
+    /// commands obtained otherwise, e.g. deserialized ones. Among these, Discord limits the
+    /// combined length of the name, description, and string choice values of a command and all
+    /// of its options to 4000 characters. The limit is applied separately for each locale,
+    /// using the localized strings where available.
+    ///
+    /// # Errors
+    /// Returns the first rule the command breaks, such as `CommandError::TooManyCharacters` if
+    /// the combined character limit is exceeded in any locale.
+    pub fn validate(&self) -> Result<(), CommandError> {
+        let kind = &self.kind;
+        let name = &self.name;
+        match kind {
+            CommandKind::ChatInput => {
+                validate_chat_input_name(name)?;
+                validate_localized_names(self.name_localizations(), validate_chat_input_name)?;
+                validate_description(name, &self.description)?;
+                validate_localized_descriptions(name, self.description_localizations())?;
+            }
+            CommandKind::User | CommandKind::Message => {
+                let validate = |name: &str| validate_name_length(name, MAX_NAME_LENGTH);
+                validate(name)?;
+                validate_localized_names(self.name_localizations(), validate)?;
+                if !self.description.is_empty() {
+                    return Err(CommandError::UnexpectedDescription {
+                        name: self.name.clone(),
+                    });
+                }
+            }
+            CommandKind::PrimaryEntryPoint => {
+                validate_name_length(name, MAX_NAME_LENGTH)?;
+            }
+        }
+        validate_option_count(name, self.options())?;
+        validate_top_level_options(self.options())?;
+        match (kind, &self.handler) {
+            (CommandKind::PrimaryEntryPoint, None) => {
+                return Err(CommandError::InvalidField {
+                    field: "handler",
+                    reason: "must be set on `PrimaryEntryPoint` commands",
+                });
+            }
+            (kind, Some(_)) if *kind != CommandKind::PrimaryEntryPoint => {
+                return Err(CommandError::InvalidField {
+                    field: "handler",
+                    reason: "can only be set on `PrimaryEntryPoint` commands",
+                });
+            }
+            _ => (),
+        }
+        validate_option_order(self.options())?;
+        for opt in self.options().unwrap_or_default() {
+            opt.validate()?;
+        }
+        validate_character_budget(self)
+    }
+
+    /// Returns the interaction contexts where the command can be used, if restricted.
+    pub fn contexts(&self) -> Option<&[InteractionContextType]> {
+        self.contexts.as_deref()
+    }
+
+    /// Returns the installation types where the command is available, if set.
+    pub fn integration_types(&self) -> Option<&[ApplicationIntegrationType]> {
+        self.integration_types.as_deref()
+    }
+
+    /// Returns how interactions with the command are handled, for primary entry point commands.
+    pub fn handler(&self) -> Option<EntryPointHandler> {
+        self.handler
+    }
+
+    /// Returns the permissions members need to use the command by default, if set.
+    pub fn default_member_permissions(&self) -> Option<Permissions> {
+        self.default_member_permissions
+    }
 }
 
 impl Serialize for Command {
     /// Serializes the `Command` struct into a format suitable for Discord's API.
     ///
-    /// The fields `name`, `type`, and `description` are always serialized, but the `options` field
-    /// is serialized only if it's present. If `options` is `None`, it will be skipped.
+    /// The fields `name`, `type`, and `description` are always serialized, but the localizations,
+    /// `options`, `contexts`, `integration_types`, `handler`, and `default_member_permissions`
+    /// fields are serialized only if they're present. If they are `None`, they will be skipped.
     ///
     /// # Errors
     /// Returns an error if the serialization fails.
@@ -37,15 +179,45 @@ impl Serialize for Command {
     where
         S: serde::Serializer,
     {
-        let mut s = serializer.serialize_struct("Command", 4)?;
+        let mut s = serializer.serialize_struct("Command", 10)?;
         s.serialize_field("name", &self.name)?;
+        if let Some(name_localizations) = &self.name_localizations {
+            s.serialize_field("name_localizations", name_localizations)?;
+        } else {
+            s.skip_field("name_localizations")?;
+        }
         s.serialize_field("type", &self.kind)?;
         s.serialize_field("description", &self.description)?;
+        if let Some(description_localizations) = &self.description_localizations {
+            s.serialize_field("description_localizations", description_localizations)?;
+        } else {
+            s.skip_field("description_localizations")?;
+        }
         if let Some(options) = &self.options {
             s.serialize_field("options", &options)?;
         } else {
             s.skip_field("options")?;
         }
+        if let Some(contexts) = &self.contexts {
+            s.serialize_field("contexts", &contexts)?;
+        } else {
+            s.skip_field("contexts")?;
+        }
+        if let Some(integration_types) = &self.integration_types {
+            s.serialize_field("integration_types", &integration_types)?;
+        } else {
+            s.skip_field("integration_types")?;
+        }
+        if let Some(handler) = &self.handler {
+            s.serialize_field("handler", handler)?;
+        } else {
+            s.skip_field("handler")?;
+        }
+        if let Some(default_member_permissions) = &self.default_member_permissions {
+            s.serialize_field("default_member_permissions", default_member_permissions)?;
+        } else {
+            s.skip_field("default_member_permissions")?;
+        }
         s.end()
     }
 }
@@ -58,14 +230,28 @@ impl Serialize for Command {
 ///
 /// # Fields
 /// - `name`: The name of the command to be created.
+/// - `name_localizations`: Optional localized names of the command.
 /// - `kind`: The type of the command (e.g., slash command).
 /// - `description`: A short description of the command.
+/// - `description_localizations`: Optional localized descriptions of the command.
 /// - `options`: An optional list of command options, such as arguments or subcommands.
+/// - `contexts`: An optional list of interaction contexts where the command can be used.
+/// - `integration_types`: An optional list of installation types where the command is available.
+/// - `handler`: How interactions are handled, required for primary entry point commands.
+/// - `default_member_permissions`: The permissions members need to use the command by default.
 pub struct CommandBuilder {
     name: Option<String>,
+    name_localizations: Option<LocalizationMap>,
     kind: Option<CommandKind>,
     description: Option<String>,
+    description_localizations: Option<LocalizationMap>,
     options: Option<Vec<CommandOption>>,
+    contexts: Option<Vec<InteractionContextType>>,
+    integration_types: Option<Vec<ApplicationIntegrationType>>,
+    handler: Option<EntryPointHandler>,
+    default_member_permissions: Option<Permissions>,
+    /// The first error encountered while building a subcommand, reported by `build`.
+    error: Option<CommandError>,
 }
 
 impl CommandBuilder {
@@ -75,9 +261,16 @@ impl CommandBuilder {
     pub fn new() -> Self {
         Self {
             name: None,
+            name_localizations: None,
             kind: None,
             description: None,
+            description_localizations: None,
             options: None,
+            contexts: None,
+            integration_types: None,
+            handler: None,
+            default_member_permissions: None,
+            error: None,
         }
     }
 
@@ -88,6 +281,7 @@ impl CommandBuilder {
     ///
     /// # Example
     /// ```rust
+    /// # use serde_discord::register::CommandBuilder;
     /// let builder = CommandBuilder::new().name("example_command");
     /// ```
     pub fn name(mut self, name: impl Into<String>) -> Self {
@@ -95,6 +289,37 @@ impl CommandBuilder {
         self
     }
 
+    /// Sets the name of the command for a specific locale.
+    ///
+    /// Users with a matching client locale see the localized name instead of the default one.
+    ///
+    /// # Arguments
+    /// - `locale`: The locale the name applies to.
+    /// - `name`: The localized name, subject to the same rules as the default name.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::{register::CommandBuilder, types::Locale};
+    /// let builder = CommandBuilder::new()
+    ///     .name("roll")
+    ///     .name_localized(Locale::German, "würfeln");
+    /// ```
+    pub fn name_localized(mut self, locale: Locale, name: impl Into<String>) -> Self {
+        self.name_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, name.into());
+        self
+    }
+
+    /// Sets all the localized names of the command, replacing any previously set.
+    ///
+    /// # Arguments
+    /// - `name_localizations`: A map of locales to localized names.
+    pub fn name_localizations(mut self, name_localizations: LocalizationMap) -> Self {
+        self.name_localizations = Some(name_localizations);
+        self
+    }
+
     /// Sets the `kind` (type) of the command.
     ///
     /// # Arguments
@@ -113,6 +338,27 @@ impl CommandBuilder {
         self
     }
 
+    /// Sets the description of the command for a specific locale.
+    ///
+    /// # Arguments
+    /// - `locale`: The locale the description applies to.
+    /// - `description`: The localized description.
+    pub fn description_localized(mut self, locale: Locale, description: impl Into<String>) -> Self {
+        self.description_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, description.into());
+        self
+    }
+
+    /// Sets all the localized descriptions of the command, replacing any previously set.
+    ///
+    /// # Arguments
+    /// - `description_localizations`: A map of locales to localized descriptions.
+    pub fn description_localizations(mut self, description_localizations: LocalizationMap) -> Self {
+        self.description_localizations = Some(description_localizations);
+        self
+    }
+
     /// Adds a single command option to the command.
     ///
     /// # Arguments
@@ -138,26 +384,178 @@ impl CommandBuilder {
         self
     }
 
+    /// Builds `subcommand` as a subcommand and adds it to the command.
+    ///
+    /// The kind of `subcommand` is set to `SubCommand`, so only its name, description, and
+    /// options need to be set. Errors building the subcommand are returned by `build`.
+    ///
+    /// # Arguments
+    /// - `subcommand`: A builder for the subcommand.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::register::{CommandBuilder, CommandOptionBuilder};
+    /// let builder = CommandBuilder::new()
+    ///     .name("config")
+    ///     .description("Manage the configuration")
+    ///     .subcommand(CommandOptionBuilder::new().name("show").description("Show the config"));
+    /// ```
+    pub fn subcommand(self, subcommand: CommandOptionBuilder) -> Self {
+        self.nested(subcommand.build_as(CommandOptionKind::SubCommand))
+    }
+
+    /// Builds `group` as a subcommand group and adds it to the command.
+    ///
+    /// The kind of `group` is set to `SubCommandGroup`. Add its subcommands with
+    /// `CommandOptionBuilder::subcommand`. Errors building the group are returned by `build`.
+    ///
+    /// # Arguments
+    /// - `group`: A builder for the subcommand group.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::register::{CommandBuilder, CommandOptionBuilder};
+    /// let builder = CommandBuilder::new()
+    ///     .name("config")
+    ///     .description("Manage the configuration")
+    ///     .subcommand_group(
+    ///         CommandOptionBuilder::new()
+    ///             .name("user")
+    ///             .description("Manage user settings")
+    ///             .subcommand(CommandOptionBuilder::new().name("reset").description("Reset")),
+    ///     );
+    /// ```
+    pub fn subcommand_group(self, group: CommandOptionBuilder) -> Self {
+        self.nested(group.build_as(CommandOptionKind::SubCommandGroup))
+    }
+
+    /// Adds a built subcommand or subcommand group, or records the error building it.
+    fn nested(mut self, option: Result<CommandOption, CommandError>) -> Self {
+        match option {
+            Ok(option) => self.option(option),
+            Err(err) => {
+                self.error.get_or_insert(err);
+                self
+            }
+        }
+    }
+
+    /// Sets the interaction contexts where the command can be used.
+    ///
+    /// This replaces the deprecated `dm_permission` field. For example, passing only
+    /// `InteractionContextType::BotDm` makes the command available exclusively in DMs
+    /// with the bot. If not set, Discord makes the command available in all contexts
+    /// allowed by the app's installation.
+    ///
+    /// # Arguments
+    /// - `contexts`: The interaction contexts where the command can be used.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::{register::CommandBuilder, types::InteractionContextType};
+    /// let builder = CommandBuilder::new().contexts(vec![InteractionContextType::BotDm]);
+    /// ```
+    pub fn contexts(mut self, contexts: Vec<InteractionContextType>) -> Self {
+        self.contexts = Some(contexts);
+        self
+    }
+
+    /// Sets the installation types where the command is available.
+    ///
+    /// User-installable apps must include `ApplicationIntegrationType::UserInstall` for the
+    /// command to show up for users who installed the app. If not set, Discord defaults to the
+    /// app's configured installation types.
+    ///
+    /// # Arguments
+    /// - `integration_types`: The installation types where the command is available.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::{register::CommandBuilder, types::ApplicationIntegrationType};
+    /// let builder = CommandBuilder::new().integration_types(vec![
+    ///     ApplicationIntegrationType::GuildInstall,
+    ///     ApplicationIntegrationType::UserInstall,
+    /// ]);
+    /// ```
+    pub fn integration_types(mut self, integration_types: Vec<ApplicationIntegrationType>) -> Self {
+        self.integration_types = Some(integration_types);
+        self
+    }
+
+    /// Sets how interactions with the command are handled.
+    ///
+    /// This is required for, and only applicable to, commands of kind `PrimaryEntryPoint`,
+    /// which are used to launch Activities.
+    ///
+    /// # Arguments
+    /// - `handler`: Whether the app or Discord handles the interaction.
+    pub fn handler(mut self, handler: EntryPointHandler) -> Self {
+        self.handler = Some(handler);
+        self
+    }
+
+    /// Sets the permissions members need to use the command by default.
+    ///
+    /// Server administrators can change who may use the command afterwards. Passing
+    /// `Permissions::empty()` makes the command available only to administrators by default.
+    ///
+    /// # Arguments
+    /// - `permissions`: The permissions members need to use the command.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::{register::CommandBuilder, types::Permissions};
+    /// let builder = CommandBuilder::new().default_member_permissions(Permissions::BAN_MEMBERS);
+    /// ```
+    pub fn default_member_permissions(mut self, permissions: Permissions) -> Self {
+        self.default_member_permissions = Some(permissions);
+        self
+    }
+
     /// Builds and returns a `Command` instance.
     ///
-    /// This method checks that the required fields (`name` and `kind`) are set. If they are missing,
+    /// This method checks that the required fields (`name` and `kind`) are set and that the
+    /// name and description follow Discord's rules for the kind of command. If they don't,
     /// it returns an error. Otherwise, it returns the constructed `Command`.
     ///
+    /// Chat input command names must be 1-32 lowercase characters consisting of letters,
+    /// numbers, `-`, `_`, and `'`, and their descriptions must be 1-100 characters. User and
+    /// message command names may be mixed case and contain spaces, but must not have a
+    /// description. Localized names and descriptions follow the same rules.
+    ///
+    /// A command may have at most 25 options, and its options must either all be subcommands
+    /// and subcommand groups, or none of them. Required options must come before optional
+    /// ones.
+    ///
     /// # Errors
-    /// Returns an error if either the `name` or `kind` field is missing.
-    pub fn build(self) -> Result<Command, Box<dyn Error>> {
-        if self.name.is_none() {
-            return Err("`name` must be set".into());
+    /// Returns an error if either the `name` or `kind` field is missing, if the name or
+    /// description is invalid, if the options are invalid, or if `handler` is missing on a
+    /// primary entry point command or set on any other kind of command. Also returns an
+    /// error if the command exceeds the combined character limit (see `Command::validate`),
+    /// and the first error encountered while building a subcommand or subcommand group.
+    pub fn build(mut self) -> Result<Command, CommandError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
         }
-        if self.kind.is_none() {
-            return Err("`kind` must be set".into());
-        }
-
-        Ok(Command {
-            name: self.name.unwrap(),
-            kind: self.kind.unwrap(),
+        let Some(name) = self.name else {
+            return Err(CommandError::MissingField("name"));
+        };
+        let Some(kind) = self.kind else {
+            return Err(CommandError::MissingField("kind"));
+        };
+        let cmd = Command {
+            name,
+            name_localizations: self.name_localizations,
+            kind,
             description: self.description.unwrap_or_default(),
+            description_localizations: self.description_localizations,
             options: self.options,
-        })
+            contexts: self.contexts,
+            integration_types: self.integration_types,
+            handler: self.handler,
+            default_member_permissions: self.default_member_permissions,
+        };
+        cmd.validate()?;
+        Ok(cmd)
     }
 }
@@ -1,34 +1,249 @@
-use serde::{ser::Error, ser::SerializeStruct, Serialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
 
-use super::choice::*;
-use crate::types::CommandOptionKind;
+use super::{choice::*, validate::*, CommandError};
+use crate::types::{ChannelType, CommandOptionKind, Locale, LocalizationMap};
+
+/// The largest magnitude Discord accepts for integer values, which must fit in a double.
+const MAX_SAFE_INTEGER: i64 = 1 << 53;
+/// The largest `min_length` and `max_length` Discord accepts for string options.
+const MAX_OPTION_LENGTH: u16 = 6000;
+
+/// Represents a numeric bound of a command option, set with `min_value` and `max_value`.
+///
+/// Integers are valid for both `Integer` and `Number` options, while floats are only valid
+/// for `Number` options.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(untagged)]
+pub enum NumericValue {
+    Int(i64),
+    Float(f64),
+}
+
+impl NumericValue {
+    /// Returns the value as a float, for comparisons across variants.
+    fn as_f64(self) -> f64 {
+        match self {
+            NumericValue::Int(value) => value as f64,
+            NumericValue::Float(value) => value,
+        }
+    }
+}
+
+impl From<i32> for NumericValue {
+    fn from(value: i32) -> Self {
+        NumericValue::Int(value.into())
+    }
+}
+
+impl From<i64> for NumericValue {
+    fn from(value: i64) -> Self {
+        NumericValue::Int(value)
+    }
+}
+
+impl From<f64> for NumericValue {
+    fn from(value: f64) -> Self {
+        NumericValue::Float(value)
+    }
+}
 
 /// Represents an option for a command, including details such as type, name, description, and other optional fields.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[non_exhaustive]
 pub struct CommandOption {
+    #[serde(rename = "type")]
     kind: CommandOptionKind,
     name: String,
+    name_localizations: Option<LocalizationMap>,
     description: String,
+    description_localizations: Option<LocalizationMap>,
     required: Option<bool>,
     choices: Option<Vec<Choice>>,
     options: Option<Vec<CommandOption>>,
-    min_value: Option<ChoiceValue>,
-    max_value: Option<ChoiceValue>,
-    min_length: Option<ChoiceValue>,
-    max_length: Option<ChoiceValue>,
+    channel_types: Option<Vec<ChannelType>>,
+    min_value: Option<NumericValue>,
+    max_value: Option<NumericValue>,
+    min_length: Option<u16>,
+    max_length: Option<u16>,
     autocomplete: Option<bool>,
 }
 
+impl CommandOption {
+    /// Returns the type of the command option.
+    pub fn kind(&self) -> &CommandOptionKind {
+        &self.kind
+    }
+
+    /// Returns the name of the command option.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the localized names of the command option, if any.
+    pub fn name_localizations(&self) -> Option<&LocalizationMap> {
+        self.name_localizations.as_ref()
+    }
+
+    /// Returns the description of the command option.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the localized descriptions of the command option, if any.
+    pub fn description_localizations(&self) -> Option<&LocalizationMap> {
+        self.description_localizations.as_ref()
+    }
+
+    /// Returns the choices of the command option, if any.
+    pub fn choices(&self) -> Option<&[Choice]> {
+        self.choices.as_deref()
+    }
+
+    /// Returns the nested options of the command option, if any.
+    pub fn options(&self) -> Option<&[CommandOption]> {
+        self.options.as_deref()
+    }
+
+    /// Returns the channel types the option is restricted to, if any.
+    pub fn channel_types(&self) -> Option<&[ChannelType]> {
+        self.channel_types.as_deref()
+    }
+
+    /// Returns the minimum value of the command option, if set.
+    pub fn min_value(&self) -> Option<NumericValue> {
+        self.min_value
+    }
+
+    /// Returns the maximum value of the command option, if set.
+    pub fn max_value(&self) -> Option<NumericValue> {
+        self.max_value
+    }
+
+    /// Returns the minimum length of the command option, if set.
+    pub fn min_length(&self) -> Option<u16> {
+        self.min_length
+    }
+
+    /// Returns the maximum length of the command option, if set.
+    pub fn max_length(&self) -> Option<u16> {
+        self.max_length
+    }
+
+    /// Returns whether the command option is required.
+    pub fn required(&self) -> bool {
+        self.required.unwrap_or(false)
+    }
+
+    /// Validates the option, its choices, and its nested options against Discord's rules.
+    ///
+    /// This runs the same checks as `CommandOptionBuilder::build`, and is used to validate
+    /// options obtained otherwise, e.g. deserialized ones.
+    pub(super) fn validate(&self) -> Result<(), CommandError> {
+        let kind = &self.kind;
+        let name = &self.name;
+        let description = &self.description;
+        validate_chat_input_name(name)?;
+        validate_localized_names(self.name_localizations(), validate_chat_input_name)?;
+        validate_description(name, description)?;
+        validate_localized_descriptions(name, self.description_localizations())?;
+        validate_option_count(name, self.options())?;
+        validate_nested_options(kind, self.options())?;
+        if let Some(choices) = &self.choices {
+            if choices.len() > MAX_CHOICES {
+                return Err(CommandError::TooManyChoices {
+                    name: self.name.clone(),
+                    count: choices.len(),
+                });
+            }
+        }
+        if self.channel_types.is_some() && !matches!(kind, CommandOptionKind::Channel) {
+            return Err(CommandError::InvalidField {
+                field: "channel_types",
+                reason: "can only be set on `Channel` options",
+            });
+        }
+        for (field, value) in [("min_value", self.min_value), ("max_value", self.max_value)] {
+            validate_numeric_value(kind, field, value)?;
+        }
+        if let (Some(min), Some(max)) = (self.min_value, self.max_value) {
+            if min.as_f64() > max.as_f64() {
+                return Err(CommandError::InvalidField {
+                    field: "min_value",
+                    reason: "cannot be greater than `max_value`",
+                });
+            }
+        }
+        for (field, len) in [
+            ("min_length", self.min_length),
+            ("max_length", self.max_length),
+        ] {
+            if len.is_some() && !matches!(kind, CommandOptionKind::String) {
+                return Err(CommandError::InvalidField {
+                    field,
+                    reason: "can only be set on `String` options",
+                });
+            }
+        }
+        if self.min_length.is_some_and(|len| len > MAX_OPTION_LENGTH) {
+            return Err(CommandError::InvalidField {
+                field: "min_length",
+                reason: "cannot be greater than 6000",
+            });
+        }
+        match self.max_length {
+            Some(0) => {
+                return Err(CommandError::InvalidField {
+                    field: "max_length",
+                    reason: "must be at least 1",
+                })
+            }
+            Some(len) if len > MAX_OPTION_LENGTH => {
+                return Err(CommandError::InvalidField {
+                    field: "max_length",
+                    reason: "cannot be greater than 6000",
+                })
+            }
+            _ => (),
+        }
+        if let (Some(min), Some(max)) = (self.min_length, self.max_length) {
+            if min > max {
+                return Err(CommandError::InvalidField {
+                    field: "min_length",
+                    reason: "cannot be greater than `max_length`",
+                });
+            }
+        }
+        validate_option_order(self.options())?;
+        for choice in self.choices().unwrap_or_default() {
+            choice.validate()?;
+        }
+        for opt in self.options().unwrap_or_default() {
+            opt.validate()?;
+        }
+        Ok(())
+    }
+}
+
 /// A builder for `CommandOption`, allowing optional and required fields to be set.
 impl Serialize for CommandOption {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut s = serializer.serialize_struct("CommandOption", 11)?;
+        let mut s = serializer.serialize_struct("CommandOption", 14)?;
         s.serialize_field("type", &self.kind)?;
         s.serialize_field("name", &self.name)?;
+        if let Some(name_localizations) = &self.name_localizations {
+            s.serialize_field("name_localizations", name_localizations)?;
+        } else {
+            s.skip_field("name_localizations")?;
+        }
         s.serialize_field("description", &self.description)?;
+        if let Some(description_localizations) = &self.description_localizations {
+            s.serialize_field("description_localizations", description_localizations)?;
+        } else {
+            s.skip_field("description_localizations")?;
+        }
         if let Some(required) = self.required {
             s.serialize_field("required", &required)?;
         } else {
@@ -44,41 +259,28 @@ impl Serialize for CommandOption {
         } else {
             s.skip_field("options")?;
         }
+        if let Some(channel_types) = &self.channel_types {
+            s.serialize_field("channel_types", channel_types)?;
+        } else {
+            s.skip_field("channel_types")?;
+        }
         if let Some(min_value) = &self.min_value {
-            match min_value {
-                ChoiceValue::Int(val) => s.serialize_field("min_value", val)?,
-                ChoiceValue::Float(val) => s.serialize_field("min_value", val)?,
-                ChoiceValue::String(_) => {
-                    return Err(S::Error::custom("`min_value` should be `Int` or `Float`"))
-                }
-            }
+            s.serialize_field("min_value", min_value)?;
         } else {
             s.skip_field("min_value")?;
         }
         if let Some(max_value) = &self.max_value {
-            match max_value {
-                ChoiceValue::Int(val) => s.serialize_field("max_value", val)?,
-                ChoiceValue::Float(val) => s.serialize_field("max_value", val)?,
-                ChoiceValue::String(_) => {
-                    return Err(S::Error::custom("`max_value` should be `Int` or `Float`"))
-                }
-            }
+            s.serialize_field("max_value", max_value)?;
         } else {
             s.skip_field("max_value")?;
         }
-        if let Some(min_length) = &self.min_length {
-            match min_length {
-                ChoiceValue::Int(val) => s.serialize_field("min_length", val)?,
-                _ => return Err(S::Error::custom("`min_length` should be `Int`")),
-            }
+        if let Some(min_length) = self.min_length {
+            s.serialize_field("min_length", &min_length)?;
         } else {
             s.skip_field("min_length")?;
         }
-        if let Some(max_length) = &self.max_length {
-            match max_length {
-                ChoiceValue::Int(val) => s.serialize_field("max_length", val)?,
-                _ => return Err(S::Error::custom("`max_length` should be `Int`")),
-            }
+        if let Some(max_length) = self.max_length {
+            s.serialize_field("max_length", &max_length)?;
         } else {
             s.skip_field("max_length")?;
         }
@@ -94,15 +296,20 @@ impl Serialize for CommandOption {
 pub struct CommandOptionBuilder {
     kind: Option<CommandOptionKind>,
     name: Option<String>,
+    name_localizations: Option<LocalizationMap>,
     description: Option<String>,
+    description_localizations: Option<LocalizationMap>,
     required: Option<bool>,
     choices: Option<Vec<Choice>>,
     options: Option<Vec<CommandOption>>,
-    min_value: Option<ChoiceValue>,
-    max_value: Option<ChoiceValue>,
-    min_length: Option<ChoiceValue>,
-    max_length: Option<ChoiceValue>,
+    channel_types: Option<Vec<ChannelType>>,
+    min_value: Option<NumericValue>,
+    max_value: Option<NumericValue>,
+    min_length: Option<u16>,
+    max_length: Option<u16>,
     autocomplete: Option<bool>,
+    /// The first error encountered while building a nested subcommand, reported by `build`.
+    error: Option<CommandError>,
 }
 
 impl CommandOptionBuilder {
@@ -111,15 +318,19 @@ impl CommandOptionBuilder {
         Self {
             kind: None,
             name: None,
+            name_localizations: None,
             description: None,
+            description_localizations: None,
             required: None,
             choices: None,
             options: None,
+            channel_types: None,
             min_value: None,
             max_value: None,
             min_length: None,
             max_length: None,
             autocomplete: None,
+            error: None,
         }
     }
 
@@ -135,12 +346,40 @@ impl CommandOptionBuilder {
         self
     }
 
+    /// Sets the name of the command option for a specific locale.
+    pub fn name_localized(mut self, locale: Locale, name: impl Into<String>) -> Self {
+        self.name_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, name.into());
+        self
+    }
+
+    /// Sets all the localized names of the command option, replacing any previously set.
+    pub fn name_localizations(mut self, name_localizations: LocalizationMap) -> Self {
+        self.name_localizations = Some(name_localizations);
+        self
+    }
+
     /// Sets the description for the command option.
     pub fn description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
         self
     }
 
+    /// Sets the description of the command option for a specific locale.
+    pub fn description_localized(mut self, locale: Locale, description: impl Into<String>) -> Self {
+        self.description_localizations
+            .get_or_insert_with(LocalizationMap::new)
+            .insert(locale, description.into());
+        self
+    }
+
+    /// Sets all the localized descriptions of the command option, replacing any previously set.
+    pub fn description_localizations(mut self, description_localizations: LocalizationMap) -> Self {
+        self.description_localizations = Some(description_localizations);
+        self
+    }
+
     /// Sets whether the command option is required.
     pub fn required(mut self, required: bool) -> Self {
         self.required = Some(required);
@@ -179,26 +418,81 @@ impl CommandOptionBuilder {
         self
     }
 
-    /// Sets the minimum value for the command option.
-    pub fn min_value(mut self, min_value: ChoiceValue) -> Self {
-        self.min_value = Some(min_value);
+    /// Builds `subcommand` as a subcommand and adds it to this option.
+    ///
+    /// The kind of `subcommand` is set to `SubCommand`, and if no kind was set on this option,
+    /// it becomes a `SubCommandGroup`. Errors building the subcommand are returned by `build`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::register::CommandOptionBuilder;
+    /// let group = CommandOptionBuilder::new()
+    ///     .name("user")
+    ///     .description("Manage users")
+    ///     .subcommand(CommandOptionBuilder::new().name("add").description("Add a user"))
+    ///     .subcommand(CommandOptionBuilder::new().name("remove").description("Remove a user"));
+    /// ```
+    pub fn subcommand(mut self, subcommand: CommandOptionBuilder) -> Self {
+        self.kind.get_or_insert(CommandOptionKind::SubCommandGroup);
+        match subcommand.build_as(CommandOptionKind::SubCommand) {
+            Ok(subcommand) => self.option(subcommand),
+            Err(err) => {
+                self.error.get_or_insert(err);
+                self
+            }
+        }
+    }
+
+    /// Sets the kind of the option to `kind` and builds it.
+    pub(super) fn build_as(
+        mut self,
+        kind: CommandOptionKind,
+    ) -> Result<CommandOption, CommandError> {
+        self.kind = Some(kind);
+        self.build()
+    }
+
+    /// Restricts the channels that can be picked for a `Channel` option to the given types.
+    ///
+    /// For example, passing only `ChannelType::GuildText` lets users pick text channels only.
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.channel_types = Some(channel_types);
+        self
+    }
+
+    /// Sets the minimum value for an `Integer` or `Number` command option.
+    ///
+    /// Accepts integers for both kinds of options, and floats for `Number` options.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use serde_discord::{register::CommandOptionBuilder, types::CommandOptionKind};
+    /// let builder = CommandOptionBuilder::new()
+    ///     .kind(CommandOptionKind::Number)
+    ///     .min_value(0)
+    ///     .max_value(2.5);
+    /// ```
+    pub fn min_value(mut self, min_value: impl Into<NumericValue>) -> Self {
+        self.min_value = Some(min_value.into());
         self
     }
 
-    /// Sets the maximum value for the command option.
-    pub fn max_value(mut self, max_value: ChoiceValue) -> Self {
-        self.max_value = Some(max_value);
+    /// Sets the maximum value for an `Integer` or `Number` command option.
+    ///
+    /// Accepts integers for both kinds of options, and floats for `Number` options.
+    pub fn max_value(mut self, max_value: impl Into<NumericValue>) -> Self {
+        self.max_value = Some(max_value.into());
         self
     }
 
-    /// Sets the minimum length for the command option (if applicable).
-    pub fn min_length(mut self, min_length: ChoiceValue) -> Self {
+    /// Sets the minimum length for a `String` command option, between 0 and 6000.
+    pub fn min_length(mut self, min_length: u16) -> Self {
         self.min_length = Some(min_length);
         self
     }
 
-    /// Sets the maximum length for the command option (if applicable).
-    pub fn max_length(mut self, max_length: ChoiceValue) -> Self {
+    /// Sets the maximum length for a `String` command option, between 1 and 6000.
+    pub fn max_length(mut self, max_length: u16) -> Self {
         self.max_length = Some(max_length);
         self
     }
@@ -212,66 +506,122 @@ impl CommandOptionBuilder {
     /// Builds and returns a `CommandOption` if all required fields are set.
     ///
     /// # Errors
-    /// Returns an error if `kind`, `name`, or `description` are not set.
-    pub fn build(self) -> Result<CommandOption, Box<dyn std::error::Error>> {
-        if self.kind.is_none() {
-            return Err("`kind` must be set".into());
-        }
-        if self.name.is_none() {
-            return Err("`name` must be set".into());
-        }
-        if self.description.is_none() {
-            return Err("`description` must be set".into());
-        }
-        if let Some(min_value) = &self.min_value {
-            match min_value {
-                ChoiceValue::String(_) => return Err("`min_value` cannot be a string".into()),
-                _ => (),
-            }
-        }
-        if let Some(value) = &self.max_value {
-            match value {
-                ChoiceValue::String(_) => return Err("`max_value` cannot be a string".into()),
-                _ => (),
-            }
-        }
-        if let Some(value) = &self.min_length {
-            match value {
-                ChoiceValue::Int(value) => {
-                    if value < &0 {
-                        return Err("`min_length` must be at least 0".into());
-                    } else if value > &6000 {
-                        return Err("`min_length` cannot be greater than 6000".into());
-                    }
-                }
-                _ => return Err("`min_length` must be an integer".into()),
-            }
-        }
-        if let Some(value) = &self.max_length {
-            match value {
-                ChoiceValue::Int(value) => {
-                    if value < &1 {
-                        return Err("`max_length` must be at least 1".into());
-                    } else if value > &6000 {
-                        return Err("`max_length` cannot be greater than 6000".into());
-                    }
-                }
-                _ => return Err("`max_length` must be an integer".into()),
-            }
+    /// Returns an error if `kind`, `name`, or `description` are not set, if the name or
+    /// description (or any of their localizations) does not follow Discord's naming rules,
+    /// if any of the value and length constraints is invalid, if there are more than 25
+    /// options or choices, if the nested options are not allowed under this kind of option,
+    /// or if a required nested option follows an optional one. Also returns the first error
+    /// encountered while building a subcommand added with `subcommand`.
+    pub fn build(mut self) -> Result<CommandOption, CommandError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
         }
-
-        Ok(CommandOption {
-            kind: self.kind.unwrap(),
-            name: self.name.unwrap(),
-            description: self.description.unwrap(),
+        let Some(kind) = self.kind else {
+            return Err(CommandError::MissingField("kind"));
+        };
+        let Some(name) = self.name else {
+            return Err(CommandError::MissingField("name"));
+        };
+        let Some(description) = self.description else {
+            return Err(CommandError::MissingField("description"));
+        };
+        let option = CommandOption {
+            kind,
+            name,
+            name_localizations: self.name_localizations,
+            description,
+            description_localizations: self.description_localizations,
             required: self.required,
             choices: self.choices,
             options: self.options,
+            channel_types: self.channel_types,
             min_value: self.min_value,
             max_value: self.max_value,
             min_length: self.min_length,
             max_length: self.max_length,
             autocomplete: self.autocomplete,
-        })
+        };
+        option.validate()?;
+        Ok(option)
+    }
+}
+
+/// Validates that `value`, set as the `field` bound of an option of the given `kind`, is
+/// allowed for that kind of option.
+fn validate_numeric_value(
+    kind: &CommandOptionKind,
+    field: &'static str,
+    value: Option<NumericValue>,
+) -> Result<(), CommandError> {
+    let Some(value) = value else {
+        return Ok(());
+    };
+    let reason = match (kind, value) {
+        (CommandOptionKind::Integer | CommandOptionKind::Number, NumericValue::Int(value))
+            if !(-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&value) =>
+        {
+            "must be between -2^53 and 2^53"
+        }
+        (CommandOptionKind::Integer, NumericValue::Float(_)) => {
+            "must be an integer on `Integer` options"
+        }
+        (CommandOptionKind::Integer | CommandOptionKind::Number, _) => return Ok(()),
+        _ => "can only be set on `Integer` and `Number` options",
+    };
+    Err(CommandError::InvalidField { field, reason })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integer_option(min_value: i64) -> Result<CommandOption, CommandError> {
+        CommandOptionBuilder::new()
+            .kind(CommandOptionKind::Integer)
+            .name("count")
+            .description("How many")
+            .min_value(min_value)
+            .build()
+    }
+
+    #[test]
+    fn integer_bounds_at_the_safe_range_are_accepted() {
+        assert!(integer_option(MAX_SAFE_INTEGER).is_ok());
+        assert!(integer_option(-MAX_SAFE_INTEGER).is_ok());
+    }
+
+    #[test]
+    fn integer_bounds_past_the_safe_range_are_rejected() {
+        assert!(integer_option(MAX_SAFE_INTEGER + 1).is_err());
+        assert!(integer_option(-MAX_SAFE_INTEGER - 1).is_err());
+        assert!(integer_option(i64::MAX).is_err());
+        assert!(integer_option(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn required_options_must_precede_optional_ones() {
+        let option = |name: &str, required: bool| {
+            CommandOptionBuilder::new()
+                .kind(CommandOptionKind::String)
+                .name(name)
+                .description("An option")
+                .required(required)
+                .build()
+                .unwrap()
+        };
+        let subcommand = |options: Vec<CommandOption>| {
+            CommandOptionBuilder::new()
+                .kind(CommandOptionKind::SubCommand)
+                .name("add")
+                .description("Add a user")
+                .options(options)
+                .build()
+        };
+
+        assert!(subcommand(vec![option("user", true), option("reason", false)]).is_ok());
+        assert!(matches!(
+            subcommand(vec![option("reason", false), option("user", true)]),
+            Err(CommandError::RequiredAfterOptional { name }) if name == "user"
+        ));
     }
 }
@@ -0,0 +1,109 @@
+use serde::Deserialize;
+
+use super::{Command, CommandOption};
+use crate::{
+    format::command_mention,
+    types::{CommandKind, CommandOptionKind, Snowflake},
+};
+
+/// Represents a command as registered with Discord.
+///
+/// Unlike `Command`, which describes a command to be registered, this struct is returned by
+/// Discord's API and carries the identifiers assigned to the command on registration.
+///
+/// # Fields
+/// - `id`: The unique ID of the command.
+/// - `application_id`: The ID of the parent application.
+/// - `guild_id`: The ID of the guild, if the command is guild-scoped.
+/// - `version`: An autoincrementing version identifier updated during substantial changes.
+/// - `command`: The definition of the command, such as its name, type, and options.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RegisteredCommand {
+    id: Snowflake,
+    application_id: Snowflake,
+    #[serde(default)]
+    guild_id: Option<Snowflake>,
+    version: Snowflake,
+    #[serde(flatten)]
+    command: Command,
+}
+
+impl RegisteredCommand {
+    /// Returns the unique ID of the command.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the ID of the parent application.
+    pub fn application_id(&self) -> Snowflake {
+        self.application_id
+    }
+
+    /// Returns the ID of the guild, if the command is guild-scoped.
+    pub fn guild_id(&self) -> Option<Snowflake> {
+        self.guild_id
+    }
+
+    /// Returns the version identifier of the command.
+    pub fn version(&self) -> Snowflake {
+        self.version
+    }
+
+    /// Returns the definition of the command as registered.
+    pub fn command(&self) -> &Command {
+        &self.command
+    }
+
+    /// Returns the name of the command.
+    pub fn name(&self) -> &str {
+        self.command.name()
+    }
+
+    /// Returns the type of the command.
+    pub fn kind(&self) -> &CommandKind {
+        self.command.kind()
+    }
+
+    /// Returns the description of the command.
+    pub fn description(&self) -> &str {
+        self.command.description()
+    }
+
+    /// Returns the options of the command.
+    pub fn options(&self) -> &[CommandOption] {
+        self.command.options().unwrap_or_default()
+    }
+
+    /// Returns the markdown that renders as a clickable mention of the command, such as
+    /// `</roll:123>`.
+    ///
+    /// Commands with subcommands can only be mentioned through one of their subcommands;
+    /// use [`RegisteredCommand::subcommand_mention`] for those.
+    pub fn mention(&self) -> String {
+        command_mention(self.id, self.name(), &[])
+    }
+
+    /// Returns the markdown that renders as a clickable mention of a subcommand, such as
+    /// `</config user reset:123>`.
+    ///
+    /// `path` is the name of the subcommand, preceded by the name of its group if it has one.
+    /// Returns `None` if the command has no such subcommand.
+    pub fn subcommand_mention(&self, path: &[&str]) -> Option<String> {
+        let (last, groups) = path.split_last()?;
+        let mut options = self.options();
+        for group in groups {
+            options = options
+                .iter()
+                .find(|opt| {
+                    matches!(opt.kind(), CommandOptionKind::SubCommandGroup) && opt.name() == *group
+                })?
+                .options()
+                .unwrap_or_default();
+        }
+        options
+            .iter()
+            .any(|opt| matches!(opt.kind(), CommandOptionKind::SubCommand) && opt.name() == *last)
+            .then(|| command_mention(self.id, self.name(), path))
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::BTreeSet;
+
+use super::{ChoiceValue, Command, CommandError, CommandOption};
+use crate::types::{CommandOptionKind, Locale, LocalizationMap};
+
+/// The maximum length of command and option names.
+pub(super) const MAX_NAME_LENGTH: usize = 32;
+/// The maximum length of choice names.
+pub(super) const MAX_CHOICE_NAME_LENGTH: usize = 100;
+/// The maximum length of command and option descriptions.
+pub(super) const MAX_DESCRIPTION_LENGTH: usize = 100;
+/// The maximum number of options of a command, subcommand, or subcommand group.
+pub(super) const MAX_OPTIONS: usize = 25;
+/// The maximum number of choices of an option.
+pub(super) const MAX_CHOICES: usize = 25;
+/// The maximum combined length of the strings of a command, per locale.
+pub(super) const MAX_COMMAND_CHARACTERS: usize = 4000;
+
+/// Validates the name of a chat input command or of an option.
+///
+/// Discord requires these names to match `^[-_'\p{L}\p{N}\p{sc=Deva}\p{sc=Thai}]{1,32}$`
+/// and to use the lowercase variant of letters when one exists.
+pub(super) fn validate_chat_input_name(name: &str) -> Result<(), CommandError> {
+    validate_name_length(name, MAX_NAME_LENGTH)?;
+    if !name.chars().all(is_valid_name_char) {
+        return Err(CommandError::NameInvalidCharacters {
+            name: name.to_string(),
+        });
+    }
+    if name.chars().any(char::is_uppercase) {
+        return Err(CommandError::NameNotLowercase {
+            name: name.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Validates that a name is between 1 and `max` characters long.
+pub(super) fn validate_name_length(name: &str, max: usize) -> Result<(), CommandError> {
+    let len = name.chars().count();
+    if len == 0 || len > max {
+        return Err(CommandError::NameLength {
+            name: name.to_string(),
+            max,
+        });
+    }
+    Ok(())
+}
+
+/// Validates that the description of `name` is between 1 and 100 characters long.
+pub(super) fn validate_description(name: &str, description: &str) -> Result<(), CommandError> {
+    let len = description.chars().count();
+    if len == 0 || len > MAX_DESCRIPTION_LENGTH {
+        return Err(CommandError::DescriptionLength {
+            name: name.to_string(),
+            len,
+        });
+    }
+    Ok(())
+}
+
+/// Validates every localized name in `localizations` with `validate`.
+pub(super) fn validate_localized_names(
+    localizations: Option<&LocalizationMap>,
+    validate: impl Fn(&str) -> Result<(), CommandError>,
+) -> Result<(), CommandError> {
+    localizations
+        .into_iter()
+        .flat_map(|map| map.values())
+        .try_for_each(|name| validate(name))
+}
+
+/// Validates every localized description of `name` in `localizations`.
+pub(super) fn validate_localized_descriptions(
+    name: &str,
+    localizations: Option<&LocalizationMap>,
+) -> Result<(), CommandError> {
+    localizations
+        .into_iter()
+        .flat_map(|map| map.values())
+        .try_for_each(|description| validate_description(name, description))
+}
+
+/// Validates the number of options of the command or option `name`.
+pub(super) fn validate_option_count(
+    name: &str,
+    options: Option<&[CommandOption]>,
+) -> Result<(), CommandError> {
+    let count = options.map_or(0, <[CommandOption]>::len);
+    if count > MAX_OPTIONS {
+        return Err(CommandError::TooManyOptions {
+            name: name.to_string(),
+            count,
+        });
+    }
+    Ok(())
+}
+
+/// Validates that the options of a command are either all subcommands and subcommand
+/// groups, or none of them are.
+pub(super) fn validate_top_level_options(
+    options: Option<&[CommandOption]>,
+) -> Result<(), CommandError> {
+    let options = options.unwrap_or_default();
+    if options.iter().any(|opt| is_subcommand(opt.kind())) {
+        if let Some(opt) = options.iter().find(|opt| !is_subcommand(opt.kind())) {
+            return Err(CommandError::InvalidNesting {
+                name: opt.name().to_string(),
+                reason: "cannot be mixed with subcommands or subcommand groups",
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Validates that the required options of a command or subcommand come before its optional
+/// ones, as Discord requires.
+pub(super) fn validate_option_order(options: Option<&[CommandOption]>) -> Result<(), CommandError> {
+    let mut optional = false;
+    for opt in options.unwrap_or_default() {
+        if opt.required() && optional {
+            return Err(CommandError::RequiredAfterOptional {
+                name: opt.name().to_string(),
+            });
+        }
+        optional |= !opt.required();
+    }
+    Ok(())
+}
+
+/// Validates that the options nested under an option of the given `kind` are allowed.
+///
+/// Subcommand groups may only contain subcommands, subcommands may not contain subcommands
+/// or subcommand groups, and other options may not contain options at all.
+pub(super) fn validate_nested_options(
+    kind: &CommandOptionKind,
+    options: Option<&[CommandOption]>,
+) -> Result<(), CommandError> {
+    for opt in options.unwrap_or_default() {
+        let reason = match (kind, opt.kind()) {
+            (CommandOptionKind::SubCommandGroup, CommandOptionKind::SubCommand) => continue,
+            (CommandOptionKind::SubCommandGroup, _) => {
+                "can only be a subcommand when nested in a subcommand group"
+            }
+            (CommandOptionKind::SubCommand, child) if is_subcommand(child) => {
+                "cannot be nested in a subcommand"
+            }
+            (CommandOptionKind::SubCommand, _) => continue,
+            _ => "can only be nested in a subcommand or subcommand group",
+        };
+        return Err(CommandError::InvalidNesting {
+            name: opt.name().to_string(),
+            reason,
+        });
+    }
+    Ok(())
+}
+
+/// Validates the combined length of the names, descriptions, and string choice values of
+/// `cmd` and all of its nested options and choices.
+///
+/// Discord applies the limit separately for each locale, using the localized strings where
+/// available and the default ones otherwise.
+pub(super) fn validate_character_budget(cmd: &Command) -> Result<(), CommandError> {
+    let mut locales = BTreeSet::new();
+    collect_locales(cmd.name_localizations(), &mut locales);
+    collect_locales(cmd.description_localizations(), &mut locales);
+    visit_options(cmd.options().unwrap_or_default(), &mut |opt| {
+        collect_locales(opt.name_localizations(), &mut locales);
+        collect_locales(opt.description_localizations(), &mut locales);
+        for choice in opt.choices().unwrap_or_default() {
+            collect_locales(choice.name_localizations(), &mut locales);
+        }
+    });
+
+    for locale in std::iter::once(None).chain(locales.into_iter().map(Some)) {
+        let count = count_characters(cmd, locale);
+        if count > MAX_COMMAND_CHARACTERS {
+            return Err(CommandError::TooManyCharacters { locale, count });
+        }
+    }
+    Ok(())
+}
+
+/// Counts the characters of `cmd` as seen by users of `locale`.
+fn count_characters(cmd: &Command, locale: Option<Locale>) -> usize {
+    let len = |default: &str, localizations: Option<&LocalizationMap>| {
+        locale
+            .and_then(|locale| localizations.and_then(|map| map.get(&locale)))
+            .map_or(default, String::as_str)
+            .chars()
+            .count()
+    };
+
+    let mut count = len(cmd.name(), cmd.name_localizations())
+        + len(cmd.description(), cmd.description_localizations());
+    visit_options(cmd.options().unwrap_or_default(), &mut |opt| {
+        count += len(opt.name(), opt.name_localizations());
+        count += len(opt.description(), opt.description_localizations());
+        for choice in opt.choices().unwrap_or_default() {
+            count += len(choice.name(), choice.name_localizations());
+            if let ChoiceValue::String(value) = choice.value() {
+                count += value.chars().count();
+            }
+        }
+    });
+    count
+}
+
+/// Calls `f` on every option in `options`, recursing into nested options.
+fn visit_options(options: &[CommandOption], f: &mut impl FnMut(&CommandOption)) {
+    for opt in options {
+        f(opt);
+        visit_options(opt.options().unwrap_or_default(), f);
+    }
+}
+
+/// Adds the locales of `localizations` to `locales`.
+fn collect_locales(localizations: Option<&LocalizationMap>, locales: &mut BTreeSet<Locale>) {
+    locales.extend(
+        localizations
+            .into_iter()
+            .flat_map(|map| map.keys().copied()),
+    );
+}
+
+/// Returns whether `kind` is a subcommand or subcommand group.
+fn is_subcommand(kind: &CommandOptionKind) -> bool {
+    matches!(
+        kind,
+        CommandOptionKind::SubCommand | CommandOptionKind::SubCommandGroup
+    )
+}
+
+/// Returns whether `c` may appear in a chat input command or option name.
+fn is_valid_name_char(c: char) -> bool {
+    matches!(c, '-' | '_' | '\'')
+        || c.is_alphabetic()
+        || c.is_numeric()
+        // Devanagari and Thai scripts, which include combining marks.
+        || ('\u{0900}'..='\u{097F}').contains(&c)
+        || ('\u{0E00}'..='\u{0E7F}').contains(&c)
+}
@@ -0,0 +1,122 @@
+use std::{error::Error, fmt, time::Duration};
+
+use reqwest::{header::InvalidHeaderValue, Response, StatusCode};
+
+use super::CommandError;
+use crate::http::{error_for_status, StatusError};
+pub use crate::http::{ApiError, FieldError, INVALID_FORM_BODY};
+
+/// Represents an error encountered while registering, fetching, or synchronizing commands.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RegistrationError {
+    /// The bot token contains characters that cannot be sent in an HTTP header.
+    InvalidToken(InvalidHeaderValue),
+    /// The request could not be sent, or its response could not be read or deserialized.
+    Http(reqwest::Error),
+    /// A command could not be converted to or from JSON.
+    Json(serde_json::Error),
+    /// Discord kept rate limiting the request after all retries were used. `retry_after` is
+    /// the delay Discord asked for, if it sent one.
+    RateLimited { retry_after: Option<Duration> },
+    /// Discord responded with a non-success status code.
+    Api(ApiError),
+    /// A command was rejected locally before any request was sent.
+    InvalidCommand(CommandError),
+}
+
+impl RegistrationError {
+    /// Returns the HTTP status code of the response, if Discord rejected the request.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            RegistrationError::Api(err) => Some(err.status()),
+            RegistrationError::Http(err) => err.status(),
+            RegistrationError::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            _ => None,
+        }
+    }
+
+    /// Returns the errors Discord reported for individual fields of the request body.
+    ///
+    /// The list is empty unless Discord rejected the request with an "Invalid Form Body" error.
+    pub fn field_errors(&self) -> &[FieldError] {
+        match self {
+            RegistrationError::Api(err) => err.field_errors(),
+            _ => &[],
+        }
+    }
+
+    /// Returns the paths of the fields Discord rejected, such as `0.options.1.name`.
+    pub fn field_paths(&self) -> Vec<&str> {
+        self.field_errors().iter().map(FieldError::path).collect()
+    }
+}
+
+impl fmt::Display for RegistrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistrationError::InvalidToken(err) => write!(f, "invalid bot token: {}", err),
+            RegistrationError::Http(err) => write!(f, "request failed: {}", err),
+            RegistrationError::Json(err) => write!(f, "invalid command JSON: {}", err),
+            RegistrationError::RateLimited { retry_after } => match retry_after {
+                Some(delay) => write!(f, "rate limited, retry after {:?}", delay),
+                None => write!(f, "rate limited"),
+            },
+            RegistrationError::Api(err) => err.fmt(f),
+            RegistrationError::InvalidCommand(err) => write!(f, "invalid command: {}", err),
+        }
+    }
+}
+
+impl Error for RegistrationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RegistrationError::InvalidToken(err) => Some(err),
+            RegistrationError::Http(err) => Some(err),
+            RegistrationError::Json(err) => Some(err),
+            RegistrationError::InvalidCommand(err) => Some(err),
+            RegistrationError::RateLimited { .. } | RegistrationError::Api(_) => None,
+        }
+    }
+}
+
+impl From<InvalidHeaderValue> for RegistrationError {
+    fn from(err: InvalidHeaderValue) -> Self {
+        RegistrationError::InvalidToken(err)
+    }
+}
+
+impl From<reqwest::Error> for RegistrationError {
+    fn from(err: reqwest::Error) -> Self {
+        RegistrationError::Http(err)
+    }
+}
+
+impl From<serde_json::Error> for RegistrationError {
+    fn from(err: serde_json::Error) -> Self {
+        RegistrationError::Json(err)
+    }
+}
+
+impl From<StatusError> for RegistrationError {
+    fn from(err: StatusError) -> Self {
+        match err {
+            StatusError::RateLimited { retry_after } => {
+                RegistrationError::RateLimited { retry_after }
+            }
+            StatusError::Api(err) => RegistrationError::Api(err),
+            StatusError::Http(err) => RegistrationError::Http(err),
+        }
+    }
+}
+
+impl From<CommandError> for RegistrationError {
+    fn from(err: CommandError) -> Self {
+        RegistrationError::InvalidCommand(err)
+    }
+}
+
+/// Returns `response` if it has a success status, or the error it describes otherwise.
+pub(crate) async fn check_response(response: Response) -> Result<Response, RegistrationError> {
+    Ok(error_for_status(response).await?)
+}
@@ -0,0 +1,100 @@
+use futures_util::stream::{self, StreamExt};
+
+use super::{register_guild_commands_with, Command, RegisterOutcome, RegistrationError};
+use crate::http::HttpClient;
+
+/// Reports the result of registering a command set in many guilds with
+/// [`register_commands_in_guilds`].
+///
+/// Results are listed in the order the registrations completed, which may differ from the
+/// order of the guild IDs.
+#[non_exhaustive]
+pub struct GuildRegistrationReport {
+    results: Vec<(String, Result<RegisterOutcome, RegistrationError>)>,
+}
+
+impl GuildRegistrationReport {
+    /// Returns the result of every registration, keyed by guild ID.
+    pub fn results(&self) -> &[(String, Result<RegisterOutcome, RegistrationError>)] {
+        &self.results
+    }
+
+    /// Returns the IDs of the guilds the commands were registered in.
+    pub fn succeeded(&self) -> Vec<&str> {
+        self.results
+            .iter()
+            .filter(|(_, result)| result.is_ok())
+            .map(|(guild_id, _)| guild_id.as_str())
+            .collect()
+    }
+
+    /// Returns the IDs of the guilds the registration failed in, with their errors.
+    pub fn failed(&self) -> Vec<(&str, &RegistrationError)> {
+        self.results
+            .iter()
+            .filter_map(|(guild_id, result)| Some((guild_id.as_str(), result.as_ref().err()?)))
+            .collect()
+    }
+
+    /// Returns whether the commands were registered in every guild.
+    pub fn is_success(&self) -> bool {
+        self.results.iter().all(|(_, result)| result.is_ok())
+    }
+}
+
+/// Registers the same commands in many guilds, with at most `concurrency` requests in flight.
+///
+/// A failure in one guild does not stop the registration in the others; every result is
+/// reported in the returned [`GuildRegistrationReport`]. Rate limited requests are retried
+/// according to the retry policy of `http`, so a low `concurrency` also reduces the number
+/// of retries. A `concurrency` of `0` is treated as `1`.
+///
+/// # Arguments
+///
+/// * `http` - The client used to send the requests.
+/// * `app_id` - The Discord application ID.
+/// * `token` - The bot token for authentication.
+/// * `guild_ids` - The IDs of the guilds to register the commands in.
+/// * `cmds` - The commands to register in every guild.
+/// * `concurrency` - The maximum number of registrations running at the same time.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use serde_discord::{
+///     http::HttpClient,
+///     register::{register_commands_in_guilds, Command},
+/// };
+///
+/// # async fn example(app_id: &str, token: &str, guild_ids: Vec<String>, cmds: &[Command]) {
+/// let report =
+///     register_commands_in_guilds(&HttpClient::new(), app_id, token, guild_ids, cmds, 4).await;
+/// for (guild_id, err) in report.failed() {
+///     eprintln!("failed to register commands in {}: {}", guild_id, err);
+/// }
+/// # }
+/// ```
+pub async fn register_commands_in_guilds<I>(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    guild_ids: I,
+    cmds: &[Command],
+    concurrency: usize,
+) -> GuildRegistrationReport
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    let results = stream::iter(guild_ids.into_iter().map(Into::into))
+        .map(|guild_id: String| async move {
+            let result =
+                register_guild_commands_with(http, app_id, token, &guild_id, cmds.iter().cloned())
+                    .await;
+            (guild_id, result)
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+    GuildRegistrationReport { results }
+}
@@ -0,0 +1,356 @@
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use super::{Command, CommandError};
+
+/// The format of a command manifest file.
+///
+/// - `Json`: A JSON array of commands, as sent to Discord, or an object with a `commands` array.
+/// - `Toml`: A TOML document with a `[[commands]]` array of tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestFormat {
+    Json,
+    Toml,
+}
+
+impl ManifestFormat {
+    /// Returns the format matching the extension of `path`, if it is `json` or `toml`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        if extension.eq_ignore_ascii_case("json") {
+            Some(ManifestFormat::Json)
+        } else if extension.eq_ignore_ascii_case("toml") {
+            Some(ManifestFormat::Toml)
+        } else {
+            None
+        }
+    }
+}
+
+/// Represents an error encountered while loading a command manifest.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ManifestError {
+    /// The manifest file could not be read.
+    Io(io::Error),
+    /// The file extension is neither `json` nor `toml`.
+    UnknownFormat(PathBuf),
+    /// The manifest is not valid JSON or does not describe a list of commands.
+    Json(serde_json::Error),
+    /// The manifest is not valid TOML or does not describe a list of commands.
+    Toml(toml::de::Error),
+    /// The command `name` does not satisfy Discord's rules.
+    InvalidCommand { name: String, source: CommandError },
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Io(err) => write!(f, "failed to read manifest: {}", err),
+            ManifestError::UnknownFormat(path) => write!(
+                f,
+                "cannot infer manifest format of {}, expected a `.json` or `.toml` file",
+                path.display()
+            ),
+            ManifestError::Json(err) => write!(f, "invalid JSON manifest: {}", err),
+            ManifestError::Toml(err) => write!(f, "invalid TOML manifest: {}", err),
+            ManifestError::InvalidCommand { name, source } => {
+                write!(f, "invalid command {:?}: {}", name, source)
+            }
+        }
+    }
+}
+
+impl Error for ManifestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ManifestError::Io(err) => Some(err),
+            ManifestError::UnknownFormat(_) => None,
+            ManifestError::Json(err) => Some(err),
+            ManifestError::Toml(err) => Some(err),
+            ManifestError::InvalidCommand { source, .. } => Some(source),
+        }
+    }
+}
+
+/// A manifest document with its commands under the `commands` key.
+#[derive(Deserialize)]
+struct Manifest {
+    commands: Vec<Command>,
+}
+
+/// Loads a set of command definitions from a JSON or TOML manifest file.
+///
+/// The format is inferred from the file extension. A JSON manifest is either an array of
+/// commands in the format Discord expects, or an object with such an array under the
+/// `commands` key. A TOML manifest lists its commands as `[[commands]]` tables.
+///
+/// # Arguments
+///
+/// * `path` - The path to the manifest file.
+///
+/// # Example
+///
+/// ```toml
+/// [[commands]]
+/// name = "roll"
+/// description = "Roll a die"
+///
+/// [[commands.options]]
+/// type = 4
+/// name = "sides"
+/// description = "The number of sides of the die"
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, if its format is unknown or invalid, or if
+/// a command breaks any of the rules `CommandBuilder::build` enforces (see
+/// `Command::validate`).
+pub fn load_manifest(path: impl AsRef<Path>) -> Result<Vec<Command>, ManifestError> {
+    let path = path.as_ref();
+    let Some(format) = ManifestFormat::from_path(path) else {
+        return Err(ManifestError::UnknownFormat(path.to_path_buf()));
+    };
+    let contents = fs::read_to_string(path).map_err(ManifestError::Io)?;
+    parse_manifest(&contents, format)
+}
+
+/// Parses a set of command definitions from the contents of a manifest.
+///
+/// See [`load_manifest`] for the accepted formats.
+///
+/// # Errors
+///
+/// Returns an error if the contents are invalid for `format`, or if a command breaks any of
+/// the rules `CommandBuilder::build` enforces.
+pub fn parse_manifest(
+    contents: &str,
+    format: ManifestFormat,
+) -> Result<Vec<Command>, ManifestError> {
+    let commands = match format {
+        ManifestFormat::Json if contents.trim_start().starts_with('[') => {
+            serde_json::from_str::<Vec<Command>>(contents).map_err(ManifestError::Json)?
+        }
+        ManifestFormat::Json => {
+            serde_json::from_str::<Manifest>(contents)
+                .map_err(ManifestError::Json)?
+                .commands
+        }
+        ManifestFormat::Toml => {
+            toml::from_str::<Manifest>(contents)
+                .map_err(ManifestError::Toml)?
+                .commands
+        }
+    };
+    for cmd in &commands {
+        cmd.validate()
+            .map_err(|source| ManifestError::InvalidCommand {
+                name: cmd.name().to_string(),
+                source,
+            })?;
+    }
+    Ok(commands)
+}
+
+/// Exports a set of commands as canonical, pretty-printed JSON.
+///
+/// Commands are sorted by type and name, and object keys are sorted alphabetically, so the
+/// output only changes when the definitions do. The order of options and choices is kept,
+/// since it determines the order Discord displays them in. This makes the output suitable
+/// for checking into version control and reviewing diffs before deploying, and it can be
+/// loaded again with [`load_manifest`].
+///
+/// # Errors
+///
+/// Returns an error if a command cannot be serialized.
+pub fn export_commands(cmds: &[Command]) -> Result<String, serde_json::Error> {
+    let mut cmds: Vec<&Command> = cmds.iter().collect();
+    cmds.sort_by(|a, b| (*a.kind() as u8, a.name()).cmp(&(*b.kind() as u8, b.name())));
+    let value = sort_keys(serde_json::to_value(cmds)?);
+    let mut json = serde_json::to_string_pretty(&value)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Sorts the keys of every object in `value`.
+///
+/// Objects are rebuilt with their keys inserted in order, so they are sorted whether
+/// `serde_json` keeps objects sorted or, with its `preserve_order` feature, in insertion
+/// order.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect::<Map<String, Value>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        register::{ChoiceValue, CommandBuilder, CommandOptionBuilder, CommandOptionChoiceBuilder},
+        types::{CommandKind, CommandOptionKind},
+    };
+
+    fn command(name: &str, choice_value: &str) -> Command {
+        CommandBuilder::new()
+            .name(name)
+            .kind(CommandKind::ChatInput)
+            .description("A command")
+            .option(
+                CommandOptionBuilder::new()
+                    .kind(CommandOptionKind::String)
+                    .name("pick")
+                    .description("Pick one")
+                    .choice(
+                        CommandOptionChoiceBuilder::new()
+                            .name("Choice")
+                            .value(ChoiceValue::String(choice_value.to_string()))
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn exports_builder_valid_multi_byte_choices() {
+        let cmds = [command("kanji", &"日".repeat(60))];
+        let json = export_commands(&cmds).unwrap();
+        assert_eq!(parse_manifest(&json, ManifestFormat::Json).unwrap(), cmds);
+    }
+
+    #[test]
+    fn exports_sorted_commands_and_keys() {
+        let json = export_commands(&[command("b", "x"), command("a", "y")]).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["name"], "a");
+        assert_eq!(value[1]["name"], "b");
+
+        let keys =
+            |value: &Value| -> Vec<String> { value.as_object().unwrap().keys().cloned().collect() };
+        let mut sorted = keys(&value[0]);
+        sorted.sort();
+        assert_eq!(keys(&value[0]), sorted);
+        // Keys of nested objects are sorted too.
+        let option = &value[0]["options"][0];
+        let mut sorted = keys(option);
+        sorted.sort();
+        assert_eq!(keys(option), sorted);
+    }
+
+    /// Parses a JSON manifest with a single command, returning the error validating it.
+    fn invalid_command(command: &str) -> CommandError {
+        match parse_manifest(&format!("[{}]", command), ManifestFormat::Json) {
+            Err(ManifestError::InvalidCommand { source, .. }) => source,
+            other => panic!("expected an invalid command, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_valid_nested_manifest() {
+        let manifest = r#"
+            [[commands]]
+            name = "user"
+            description = "Manage users"
+
+            [[commands.options]]
+            type = 1
+            name = "add"
+            description = "Add a user"
+
+            [[commands.options.options]]
+            type = 6
+            name = "target"
+            description = "The user to add"
+            required = true
+
+            [[commands.options.options]]
+            type = 3
+            name = "reason"
+            description = "Why the user is added"
+        "#;
+        let cmds = parse_manifest(manifest, ManifestFormat::Toml).unwrap();
+        assert_eq!(cmds[0].options().unwrap()[0].options().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejects_manifests_breaking_builder_rules() {
+        assert!(matches!(
+            invalid_command(r#"{"name": "Roll", "description": "Roll a die"}"#),
+            CommandError::NameNotLowercase { .. }
+        ));
+        assert!(matches!(
+            invalid_command(r#"{"name": "roll dice", "description": "Roll a die"}"#),
+            CommandError::NameInvalidCharacters { .. }
+        ));
+
+        let options: Vec<String> = (0..26)
+            .map(|i| {
+                format!(
+                    r#"{{"type": 3, "name": "o{}", "description": "Option"}}"#,
+                    i
+                )
+            })
+            .collect();
+        assert!(matches!(
+            invalid_command(&format!(
+                r#"{{"name": "roll", "description": "Roll a die", "options": [{}]}}"#,
+                options.join(",")
+            )),
+            CommandError::TooManyOptions { count: 26, .. }
+        ));
+
+        assert!(matches!(
+            invalid_command(
+                r#"{"name": "roll", "description": "Roll a die", "options": [
+                    {"type": 4, "name": "sides", "description": "Sides"},
+                    {"type": 4, "name": "count", "description": "Dice", "required": true}
+                ]}"#
+            ),
+            CommandError::RequiredAfterOptional { name } if name == "count"
+        ));
+
+        assert!(matches!(
+            invalid_command(
+                r#"{"name": "user", "description": "Manage users", "options": [
+                    {"type": 1, "name": "add", "description": "Add a user", "options": [
+                        {"type": 1, "name": "now", "description": "Add now"}
+                    ]}
+                ]}"#
+            ),
+            CommandError::InvalidNesting { name, .. } if name == "now"
+        ));
+
+        assert!(matches!(
+            invalid_command(
+                r#"{"name": "color", "description": "Pick a color", "options": [
+                    {"type": 3, "name": "color", "description": "Color", "choices": [
+                        {"name": "", "value": "red"}
+                    ]}
+                ]}"#
+            ),
+            CommandError::NameLength { .. }
+        ));
+    }
+}
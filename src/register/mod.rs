@@ -1,5 +1,17 @@
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod command;
+mod error;
+mod guild;
+mod manifest;
 mod register;
+mod registry;
+mod sync;
 
 pub use command::*;
+pub use error::*;
+pub use guild::*;
+pub use manifest::*;
 pub use register::*;
+pub use registry::*;
+pub use sync::*;
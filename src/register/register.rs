@@ -1,52 +1,381 @@
-use std::error::Error;
-
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 
-use super::Command;
-use crate::DISCORD_API_VERSION;
+use super::{check_response, Command, CommandError, RegisteredCommand, RegistrationError};
+use tracing::info;
+
+use crate::{http::HttpClient, types::CommandKind};
+
+/// The maximum number of chat input commands of an application, globally or per guild.
+const MAX_CHAT_INPUT_COMMANDS: usize = 100;
+/// The maximum number of user or message commands of an application, globally or per guild.
+const MAX_CONTEXT_MENU_COMMANDS: usize = 5;
+/// The maximum number of primary entry point commands of an application.
+const MAX_ENTRY_POINT_COMMANDS: usize = 1;
+
+/// Describes the result of a successful call to [`register_commands`].
+///
+/// # Fields
+/// - `submitted`: The number of commands sent to Discord.
+/// - `commands`: The commands as registered by Discord, including their IDs.
+#[non_exhaustive]
+pub struct RegisterOutcome {
+    submitted: usize,
+    commands: Vec<RegisteredCommand>,
+}
+
+impl RegisterOutcome {
+    /// Creates a new `RegisterOutcome` from the number of submitted commands and the response.
+    pub(crate) fn new(submitted: usize, commands: Vec<RegisteredCommand>) -> Self {
+        Self {
+            submitted,
+            commands,
+        }
+    }
+
+    /// Returns the number of commands sent to Discord.
+    pub fn submitted(&self) -> usize {
+        self.submitted
+    }
+
+    /// Returns the number of commands Discord reports as registered.
+    pub fn registered(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns the commands as registered by Discord.
+    pub fn commands(&self) -> &[RegisteredCommand] {
+        &self.commands
+    }
+
+    /// Consumes the outcome, returning the commands as registered by Discord.
+    pub fn into_commands(self) -> Vec<RegisteredCommand> {
+        self.commands
+    }
+}
+
+/// Returns the endpoint for the global commands of the specified application.
+pub(crate) fn commands_endpoint(http: &HttpClient, app_id: &str) -> String {
+    http.url(&format!("applications/{}/commands", app_id))
+}
+
+/// Returns the endpoint for the commands of the specified application in a guild.
+pub(crate) fn guild_commands_endpoint(http: &HttpClient, app_id: &str, guild_id: &str) -> String {
+    http.url(&format!(
+        "applications/{}/guilds/{}/commands",
+        app_id, guild_id
+    ))
+}
+
+/// Returns the headers used to authenticate requests with a bot token.
+pub(crate) fn auth_headers(token: &str) -> Result<HeaderMap, RegistrationError> {
+    let mut headers = HeaderMap::new();
+    headers.append(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    headers.append(
+        "Authorization",
+        HeaderValue::from_str(&format!("Bot {}", token))?,
+    );
+    Ok(headers)
+}
 
 /// Registers Discord commands with the specified application.
 ///
+/// This overwrites every global command of the application and returns a summary with the
+/// commands as registered by Discord, including their IDs. Progress is reported through
+/// `tracing` events rather than printed.
+///
+/// `cmds` can be any iterator, so generated command sets do not have to be collected up
+/// front. Each command is validated as it is consumed, and consumption stops at the first
+/// invalid command or once Discord's limit on the number of commands of a type is exceeded.
+/// Since every bulk overwrite replaces the whole set, the commands are sent in a single
+/// request.
+///
 /// # Arguments
 ///
 /// * `app_id` - The Discord application ID.
 /// * `token` - The bot token for authentication.
-/// * `cmds` - A vector of commands to be registered.
+/// * `cmds` - The commands to be registered.
 ///
 /// # Errors
 ///
-/// Returns an error if the request to register commands fails.
+/// Returns `RegistrationError::InvalidCommand` without sending a request if a command is
+/// invalid or there are too many commands of a type. Returns another error if the request
+/// to register commands fails. If Discord rejects the commands, the error contains the
+/// status code and the paths of the offending fields.
 pub async fn register_commands(
     app_id: &str,
     token: &str,
-    cmds: Vec<Command>,
-) -> Result<(), Box<dyn Error>> {
-    let endpoint = format!(
-        "https://discord.com/api/{}/applications/{}/commands",
-        DISCORD_API_VERSION, app_id
+    cmds: impl IntoIterator<Item = Command>,
+) -> Result<RegisterOutcome, RegistrationError> {
+    register_commands_with(&HttpClient::new(), app_id, token, cmds).await
+}
+
+/// Registers Discord commands with the specified application using `http`.
+///
+/// Unlike [`register_commands`], which creates a new client for every call, this reuses the
+/// connection pool and configuration of `http`. Rate limited requests are retried according
+/// to its retry policy.
+///
+/// # Errors
+///
+/// Returns an error if a command is invalid, if the request to register commands fails, if
+/// it is still rate limited after all retries were used, or if the response cannot be
+/// deserialized.
+pub async fn register_commands_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    cmds: impl IntoIterator<Item = Command>,
+) -> Result<RegisterOutcome, RegistrationError> {
+    let cmds = collect_commands(cmds)?;
+    let endpoint = commands_endpoint(http, app_id);
+    let commands = overwrite_commands(http, &endpoint, token, &cmds).await?;
+    info!(
+        app_id,
+        submitted = cmds.len(),
+        registered = commands.len(),
+        "registered commands"
     );
+    Ok(RegisterOutcome::new(cmds.len(), commands))
+}
 
-    let client = reqwest::Client::new();
+/// Registers Discord commands with the specified application in a guild.
+///
+/// This overwrites every command of the application in the guild. Guild commands are
+/// available immediately, which makes them useful for testing.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The bot token for authentication.
+/// * `guild_id` - The ID of the guild to register the commands in.
+/// * `cmds` - The commands to be registered. These are validated as they are consumed, like
+///   in [`register_commands`].
+///
+/// # Errors
+///
+/// Returns an error if a command is invalid or if the request to register commands fails.
+pub async fn register_guild_commands(
+    app_id: &str,
+    token: &str,
+    guild_id: &str,
+    cmds: impl IntoIterator<Item = Command>,
+) -> Result<RegisterOutcome, RegistrationError> {
+    register_guild_commands_with(&HttpClient::new(), app_id, token, guild_id, cmds).await
+}
 
-    let mut headers = HeaderMap::new();
-    headers.append(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-    headers.append(
-        "Authorization",
-        HeaderValue::from_str(&format!("Bot {}", token))?,
+/// Registers Discord commands with the specified application in a guild using `http`.
+///
+/// # Errors
+///
+/// Returns `RegistrationError::InvalidCommand` without sending a request if a command is
+/// invalid or there are too many commands of a type. Returns another error if the request
+/// to register commands fails, if it is still rate limited after all retries were used, or
+/// if the response cannot be deserialized.
+pub async fn register_guild_commands_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    guild_id: &str,
+    cmds: impl IntoIterator<Item = Command>,
+) -> Result<RegisterOutcome, RegistrationError> {
+    let cmds = collect_commands(cmds)?;
+    let endpoint = guild_commands_endpoint(http, app_id, guild_id);
+    let commands = overwrite_commands(http, &endpoint, token, &cmds).await?;
+    info!(
+        app_id,
+        guild_id,
+        submitted = cmds.len(),
+        registered = commands.len(),
+        "registered guild commands"
     );
+    Ok(RegisterOutcome::new(cmds.len(), commands))
+}
+
+/// Fetches the global commands currently registered for the specified application.
+///
+/// This is useful for auditing the registered commands against the local definitions.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The bot token for authentication.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or if the response cannot be deserialized.
+pub async fn get_commands(
+    app_id: &str,
+    token: &str,
+) -> Result<Vec<RegisteredCommand>, RegistrationError> {
+    get_commands_with(&HttpClient::new(), app_id, token).await
+}
+
+/// Fetches the global commands currently registered for the specified application using
+/// `http`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, if it is still rate limited after all retries
+/// were used, or if the response cannot be deserialized.
+pub async fn get_commands_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+) -> Result<Vec<RegisteredCommand>, RegistrationError> {
+    // Localizations are only included when requested, and without them every localized
+    // command would differ from its local definition.
+    let request = http
+        .inner()
+        .get(commands_endpoint(http, app_id))
+        .query(&[("with_localizations", true)])
+        .headers(auth_headers(token)?);
+    let response = http.send(request).await?;
+
+    let response = check_response(response).await?;
+    Ok(response.json::<Vec<RegisteredCommand>>().await?)
+}
 
-    let response = client
-        .put(&endpoint)
-        .headers(headers)
-        .json(&cmds)
-        .send()
-        .await?;
+/// Removes every global command of the specified application.
+///
+/// This is useful when decommissioning an application or cleaning up after tests.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The bot token for authentication.
+///
+/// # Errors
+///
+/// Returns an error if the request fails.
+pub async fn clear_commands(app_id: &str, token: &str) -> Result<(), RegistrationError> {
+    clear_commands_with(&HttpClient::new(), app_id, token).await
+}
+
+/// Removes every global command of the specified application using `http`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, or if it is still rate limited after all retries
+/// were used.
+pub async fn clear_commands_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+) -> Result<(), RegistrationError> {
+    let endpoint = commands_endpoint(http, app_id);
+    overwrite_commands(http, &endpoint, token, &[]).await?;
+    info!(app_id, "cleared commands");
+    Ok(())
+}
 
-    if response.status().is_success() {
-        println!("Commands registered");
+/// Removes every command of the specified application in a guild.
+///
+/// This is useful when cleaning up test guilds.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The bot token for authentication.
+/// * `guild_id` - The ID of the guild to remove the commands from.
+///
+/// # Errors
+///
+/// Returns an error if the request fails.
+pub async fn clear_guild_commands(
+    app_id: &str,
+    token: &str,
+    guild_id: &str,
+) -> Result<(), RegistrationError> {
+    clear_guild_commands_with(&HttpClient::new(), app_id, token, guild_id).await
+}
+
+/// Removes every command of the specified application in a guild using `http`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, or if it is still rate limited after all retries
+/// were used.
+pub async fn clear_guild_commands_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    guild_id: &str,
+) -> Result<(), RegistrationError> {
+    let endpoint = guild_commands_endpoint(http, app_id, guild_id);
+    overwrite_commands(http, &endpoint, token, &[]).await?;
+    info!(app_id, guild_id, "cleared guild commands");
+    Ok(())
+}
+
+/// Collects `cmds`, validating each command and the number of commands of each type.
+///
+/// This stops consuming `cmds` at the first error, so an oversized or endless iterator is
+/// never fully collected.
+pub(crate) fn collect_commands(
+    cmds: impl IntoIterator<Item = Command>,
+) -> Result<Vec<Command>, CommandError> {
+    let mut collected = Vec::new();
+    let mut counts = CommandCounts::default();
+    for cmd in cmds {
+        counts.add(&cmd)?;
+        collected.push(cmd);
+    }
+    Ok(collected)
+}
+
+/// Validates each command of `cmds` and the number of commands of each type.
+///
+/// This applies the same rules as [`collect_commands`] to commands that are already collected.
+pub(crate) fn validate_commands(cmds: &[Command]) -> Result<(), CommandError> {
+    let mut counts = CommandCounts::default();
+    cmds.iter().try_for_each(|cmd| counts.add(cmd))
+}
+
+/// Counts commands by type, enforcing Discord's limit on the number of commands of a type.
+#[derive(Default)]
+struct CommandCounts {
+    chat_input: usize,
+    user: usize,
+    message: usize,
+    entry_point: usize,
+}
+
+impl CommandCounts {
+    /// Validates `cmd` and counts it.
+    ///
+    /// # Errors
+    /// Returns an error if `cmd` is invalid or if there are too many commands of its type.
+    fn add(&mut self, cmd: &Command) -> Result<(), CommandError> {
+        cmd.validate()?;
+        let kind = *cmd.kind();
+        let (count, max) = match kind {
+            CommandKind::ChatInput => (&mut self.chat_input, MAX_CHAT_INPUT_COMMANDS),
+            CommandKind::User => (&mut self.user, MAX_CONTEXT_MENU_COMMANDS),
+            CommandKind::Message => (&mut self.message, MAX_CONTEXT_MENU_COMMANDS),
+            CommandKind::PrimaryEntryPoint => (&mut self.entry_point, MAX_ENTRY_POINT_COMMANDS),
+        };
+        *count += 1;
+        if *count > max {
+            return Err(CommandError::TooManyCommands { kind, max });
+        }
         Ok(())
-    } else {
-        let error_message = response.text().await?;
-        Err(format!("Error registering commands: {}", error_message).into())
     }
 }
+
+/// Replaces the commands at `endpoint` with `cmds`, returning them as registered by Discord.
+///
+/// Callers validate the commands beforehand, with [`collect_commands`] or [`validate_commands`].
+pub(crate) async fn overwrite_commands(
+    http: &HttpClient,
+    endpoint: &str,
+    token: &str,
+    cmds: &[Command],
+) -> Result<Vec<RegisteredCommand>, RegistrationError> {
+    let request = http
+        .inner()
+        .put(endpoint)
+        .headers(auth_headers(token)?)
+        .json(cmds);
+    let response = check_response(http.send(request).await?).await?;
+    Ok(response.json::<Vec<RegisteredCommand>>().await?)
+}
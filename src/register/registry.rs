@@ -0,0 +1,182 @@
+use super::{
+    auth_headers, check_response, clear_commands_with, commands_endpoint, get_commands_with,
+    guild_commands_endpoint, register_commands_with, register_guild_commands_with,
+    sync_commands_dry_run_with, sync_commands_with, Command, RegisterOutcome, RegisteredCommand,
+    RegistrationError, SyncReport, SyncSummary,
+};
+use crate::{http::HttpClient, types::GuildApplicationCommandPermissions};
+
+/// A client for managing the commands of an application.
+///
+/// The registry holds the application ID, the bot token, and the HTTP client, so they don't
+/// have to be passed to every call. Configure the base URL, retry policy, or underlying
+/// `reqwest::Client` through the [`HttpClient`] passed to [`CommandRegistry::with_http`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use serde_discord::register::{Command, CommandRegistry};
+///
+/// # async fn example(
+/// #     app_id: &str,
+/// #     token: &str,
+/// #     cmds: Vec<Command>,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let registry = CommandRegistry::new(app_id, token);
+/// let outcome = registry.register(cmds).await?;
+/// for cmd in registry.get().await? {
+///     println!("{} ({})", cmd.name(), cmd.id());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct CommandRegistry {
+    http: HttpClient,
+    app_id: String,
+    token: String,
+}
+
+impl CommandRegistry {
+    /// Creates a new `CommandRegistry` for the specified application.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - The Discord application ID.
+    /// * `token` - The bot token for authentication.
+    pub fn new(app_id: impl Into<String>, token: impl Into<String>) -> Self {
+        Self::with_http(HttpClient::new(), app_id, token)
+    }
+
+    /// Creates a new `CommandRegistry` for the specified application that sends its requests
+    /// with `http`.
+    pub fn with_http(
+        http: HttpClient,
+        app_id: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        Self {
+            http,
+            app_id: app_id.into(),
+            token: token.into(),
+        }
+    }
+
+    /// Returns the HTTP client used to send requests.
+    pub fn http(&self) -> &HttpClient {
+        &self.http
+    }
+
+    /// Returns the ID of the application.
+    pub fn app_id(&self) -> &str {
+        &self.app_id
+    }
+
+    /// Overwrites the global commands of the application with `cmds`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails. See [`register_commands`](super::register_commands).
+    pub async fn register(
+        &self,
+        cmds: impl IntoIterator<Item = Command>,
+    ) -> Result<RegisterOutcome, RegistrationError> {
+        register_commands_with(&self.http, &self.app_id, &self.token, cmds).await
+    }
+
+    /// Overwrites the commands of the application in a guild with `cmds`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn register_guild(
+        &self,
+        guild_id: &str,
+        cmds: impl IntoIterator<Item = Command>,
+    ) -> Result<RegisterOutcome, RegistrationError> {
+        register_guild_commands_with(&self.http, &self.app_id, &self.token, guild_id, cmds).await
+    }
+
+    /// Creates, updates, and deletes global commands so they match `cmds`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any request fails. See [`sync_commands`](super::sync_commands).
+    pub async fn sync(&self, cmds: &[Command]) -> Result<SyncSummary, RegistrationError> {
+        sync_commands_with(&self.http, &self.app_id, &self.token, cmds).await
+    }
+
+    /// Computes the changes [`sync`](Self::sync) would apply, without applying them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the registered commands fails. See
+    /// [`sync_commands_dry_run`](super::sync_commands_dry_run).
+    pub async fn sync_dry_run(&self, cmds: &[Command]) -> Result<SyncReport, RegistrationError> {
+        sync_commands_dry_run_with(&self.http, &self.app_id, &self.token, cmds).await
+    }
+
+    /// Fetches the global commands currently registered for the application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the response cannot be deserialized.
+    pub async fn get(&self) -> Result<Vec<RegisteredCommand>, RegistrationError> {
+        get_commands_with(&self.http, &self.app_id, &self.token).await
+    }
+
+    /// Deletes the global command with the given ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, e.g. because the command does not exist.
+    pub async fn delete(&self, command_id: &str) -> Result<(), RegistrationError> {
+        let url = format!(
+            "{}/{}",
+            commands_endpoint(&self.http, &self.app_id),
+            command_id
+        );
+        let request = self
+            .http
+            .inner()
+            .delete(url)
+            .headers(auth_headers(&self.token)?);
+        check_response(self.http.send(request).await?).await?;
+        Ok(())
+    }
+
+    /// Removes every global command of the application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn clear(&self) -> Result<(), RegistrationError> {
+        clear_commands_with(&self.http, &self.app_id, &self.token).await
+    }
+
+    /// Fetches the permission overwrites of the application's commands in a guild.
+    ///
+    /// Only commands with overwrites are included. Overwrites that apply to every command of
+    /// the application are listed under the application ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the response cannot be deserialized.
+    pub async fn permissions(
+        &self,
+        guild_id: &str,
+    ) -> Result<Vec<GuildApplicationCommandPermissions>, RegistrationError> {
+        let url = format!(
+            "{}/permissions",
+            guild_commands_endpoint(&self.http, &self.app_id, guild_id)
+        );
+        let request = self
+            .http
+            .inner()
+            .get(url)
+            .headers(auth_headers(&self.token)?);
+        let response = check_response(self.http.send(request).await?).await?;
+        Ok(response
+            .json::<Vec<GuildApplicationCommandPermissions>>()
+            .await?)
+    }
+}
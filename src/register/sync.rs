@@ -0,0 +1,516 @@
+use std::fmt;
+
+use serde_json::Value;
+
+use super::{
+    auth_headers, check_response, commands_endpoint, get_commands_with, validate_commands, Command,
+    RegisteredCommand, RegistrationError,
+};
+use crate::http::HttpClient;
+
+/// Summarizes the changes applied by [`sync_commands`].
+///
+/// Each list contains the names of the affected commands.
+///
+/// # Fields
+/// - `created`: Commands that were registered because they did not exist remotely.
+/// - `updated`: Commands that existed remotely but differed from the local definition.
+/// - `deleted`: Commands that existed remotely but not locally.
+/// - `unchanged`: Commands whose remote definition already matched the local one.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct SyncSummary {
+    created: Vec<String>,
+    updated: Vec<String>,
+    deleted: Vec<String>,
+    unchanged: Vec<String>,
+}
+
+impl SyncSummary {
+    /// Returns the names of the commands that were created.
+    pub fn created(&self) -> &[String] {
+        &self.created
+    }
+
+    /// Returns the names of the commands that were updated.
+    pub fn updated(&self) -> &[String] {
+        &self.updated
+    }
+
+    /// Returns the names of the commands that were deleted.
+    pub fn deleted(&self) -> &[String] {
+        &self.deleted
+    }
+
+    /// Returns the names of the commands that were left untouched.
+    pub fn unchanged(&self) -> &[String] {
+        &self.unchanged
+    }
+
+    /// Returns whether any command was created, updated, or deleted.
+    pub fn has_changes(&self) -> bool {
+        !(self.created.is_empty() && self.updated.is_empty() && self.deleted.is_empty())
+    }
+}
+
+/// Describes the changes [`sync_commands`] would apply, without applying them.
+///
+/// This is returned by [`sync_commands_dry_run`]. Its `Display` implementation renders a
+/// human-readable diff, which makes it suitable for reviewing changes in CI before a deploy.
+///
+/// # Fields
+/// - `commands`: The planned change of every local and remote command.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct SyncReport {
+    commands: Vec<CommandDiff>,
+}
+
+impl SyncReport {
+    /// Returns the planned change of every local and remote command.
+    pub fn commands(&self) -> &[CommandDiff] {
+        &self.commands
+    }
+
+    /// Returns the planned changes of the given kind.
+    pub fn changes(&self, kind: ChangeKind) -> impl Iterator<Item = &CommandDiff> {
+        self.commands.iter().filter(move |diff| diff.kind == kind)
+    }
+
+    /// Returns whether any command would be created, updated, or deleted.
+    pub fn has_changes(&self) -> bool {
+        self.commands
+            .iter()
+            .any(|diff| diff.kind != ChangeKind::Unchanged)
+    }
+}
+
+impl fmt::Display for SyncReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for diff in &self.commands {
+            let marker = match diff.kind {
+                ChangeKind::Create => '+',
+                ChangeKind::Update => '~',
+                ChangeKind::Delete => '-',
+                ChangeKind::Unchanged => continue,
+            };
+            writeln!(f, "{} {} `{}`", marker, diff.kind, diff.name)?;
+            for field in &diff.fields {
+                writeln!(f, "    {}", field)?;
+            }
+        }
+
+        let count = |kind| self.changes(kind).count();
+        write!(
+            f,
+            "{} to create, {} to update, {} to delete, {} unchanged",
+            count(ChangeKind::Create),
+            count(ChangeKind::Update),
+            count(ChangeKind::Delete),
+            count(ChangeKind::Unchanged)
+        )
+    }
+}
+
+/// The kind of change planned for a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// The command does not exist remotely and would be registered.
+    Create,
+    /// The command exists remotely but differs from the local definition.
+    Update,
+    /// The command exists remotely but not locally, and would be deleted.
+    Delete,
+    /// The remote definition of the command already matches the local one.
+    Unchanged,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            ChangeKind::Create => "create",
+            ChangeKind::Update => "update",
+            ChangeKind::Delete => "delete",
+            ChangeKind::Unchanged => "unchanged",
+        };
+        f.write_str(kind)
+    }
+}
+
+/// Describes the change planned for a single command.
+///
+/// # Fields
+/// - `name`: The name of the command.
+/// - `kind`: The kind of change.
+/// - `fields`: The fields that differ between the remote and local definitions. This is only
+///   populated for updates.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CommandDiff {
+    name: String,
+    kind: ChangeKind,
+    fields: Vec<FieldDiff>,
+}
+
+impl CommandDiff {
+    /// Returns the name of the command.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the kind of change.
+    pub fn kind(&self) -> ChangeKind {
+        self.kind
+    }
+
+    /// Returns the fields that differ between the remote and local definitions.
+    pub fn fields(&self) -> &[FieldDiff] {
+        &self.fields
+    }
+}
+
+/// Describes a field that differs between the remote and local definition of a command.
+///
+/// # Fields
+/// - `path`: The dot-separated path of the field, such as `options.0.description`.
+/// - `remote`: The remote value, or `None` if the field is only set locally.
+/// - `local`: The local value, or `None` if the field is only set remotely.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct FieldDiff {
+    path: String,
+    remote: Option<Value>,
+    local: Option<Value>,
+}
+
+impl FieldDiff {
+    /// Returns the dot-separated path of the field.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the remote value, or `None` if the field is only set locally.
+    pub fn remote(&self) -> Option<&Value> {
+        self.remote.as_ref()
+    }
+
+    /// Returns the local value, or `None` if the field is only set remotely.
+    pub fn local(&self) -> Option<&Value> {
+        self.local.as_ref()
+    }
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = |value: &Option<Value>| {
+            value
+                .as_ref()
+                .map_or_else(|| "(unset)".to_string(), Value::to_string)
+        };
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            value(&self.remote),
+            value(&self.local)
+        )
+    }
+}
+
+/// A single change required to bring the remote commands in line with the local ones.
+enum CommandChange<'a> {
+    Create(&'a Command),
+    Update(&'a RegisteredCommand, &'a Command),
+    Delete(&'a RegisteredCommand),
+    Unchanged(&'a Command),
+}
+
+/// Synchronizes the global commands of the specified application with `cmds`.
+///
+/// Unlike [`register_commands`](super::register_commands), which overwrites every command,
+/// this function fetches the registered commands, compares them with the local definitions,
+/// and only creates, edits, or deletes the commands that differ. Commands are matched by
+/// their name and type. Top-level fields left unset locally are not compared, since Discord
+/// fills in defaults for them and editing a command leaves them unchanged.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The bot token for authentication.
+/// * `cmds` - The local command definitions.
+///
+/// # Errors
+///
+/// Returns an error if fetching the registered commands or any of the mutating requests
+/// fails. Changes applied before the failing request are not rolled back.
+pub async fn sync_commands(
+    app_id: &str,
+    token: &str,
+    cmds: &[Command],
+) -> Result<SyncSummary, RegistrationError> {
+    sync_commands_with(&HttpClient::new(), app_id, token, cmds).await
+}
+
+/// Synchronizes the global commands of the specified application with `cmds` using `http`.
+///
+/// See [`sync_commands`] for details. Since every change is sent as a separate request,
+/// rate limits are likely to be hit when many commands change; those requests are retried
+/// according to the retry policy of `http`.
+///
+/// # Errors
+///
+/// Returns `RegistrationError::InvalidCommand` without sending a request if a command is
+/// invalid or there are too many commands of a type. Returns another error if fetching the
+/// registered commands or any of the mutating requests fails. Changes applied before the
+/// failing request are not rolled back.
+pub async fn sync_commands_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    cmds: &[Command],
+) -> Result<SyncSummary, RegistrationError> {
+    validate_commands(cmds)?;
+    let remote = get_commands_with(http, app_id, token).await?;
+    let changes = plan_changes(cmds, &remote)?;
+
+    let client = http.inner();
+    let endpoint = commands_endpoint(http, app_id);
+    let mut summary = SyncSummary::default();
+
+    for change in changes {
+        let request = match change {
+            CommandChange::Create(cmd) => {
+                summary.created.push(cmd.name().to_string());
+                client.post(&endpoint).json(cmd)
+            }
+            CommandChange::Update(registered, cmd) => {
+                summary.updated.push(cmd.name().to_string());
+                let url = format!("{}/{}", endpoint, registered.id());
+                client.patch(url).json(cmd)
+            }
+            CommandChange::Delete(registered) => {
+                summary.deleted.push(registered.name().to_string());
+                let url = format!("{}/{}", endpoint, registered.id());
+                client.delete(url)
+            }
+            CommandChange::Unchanged(cmd) => {
+                summary.unchanged.push(cmd.name().to_string());
+                continue;
+            }
+        };
+
+        let response = http.send(request.headers(auth_headers(token)?)).await?;
+        check_response(response).await?;
+    }
+
+    Ok(summary)
+}
+
+/// Computes the changes [`sync_commands`] would apply, without applying them.
+///
+/// This fetches the registered commands and compares them with the local definitions, but
+/// makes no mutating requests. Print the returned report to get a human-readable diff, or
+/// check [`SyncReport::has_changes`] to fail a CI job when the commands are out of date.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The bot token for authentication.
+/// * `cmds` - The local command definitions.
+///
+/// # Errors
+///
+/// Returns an error if fetching the registered commands fails.
+///
+/// # Example
+///
+/// ```no_run
+/// use serde_discord::register::{sync_commands_dry_run, Command};
+///
+/// # async fn example(
+/// #     app_id: &str,
+/// #     token: &str,
+/// #     commands: Vec<Command>,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let report = sync_commands_dry_run(app_id, token, &commands).await?;
+/// println!("{}", report);
+/// if report.has_changes() {
+///     std::process::exit(1);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn sync_commands_dry_run(
+    app_id: &str,
+    token: &str,
+    cmds: &[Command],
+) -> Result<SyncReport, RegistrationError> {
+    sync_commands_dry_run_with(&HttpClient::new(), app_id, token, cmds).await
+}
+
+/// Computes the changes [`sync_commands`] would apply using `http`, without applying them.
+///
+/// # Errors
+///
+/// Returns `RegistrationError::InvalidCommand` without sending a request if a command is
+/// invalid or there are too many commands of a type. Returns another error if fetching the
+/// registered commands fails, if it is still rate limited after all retries were used, or if
+/// the response cannot be deserialized.
+pub async fn sync_commands_dry_run_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    cmds: &[Command],
+) -> Result<SyncReport, RegistrationError> {
+    validate_commands(cmds)?;
+    let remote = get_commands_with(http, app_id, token).await?;
+    let mut report = SyncReport::default();
+
+    for change in plan_changes(cmds, &remote)? {
+        let (name, kind, fields) = match change {
+            CommandChange::Create(cmd) => (cmd.name(), ChangeKind::Create, Vec::new()),
+            CommandChange::Update(registered, cmd) => {
+                let (remote, local) = comparable(registered.command(), cmd)?;
+                let mut fields = Vec::new();
+                diff_values(String::new(), Some(&remote), Some(&local), &mut fields);
+                (cmd.name(), ChangeKind::Update, fields)
+            }
+            CommandChange::Delete(registered) => {
+                (registered.name(), ChangeKind::Delete, Vec::new())
+            }
+            CommandChange::Unchanged(cmd) => (cmd.name(), ChangeKind::Unchanged, Vec::new()),
+        };
+        report.commands.push(CommandDiff {
+            name: name.to_string(),
+            kind,
+            fields,
+        });
+    }
+
+    Ok(report)
+}
+
+/// Collects the leaf fields that differ between `remote` and `local` into `diffs`.
+///
+/// Objects are compared key by key and arrays index by index, so a changed option is
+/// reported at the path of the field that changed rather than as a whole.
+fn diff_values(
+    path: String,
+    remote: Option<&Value>,
+    local: Option<&Value>,
+    diffs: &mut Vec<FieldDiff>,
+) {
+    let join = |key: &dyn fmt::Display| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+
+    match (remote, local) {
+        (Some(Value::Object(remote)), Some(Value::Object(local))) => {
+            let mut keys: Vec<&String> = remote.keys().chain(local.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                diff_values(join(key), remote.get(key), local.get(key), diffs);
+            }
+        }
+        (Some(Value::Array(remote)), Some(Value::Array(local))) => {
+            for i in 0..remote.len().max(local.len()) {
+                diff_values(join(&i), remote.get(i), local.get(i), diffs);
+            }
+        }
+        (remote, local) if remote != local => diffs.push(FieldDiff {
+            path,
+            remote: remote.cloned(),
+            local: local.cloned(),
+        }),
+        _ => (),
+    }
+}
+
+/// Computes the changes required to turn `remote` into `local`.
+fn plan_changes<'a>(
+    local: &'a [Command],
+    remote: &'a [RegisteredCommand],
+) -> Result<Vec<CommandChange<'a>>, serde_json::Error> {
+    let mut changes = Vec::new();
+
+    for cmd in local {
+        let registered = remote
+            .iter()
+            .find(|r| r.name() == cmd.name() && r.kind() == cmd.kind());
+        match registered {
+            Some(registered) => {
+                let (remote, local) = comparable(registered.command(), cmd)?;
+                if remote == local {
+                    changes.push(CommandChange::Unchanged(cmd));
+                } else {
+                    changes.push(CommandChange::Update(registered, cmd));
+                }
+            }
+            None => changes.push(CommandChange::Create(cmd)),
+        }
+    }
+
+    for registered in remote {
+        let exists = local
+            .iter()
+            .any(|cmd| cmd.name() == registered.name() && cmd.kind() == registered.kind());
+        if !exists {
+            changes.push(CommandChange::Delete(registered));
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Serializes the remote and local definitions of a command into canonical JSON values for
+/// structural comparison.
+///
+/// Discord fills in defaults for the top-level fields a command was registered without, such
+/// as `integration_types` and `contexts`. Since editing a command leaves the fields missing
+/// from the request unchanged, only the top-level fields set locally are compared. Nested
+/// fields are always compared, because the `options` are replaced as a whole.
+fn comparable(remote: &Command, local: &Command) -> Result<(Value, Value), serde_json::Error> {
+    let mut remote = serde_json::to_value(remote)?;
+    let mut local = serde_json::to_value(local)?;
+    if let (Value::Object(remote), Value::Object(local)) = (&mut remote, &local) {
+        remote.retain(|key, _| local.contains_key(key));
+    }
+    normalize(&mut remote);
+    normalize(&mut local);
+    Ok((remote, local))
+}
+
+/// Removes the differences between a local definition and Discord's representation of it
+/// that do not change the meaning of the command.
+///
+/// Discord fills in defaults for omitted fields, so `null`s, empty lists and maps, and
+/// `false` flags are stripped, and integral floats are turned into integers since `1` and
+/// `1.0` are equivalent.
+fn normalize(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, v| match v {
+                Value::Null | Value::Bool(false) => false,
+                Value::Array(items) => !items.is_empty(),
+                Value::Object(fields) => !fields.is_empty(),
+                _ => true,
+            });
+            map.values_mut().for_each(normalize);
+        }
+        Value::Array(items) => items.iter_mut().for_each(normalize),
+        Value::Number(n) => {
+            if let Some(float) = n
+                .as_f64()
+                .filter(|f| f.fract() == 0.0 && f.abs() < 2f64.powi(63))
+            {
+                *n = serde_json::Number::from(float as i64);
+            }
+        }
+        _ => (),
+    }
+}
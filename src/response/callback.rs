@@ -0,0 +1,263 @@
+use serde::Deserialize;
+use tracing::info;
+
+use super::{
+    followup::SentMessage,
+    request::{execute, with_payload},
+    InteractionResponse, ResponseError,
+};
+use crate::{http::HttpClient, types::Snowflake};
+
+/// Represents what Discord returns when an interaction is responded to with
+/// `?with_response=true`.
+///
+/// # Fields
+/// - `interaction`: The interaction that was responded to.
+/// - `resource`: The resource created by the response, if any.
+#[derive(Deserialize, Debug)]
+#[non_exhaustive]
+pub struct InteractionCallbackResponse {
+    interaction: InteractionCallback,
+    #[serde(default)]
+    resource: Option<InteractionCallbackResource>,
+}
+
+impl InteractionCallbackResponse {
+    pub fn interaction(&self) -> &InteractionCallback {
+        &self.interaction
+    }
+
+    pub fn resource(&self) -> Option<&InteractionCallbackResource> {
+        self.resource.as_ref()
+    }
+
+    /// Returns the message created by the response, if any.
+    pub fn message(&self) -> Option<&SentMessage> {
+        self.resource.as_ref()?.message()
+    }
+}
+
+/// Represents the interaction an `InteractionCallbackResponse` is for.
+///
+/// # Fields
+/// - `id`: The ID of the interaction.
+/// - `kind`: The type of the interaction.
+/// - `activity_instance_id`: The ID of the Activity instance launched by the response, if any.
+/// - `response_message_id`: The ID of the message created by the response, if any.
+/// - `response_message_loading`: Whether the message is in a loading state.
+/// - `response_message_ephemeral`: Whether the message is ephemeral.
+#[derive(Deserialize, Debug)]
+#[non_exhaustive]
+pub struct InteractionCallback {
+    id: Snowflake,
+    #[serde(rename = "type")]
+    kind: u8,
+    #[serde(default)]
+    activity_instance_id: Option<String>,
+    #[serde(default)]
+    response_message_id: Option<Snowflake>,
+    #[serde(default)]
+    response_message_loading: Option<bool>,
+    #[serde(default)]
+    response_message_ephemeral: Option<bool>,
+}
+
+impl InteractionCallback {
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the raw type of the interaction.
+    pub fn kind(&self) -> u8 {
+        self.kind
+    }
+
+    pub fn activity_instance_id(&self) -> Option<&str> {
+        self.activity_instance_id.as_deref()
+    }
+
+    pub fn response_message_id(&self) -> Option<Snowflake> {
+        self.response_message_id
+    }
+
+    pub fn response_message_loading(&self) -> bool {
+        self.response_message_loading.unwrap_or(false)
+    }
+
+    pub fn response_message_ephemeral(&self) -> bool {
+        self.response_message_ephemeral.unwrap_or(false)
+    }
+}
+
+/// Represents the resource created by an interaction response.
+///
+/// # Fields
+/// - `kind`: The type of the interaction response that created the resource.
+/// - `activity_instance`: The launched Activity instance, for `LaunchActivity` responses.
+/// - `message`: The created message, for message responses.
+#[derive(Deserialize, Debug)]
+#[non_exhaustive]
+pub struct InteractionCallbackResource {
+    #[serde(rename = "type")]
+    kind: u8,
+    #[serde(default)]
+    activity_instance: Option<ActivityInstance>,
+    #[serde(default)]
+    message: Option<SentMessage>,
+}
+
+impl InteractionCallbackResource {
+    /// Returns the raw type of the interaction response that created the resource.
+    pub fn kind(&self) -> u8 {
+        self.kind
+    }
+
+    pub fn activity_instance(&self) -> Option<&ActivityInstance> {
+        self.activity_instance.as_ref()
+    }
+
+    pub fn message(&self) -> Option<&SentMessage> {
+        self.message.as_ref()
+    }
+}
+
+/// Represents an Activity instance launched by an interaction response.
+#[derive(Deserialize, Debug)]
+#[non_exhaustive]
+pub struct ActivityInstance {
+    id: String,
+}
+
+impl ActivityInstance {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Returns the callback endpoint of the interaction with the given ID and token.
+fn callback_endpoint(http: &HttpClient, interaction_id: Snowflake, token: &str) -> String {
+    http.url(&format!(
+        "interactions/{}/{}/callback",
+        interaction_id, token
+    ))
+}
+
+/// Responds to an interaction through its callback endpoint.
+///
+/// Bots that receive interactions over the gateway must respond this way, while bots that
+/// receive them through an outgoing webhook can also return the response as the body of the
+/// webhook. Responses with files are sent as a `multipart/form-data` request.
+///
+/// # Arguments
+///
+/// * `interaction_id` - The ID of the interaction.
+/// * `token` - The token of the interaction.
+/// * `response` - The response to send.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or if Discord rejects the response.
+///
+/// # Example
+///
+/// ```no_run
+/// use serde_discord::response::{data::MessageBuilder, send, InteractionResponse};
+/// # use serde_discord::types::Snowflake;
+///
+/// # async fn example(interaction_id: Snowflake, token: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let msg = MessageBuilder::new().content("Pong!").build()?;
+/// send(interaction_id, token, &InteractionResponse::Message(msg)).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn send(
+    interaction_id: Snowflake,
+    token: &str,
+    response: &InteractionResponse,
+) -> Result<(), ResponseError> {
+    send_with(&HttpClient::new(), interaction_id, token, response).await
+}
+
+/// Responds to an interaction through its callback endpoint using `http`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, or if it is still rate limited after all retries
+/// were used.
+pub async fn send_with(
+    http: &HttpClient,
+    interaction_id: Snowflake,
+    token: &str,
+    response: &InteractionResponse,
+) -> Result<(), ResponseError> {
+    let request = http
+        .inner()
+        .post(callback_endpoint(http, interaction_id, token));
+    let request = with_payload(request, response, response.files())?;
+    execute(http, request).await?;
+    info!(%interaction_id, "sent interaction response");
+    Ok(())
+}
+
+/// Responds to an interaction through its callback endpoint, returning the created resource.
+///
+/// Unlike [`send`], the request is sent with `?with_response=true`, so the ID of the
+/// response message is known without fetching it afterwards. Responses with files are sent
+/// as a `multipart/form-data` request.
+///
+/// # Arguments
+///
+/// * `interaction_id` - The ID of the interaction.
+/// * `token` - The token of the interaction.
+/// * `response` - The response to send.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or if Discord rejects the response.
+///
+/// # Example
+///
+/// ```no_run
+/// use serde_discord::response::{data::Message, send_and_fetch, InteractionResponse};
+/// # use serde_discord::types::Snowflake;
+///
+/// # async fn example(interaction_id: Snowflake, token: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let response = InteractionResponse::Message(Message::ephemeral("Working on it...")?);
+/// let callback = send_and_fetch(interaction_id, token, &response).await?;
+/// let message_id = callback.interaction().response_message_id();
+/// # Ok(())
+/// # }
+/// ```
+pub async fn send_and_fetch(
+    interaction_id: Snowflake,
+    token: &str,
+    response: &InteractionResponse,
+) -> Result<InteractionCallbackResponse, ResponseError> {
+    send_and_fetch_with(&HttpClient::new(), interaction_id, token, response).await
+}
+
+/// Responds to an interaction through its callback endpoint using `http`, returning the
+/// created resource.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, if it is still rate limited after all retries
+/// were used, or if the response cannot be deserialized.
+pub async fn send_and_fetch_with(
+    http: &HttpClient,
+    interaction_id: Snowflake,
+    token: &str,
+    response: &InteractionResponse,
+) -> Result<InteractionCallbackResponse, ResponseError> {
+    let request = http
+        .inner()
+        .post(callback_endpoint(http, interaction_id, token))
+        .query(&[("with_response", true)]);
+    let request = with_payload(request, response, response.files())?;
+    let callback = execute(http, request)
+        .await?
+        .json::<InteractionCallbackResponse>()
+        .await?;
+    info!(%interaction_id, "sent interaction response");
+    Ok(callback)
+}
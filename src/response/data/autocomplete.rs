@@ -1,5 +1,221 @@
+use std::error::Error;
+
 use serde::Serialize;
 
+use crate::types::LocalizationMap;
+
+/// The maximum number of autocomplete choices.
+const MAX_CHOICES: usize = 25;
+/// The maximum length of the name and string value of an autocomplete choice.
+const MAX_CHOICE_LENGTH: usize = 100;
+
+/// Represents the value of an autocomplete choice.
+///
+/// The type of the value must match the type of the option being autocompleted.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum AutocompleteValue {
+    String(String),
+    Integer(i64),
+    Number(f64),
+}
+
+impl From<&str> for AutocompleteValue {
+    fn from(value: &str) -> Self {
+        AutocompleteValue::String(value.to_string())
+    }
+}
+
+impl From<String> for AutocompleteValue {
+    fn from(value: String) -> Self {
+        AutocompleteValue::String(value)
+    }
+}
+
+impl From<i32> for AutocompleteValue {
+    fn from(value: i32) -> Self {
+        AutocompleteValue::Integer(value.into())
+    }
+}
+
+impl From<i64> for AutocompleteValue {
+    fn from(value: i64) -> Self {
+        AutocompleteValue::Integer(value)
+    }
+}
+
+impl From<f64> for AutocompleteValue {
+    fn from(value: f64) -> Self {
+        AutocompleteValue::Number(value)
+    }
+}
+
+/// Represents a suggestion shown to the user while they fill in an option.
+///
+/// Discord shows the name matching the locale of the user if there is one in
+/// `name_localizations`, and `name` otherwise.
 #[derive(Serialize)]
-// TODO: implement this struct
-pub struct Autocomplete;
+#[non_exhaustive]
+pub struct AutocompleteChoice {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<LocalizationMap>,
+    value: AutocompleteValue,
+}
+
+impl AutocompleteChoice {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn name_localizations(&self) -> Option<&LocalizationMap> {
+        self.name_localizations.as_ref()
+    }
+
+    pub fn value(&self) -> &AutocompleteValue {
+        &self.value
+    }
+}
+
+/// Represents the suggestions sent in response to an autocomplete interaction.
+#[derive(Serialize)]
+#[non_exhaustive]
+pub struct Autocomplete {
+    choices: Vec<AutocompleteChoice>,
+}
+
+impl Autocomplete {
+    pub fn choices(&self) -> &[AutocompleteChoice] {
+        &self.choices
+    }
+}
+
+/// A builder for creating an `Autocomplete`.
+///
+/// Discord rejects the whole response if it has more than 25 choices, so results of a
+/// search can be passed as is and cut down with [`truncate`](Self::truncate).
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::response::data::AutocompleteBuilder;
+///
+/// # struct City { name: String, id: i64 }
+/// # let matching_cities = vec![City { name: "Paris".to_string(), id: 1 }];
+/// let autocomplete = AutocompleteBuilder::new()
+///     .choices(matching_cities.iter().map(|city| (city.name.clone(), city.id)))
+///     .truncate()
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct AutocompleteBuilder {
+    choices: Vec<AutocompleteChoice>,
+    truncate: bool,
+}
+
+impl AutocompleteBuilder {
+    pub fn new() -> Self {
+        Self {
+            choices: vec![],
+            truncate: false,
+        }
+    }
+
+    pub fn choice(mut self, name: impl Into<String>, value: impl Into<AutocompleteValue>) -> Self {
+        self.choices.push(AutocompleteChoice {
+            name: name.into(),
+            name_localizations: None,
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Adds a choice whose name is shown in the locale of the user, falling back to `name`
+    /// for locales missing from `name_localizations`.
+    pub fn localized_choice(
+        mut self,
+        name: impl Into<String>,
+        name_localizations: LocalizationMap,
+        value: impl Into<AutocompleteValue>,
+    ) -> Self {
+        self.choices.push(AutocompleteChoice {
+            name: name.into(),
+            name_localizations: Some(name_localizations),
+            value: value.into(),
+        });
+        self
+    }
+
+    pub fn choices<N, V>(mut self, choices: impl IntoIterator<Item = (N, V)>) -> Self
+    where
+        N: Into<String>,
+        V: Into<AutocompleteValue>,
+    {
+        for (name, value) in choices {
+            self = self.choice(name, value);
+        }
+        self
+    }
+
+    /// Keeps only the first 25 choices instead of failing when there are more.
+    pub fn truncate(mut self) -> Self {
+        self.truncate = true;
+        self
+    }
+
+    /// Builds the `Autocomplete`.
+    ///
+    /// # Errors
+    /// Returns an error if there are more than 25 choices and [`truncate`](Self::truncate)
+    /// was not called, or if the name, a localized name, or the string value of a kept
+    /// choice is empty or longer than 100 characters.
+    pub fn build(mut self) -> Result<Autocomplete, Box<dyn Error>> {
+        if self.choices.len() > MAX_CHOICES {
+            if !self.truncate {
+                return Err("an autocomplete response can have at most 25 choices".into());
+            }
+            self.choices.truncate(MAX_CHOICES);
+        }
+
+        for choice in &self.choices {
+            let len = choice.name.chars().count();
+            if len == 0 || len > MAX_CHOICE_LENGTH {
+                return Err(format!(
+                    "name of choice {:?} must be between 1 and 100 characters",
+                    choice.name
+                )
+                .into());
+            }
+            for (locale, name) in choice.name_localizations.iter().flatten() {
+                let len = name.chars().count();
+                if len == 0 || len > MAX_CHOICE_LENGTH {
+                    return Err(format!(
+                        "{:?} name of choice {:?} must be between 1 and 100 characters",
+                        locale, choice.name
+                    )
+                    .into());
+                }
+            }
+            if let AutocompleteValue::String(value) = &choice.value {
+                let len = value.chars().count();
+                if len == 0 || len > MAX_CHOICE_LENGTH {
+                    return Err(format!(
+                        "value of choice {:?} must be between 1 and 100 characters",
+                        choice.name
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok(Autocomplete {
+            choices: self.choices,
+        })
+    }
+}
+
+impl Default for AutocompleteBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,215 @@
+use reqwest::multipart::{Form, Part};
+use serde::{
+    ser::{SerializeSeq, SerializeStruct},
+    Serialize, Serializer,
+};
+
+use crate::response::MultipartError;
+
+/// The filename prefix that makes Discord blur a file until it is clicked.
+const SPOILER_PREFIX: &str = "SPOILER_";
+
+/// Represents a file uploaded together with a message.
+///
+/// Files are not part of the JSON payload. They are sent as `files[n]` parts of a
+/// `multipart/form-data` request, alongside the payload in a `payload_json` part, and
+/// referenced from the payload's `attachments` array by their index `n`. Embeds can show
+/// an uploaded image with an `attachment://<filename>` URL.
+///
+/// # Fields
+/// - `filename`: The name of the file, including its extension.
+/// - `description`: The description (alt text) of the file.
+/// - `content_type`: The media type of the file, such as `image/png`.
+/// - `duration_secs`: The duration of the audio, for voice messages.
+/// - `waveform`: The base64 encoded waveform of the audio, for voice messages.
+/// - `data`: The contents of the file.
+#[non_exhaustive]
+pub struct Attachment {
+    filename: String,
+    description: Option<String>,
+    content_type: Option<String>,
+    duration_secs: Option<f64>,
+    waveform: Option<String>,
+    data: Vec<u8>,
+}
+
+impl Attachment {
+    /// Creates an attachment with the given filename and contents.
+    pub fn new(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            filename: filename.into(),
+            description: None,
+            content_type: None,
+            duration_secs: None,
+            waveform: None,
+            data: data.into(),
+        }
+    }
+
+    /// Sets the description (alt text) of the file.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the media type of the file. Discord infers it from the filename if unset.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Sets the duration and waveform shown by the player of a voice message.
+    ///
+    /// The waveform is a base64 encoded byte array of volume samples, at most one per 100
+    /// milliseconds of audio.
+    pub fn voice(mut self, duration_secs: f64, waveform: impl Into<String>) -> Self {
+        self.duration_secs = Some(duration_secs);
+        self.waveform = Some(waveform.into());
+        self
+    }
+
+    /// Marks the file as a spoiler by prefixing its filename with `SPOILER_`, unless it
+    /// already is one.
+    ///
+    /// Embeds showing the file must use the prefixed filename in their `attachment://` URL.
+    pub fn spoiler(mut self) -> Self {
+        if !self.is_spoiler() {
+            self.filename = format!("{}{}", SPOILER_PREFIX, self.filename);
+        }
+        self
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn get_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn get_content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    pub fn duration_secs(&self) -> Option<f64> {
+        self.duration_secs
+    }
+
+    pub fn waveform(&self) -> Option<&str> {
+        self.waveform.as_deref()
+    }
+
+    /// Returns whether the file can be sent as a voice message, i.e. it has a duration and
+    /// waveform and, if its media type is set, it is an audio type.
+    pub fn is_voice(&self) -> bool {
+        self.duration_secs.is_some()
+            && self.waveform.is_some()
+            && self
+                .content_type
+                .as_deref()
+                .is_none_or(|content_type| content_type.starts_with("audio/"))
+    }
+
+    /// Returns whether the file is marked as a spoiler.
+    pub fn is_spoiler(&self) -> bool {
+        self.filename.starts_with(SPOILER_PREFIX)
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Converts the file into the `files[n]` part of a multipart request.
+    fn to_part(&self) -> Result<Part, reqwest::Error> {
+        let part = Part::bytes(self.data.clone()).file_name(self.filename.clone());
+        match &self.content_type {
+            Some(content_type) => part.mime_str(content_type),
+            None => Ok(part),
+        }
+    }
+}
+
+/// Represents metadata set with `MessageBuilder::attachment` for the file with the given
+/// index, applied when the message is built.
+pub(super) struct AttachmentMetadata {
+    pub(super) id: usize,
+    pub(super) filename: String,
+    pub(super) description: Option<String>,
+    pub(super) spoiler: bool,
+}
+
+impl AttachmentMetadata {
+    /// Renames `file` and sets its description.
+    pub(super) fn apply(self, file: &mut Attachment) {
+        file.filename = self.filename;
+        if let Some(description) = self.description {
+            file.description = Some(description);
+        }
+        if self.spoiler && !file.is_spoiler() {
+            file.filename = format!("{}{}", SPOILER_PREFIX, file.filename);
+        }
+    }
+}
+
+/// Serializes `files` as the `attachments` array of a payload, using their index as ID.
+pub(super) fn serialize_attachments<S>(
+    files: &[Attachment],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    struct Metadata<'a>(usize, &'a Attachment);
+
+    impl Serialize for Metadata<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let Metadata(id, file) = self;
+            let mut s = serializer.serialize_struct("Attachment", 5)?;
+            s.serialize_field("id", id)?;
+            s.serialize_field("filename", &file.filename)?;
+            match &file.description {
+                Some(description) => s.serialize_field("description", description)?,
+                None => s.skip_field("description")?,
+            }
+            match &file.duration_secs {
+                Some(duration_secs) => s.serialize_field("duration_secs", duration_secs)?,
+                None => s.skip_field("duration_secs")?,
+            }
+            match &file.waveform {
+                Some(waveform) => s.serialize_field("waveform", waveform)?,
+                None => s.skip_field("waveform")?,
+            }
+            s.end()
+        }
+    }
+
+    let mut seq = serializer.serialize_seq(Some(files.len()))?;
+    for (id, file) in files.iter().enumerate() {
+        seq.serialize_element(&Metadata(id, file))?;
+    }
+    seq.end()
+}
+
+/// Builds a `multipart/form-data` body from `payload` and the files it references.
+///
+/// The payload is sent as the `payload_json` part and each file as a `files[n]` part. This
+/// works for both the initial response callback, whose payload is an `InteractionResponse`,
+/// and followup messages, whose payload is a `Message`.
+pub(crate) fn multipart_form(
+    payload: &impl Serialize,
+    files: &[Attachment],
+) -> Result<Form, MultipartError> {
+    let json = serde_json::to_string(payload).map_err(MultipartError::Json)?;
+    let payload = Part::text(json)
+        .mime_str("application/json")
+        .map_err(MultipartError::ContentType)?;
+    let mut form = Form::new().part("payload_json", payload);
+    for (i, file) in files.iter().enumerate() {
+        let part = file.to_part().map_err(MultipartError::ContentType)?;
+        form = form.part(format!("files[{}]", i), part);
+    }
+    Ok(form)
+}
@@ -1,11 +1,45 @@
-use serde::{ser::SerializeStruct, Serialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
 
-use super::MessageComponent;
+use super::{ButtonComponent, ComponentError, MessageComponent};
+use crate::types::ComponentType;
 
+/// The maximum number of buttons in an action row.
+const MAX_BUTTONS: usize = 5;
+
+#[derive(Deserialize)]
 pub struct ActionRow {
     components: Vec<MessageComponent>,
 }
 
+impl ActionRow {
+    pub fn components(&self) -> &[MessageComponent] {
+        &self.components
+    }
+
+    /// Creates a row holding only `button`.
+    pub(crate) fn from_button(button: ButtonComponent) -> Self {
+        Self {
+            components: vec![MessageComponent::Button(button)],
+        }
+    }
+
+    /// Returns whether another button can be added to the row, which is the case if the row
+    /// only holds buttons and has fewer than 5.
+    pub(crate) fn has_room_for_button(&self) -> bool {
+        let only_buttons = self
+            .components
+            .iter()
+            .all(|component| matches!(component, MessageComponent::Button(_)));
+        only_buttons && self.components.len() < MAX_BUTTONS
+    }
+
+    /// Adds `button` to the row. Check [`has_room_for_button`](Self::has_room_for_button)
+    /// first.
+    pub(crate) fn push_button(&mut self, button: ButtonComponent) {
+        self.components.push(MessageComponent::Button(button));
+    }
+}
+
 pub struct ActionRowBuilder {
     components: Vec<MessageComponent>,
 }
@@ -25,10 +59,41 @@ impl ActionRowBuilder {
         self
     }
 
-    pub fn build(self) -> ActionRow {
-        ActionRow {
-            components: self.components,
+    /// Builds the `ActionRow`.
+    ///
+    /// # Errors
+    /// Returns an error if the row is empty, has more than 5 buttons, contains another action
+    /// row or a label, or contains a select menu or text input together with other components.
+    pub fn build(self) -> Result<ActionRow, ComponentError> {
+        if self.components.is_empty() {
+            return Err(ComponentError::EmptyActionRow);
+        }
+
+        let mut buttons = 0;
+        for component in &self.components {
+            let component = match component {
+                MessageComponent::ActionRow(_) => return Err(ComponentError::NestedActionRow),
+                MessageComponent::Label(_) => {
+                    return Err(ComponentError::NotAllowedInActionRow { component: "label" })
+                }
+                MessageComponent::Button(_) => {
+                    buttons += 1;
+                    continue;
+                }
+                MessageComponent::TextInput(_) => "text input",
+                MessageComponent::SelectMenu(_) => "select menu",
+            };
+            if self.components.len() > 1 {
+                return Err(ComponentError::NotAlone { component });
+            }
         }
+        if buttons > MAX_BUTTONS {
+            return Err(ComponentError::TooManyButtons { count: buttons });
+        }
+
+        Ok(ActionRow {
+            components: self.components,
+        })
     }
 }
 
@@ -38,8 +103,100 @@ impl Serialize for ActionRow {
         S: serde::Serializer,
     {
         let mut s = serializer.serialize_struct("ActionRow", 2)?;
-        s.serialize_field("type", &1)?;
+        s.serialize_field("type", &ComponentType::ActionRow)?;
         s.serialize_field("components", &self.components)?;
         s.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::data::{
+        ButtonComponentBuilder, ButtonStyle, SelectMenuBuilder, SelectMenuKind, TextInputBuilder,
+        TextInputStyle,
+    };
+
+    fn button(custom_id: &str) -> MessageComponent {
+        MessageComponent::Button(
+            ButtonComponentBuilder::new()
+                .style(ButtonStyle::Primary)
+                .custom_id(custom_id.to_string())
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn buttons(count: usize) -> Vec<MessageComponent> {
+        (0..count).map(|i| button(&i.to_string())).collect()
+    }
+
+    fn select_menu() -> MessageComponent {
+        MessageComponent::SelectMenu(
+            SelectMenuBuilder::new()
+                .kind(SelectMenuKind::User)
+                .custom_id("users".to_string())
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn text_input() -> MessageComponent {
+        MessageComponent::TextInput(
+            TextInputBuilder::new()
+                .custom_id("text".to_string())
+                .style(TextInputStyle::Short)
+                .label("Text".to_string())
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn row(components: Vec<MessageComponent>) -> Result<ActionRow, ComponentError> {
+        ActionRowBuilder::new().components(components).build()
+    }
+
+    #[test]
+    fn rows_hold_at_most_5_buttons() {
+        assert!(row(buttons(5)).is_ok());
+        assert!(matches!(
+            row(buttons(6)),
+            Err(ComponentError::TooManyButtons { count: 6 })
+        ));
+        assert!(matches!(row(vec![]), Err(ComponentError::EmptyActionRow)));
+    }
+
+    #[test]
+    fn select_menus_and_text_inputs_must_be_alone() {
+        assert!(row(vec![select_menu()]).is_ok());
+        assert!(row(vec![text_input()]).is_ok());
+
+        assert!(matches!(
+            row(vec![button("a"), select_menu()]),
+            Err(ComponentError::NotAlone {
+                component: "select menu"
+            })
+        ));
+        assert!(matches!(
+            row(vec![select_menu(), select_menu()]),
+            Err(ComponentError::NotAlone {
+                component: "select menu"
+            })
+        ));
+        assert!(matches!(
+            row(vec![text_input(), button("a")]),
+            Err(ComponentError::NotAlone {
+                component: "text input"
+            })
+        ));
+    }
+
+    #[test]
+    fn rows_cannot_be_nested() {
+        let inner = MessageComponent::ActionRow(row(buttons(1)).unwrap());
+        assert!(matches!(
+            row(vec![inner]),
+            Err(ComponentError::NestedActionRow)
+        ));
+    }
+}
@@ -1,9 +1,16 @@
 use std::error::Error;
 
-use serde::{ser::SerializeStruct, Serialize};
-use serde_repr::Serialize_repr;
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
-#[derive(Serialize_repr)]
+use crate::types::{
+    ComponentType, CustomId, CustomIdError, Emoji, Snowflake, MAX_CUSTOM_ID_LENGTH,
+};
+
+/// The maximum length of the label of a button.
+const MAX_LABEL_LENGTH: usize = 80;
+
+#[derive(Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 #[allow(dead_code)]
 pub enum ButtonStyle {
@@ -15,12 +22,16 @@ pub enum ButtonStyle {
     Premium = 6,
 }
 
+#[derive(Deserialize)]
 #[non_exhaustive]
 pub struct ButtonComponent {
     style: ButtonStyle,
     label: Option<String>,
+    emoji: Option<Emoji>,
     custom_id: Option<String>,
     url: Option<String>,
+    #[serde(default)]
+    sku_id: Option<Snowflake>,
     disabled: Option<bool>,
 }
 
@@ -29,13 +40,17 @@ impl Serialize for ButtonComponent {
     where
         S: serde::Serializer,
     {
-        let mut s = serializer.serialize_struct("ButtonMessageComponent", 5)?;
-        s.serialize_field("type", &5)?;
+        let mut s = serializer.serialize_struct("ButtonMessageComponent", 8)?;
+        s.serialize_field("type", &ComponentType::Button)?;
         s.serialize_field("style", &self.style)?;
         match &self.label {
             Some(label) => s.serialize_field("label", label)?,
             None => s.skip_field("label")?,
         }
+        match &self.emoji {
+            Some(emoji) => s.serialize_field("emoji", emoji)?,
+            None => s.skip_field("emoji")?,
+        }
         match &self.custom_id {
             Some(custom_id) => s.serialize_field("custom_id", custom_id)?,
             None => s.skip_field("custom_id")?,
@@ -44,8 +59,12 @@ impl Serialize for ButtonComponent {
             Some(url) => s.serialize_field("url", url)?,
             None => s.skip_field("url")?,
         }
+        match &self.sku_id {
+            Some(sku_id) => s.serialize_field("sku_id", &sku_id.to_string())?,
+            None => s.skip_field("sku_id")?,
+        }
         match &self.disabled {
-            Some(disabled) => s.serialize_field("url", disabled)?,
+            Some(disabled) => s.serialize_field("disabled", disabled)?,
             None => s.skip_field("disabled")?,
         }
         s.end()
@@ -55,8 +74,10 @@ impl Serialize for ButtonComponent {
 pub struct ButtonComponentBuilder {
     style: Option<ButtonStyle>,
     label: Option<String>,
+    emoji: Option<Emoji>,
     custom_id: Option<String>,
     url: Option<String>,
+    sku_id: Option<Snowflake>,
     disabled: Option<bool>,
 }
 
@@ -65,8 +86,10 @@ impl ButtonComponentBuilder {
         Self {
             style: None,
             label: None,
+            emoji: None,
             custom_id: None,
             url: None,
+            sku_id: None,
             disabled: None,
         }
     }
@@ -76,37 +99,230 @@ impl ButtonComponentBuilder {
         self
     }
 
-    pub fn label(mut self, label: String) -> Self {
-        self.label = Some(label);
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the emoji shown before the label.
+    pub fn emoji(mut self, emoji: Emoji) -> Self {
+        self.emoji = Some(emoji);
         self
     }
 
-    pub fn custom_id(mut self, custom_id: String) -> Self {
-        self.custom_id = Some(custom_id);
+    pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+        self.custom_id = Some(custom_id.into());
         self
     }
 
-    pub fn url(mut self, url: String) -> Self {
-        self.url = Some(url);
+    /// Sets the custom ID to the encoding of `state`.
+    ///
+    /// # Errors
+    /// Returns an error if `state` cannot be encoded as a custom ID.
+    pub fn state(mut self, state: &impl CustomId) -> Result<Self, CustomIdError> {
+        self.custom_id = Some(state.encode()?);
+        Ok(self)
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
         self
     }
 
-    pub fn disabled(mut self) -> Self {
-        self.disabled = Some(true);
+    /// Sets the SKU the button offers for purchase. Only valid on `Premium` buttons.
+    pub fn sku_id(mut self, sku_id: Snowflake) -> Self {
+        self.sku_id = Some(sku_id);
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    /// Builds the `ButtonComponent`.
+    ///
+    /// # Errors
+    /// Returns an error if the style is not set, if the label is longer than 80 characters,
+    /// or if the custom ID is longer than 100 characters. Link buttons must have a URL and
+    /// no custom ID, premium buttons must have a SKU and nothing else, and other buttons
+    /// must have a custom ID and no URL.
     pub fn build(self) -> Result<ButtonComponent, Box<dyn Error>> {
-        if self.style.is_none() {
-            return Err("`style` must be set".into());
+        let style = match &self.style {
+            Some(style) => style,
+            None => return Err("`style` must be set".into()),
+        };
+
+        if let Some(label) = &self.label {
+            if label.chars().count() > MAX_LABEL_LENGTH {
+                return Err("`label` must be at most 80 characters".into());
+            }
+        }
+        if let Some(custom_id) = &self.custom_id {
+            let len = custom_id.chars().count();
+            if len == 0 || len > MAX_CUSTOM_ID_LENGTH {
+                return Err("`custom_id` must be between 1 and 100 characters".into());
+            }
+        }
+
+        match style {
+            ButtonStyle::Premium => {
+                if self.sku_id.is_none() {
+                    return Err("`sku_id` must be set on premium buttons".into());
+                }
+                if self.label.is_some()
+                    || self.emoji.is_some()
+                    || self.custom_id.is_some()
+                    || self.url.is_some()
+                {
+                    return Err(
+                        "`label`, `emoji`, `custom_id`, and `url` cannot be set on premium buttons"
+                            .into(),
+                    );
+                }
+            }
+            ButtonStyle::Link => {
+                if self.url.is_none() {
+                    return Err("`url` must be set on link buttons".into());
+                }
+                if self.custom_id.is_some() {
+                    return Err("`custom_id` cannot be set on link buttons".into());
+                }
+            }
+            _ => {
+                if self.custom_id.is_none() {
+                    return Err("`custom_id` must be set on non-link buttons".into());
+                }
+                if self.url.is_some() {
+                    return Err("`url` can only be set on link buttons".into());
+                }
+            }
+        }
+        if self.sku_id.is_some() && !matches!(style, ButtonStyle::Premium) {
+            return Err("`sku_id` can only be set on premium buttons".into());
         }
 
         Ok(ButtonComponent {
             style: self.style.unwrap(),
             label: self.label,
+            emoji: self.emoji,
             custom_id: self.custom_id,
             url: self.url,
+            sku_id: self.sku_id,
             disabled: self.disabled,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button(style: ButtonStyle) -> ButtonComponentBuilder {
+        ButtonComponentBuilder::new().style(style)
+    }
+
+    fn error(builder: ButtonComponentBuilder) -> String {
+        match builder.build() {
+            Ok(_) => panic!("expected the button to be rejected"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn labels_are_limited_to_80_characters() {
+        for label in ["a".repeat(80), "日".repeat(80)] {
+            assert!(button(ButtonStyle::Primary)
+                .custom_id("x")
+                .label(label)
+                .build()
+                .is_ok());
+        }
+        for label in ["a".repeat(81), "日".repeat(81)] {
+            assert_eq!(
+                error(button(ButtonStyle::Primary).custom_id("x").label(label)),
+                "`label` must be at most 80 characters"
+            );
+        }
+    }
+
+    #[test]
+    fn custom_ids_are_limited_to_100_characters() {
+        for custom_id in ["a".repeat(100), "日".repeat(100)] {
+            assert!(button(ButtonStyle::Primary)
+                .custom_id(custom_id)
+                .build()
+                .is_ok());
+        }
+        for custom_id in [String::new(), "a".repeat(101), "日".repeat(101)] {
+            assert_eq!(
+                error(button(ButtonStyle::Primary).custom_id(custom_id)),
+                "`custom_id` must be between 1 and 100 characters"
+            );
+        }
+    }
+
+    #[test]
+    fn link_buttons_need_a_url_and_no_custom_id() {
+        assert!(button(ButtonStyle::Link)
+            .url("https://example.com")
+            .build()
+            .is_ok());
+        assert_eq!(
+            error(button(ButtonStyle::Link)),
+            "`url` must be set on link buttons"
+        );
+        assert_eq!(
+            error(
+                button(ButtonStyle::Link)
+                    .url("https://example.com")
+                    .custom_id("x")
+            ),
+            "`custom_id` cannot be set on link buttons"
+        );
+    }
+
+    #[test]
+    fn other_buttons_need_a_custom_id_and_no_url() {
+        assert_eq!(
+            error(button(ButtonStyle::Danger)),
+            "`custom_id` must be set on non-link buttons"
+        );
+        assert_eq!(
+            error(
+                button(ButtonStyle::Danger)
+                    .custom_id("x")
+                    .url("https://example.com")
+            ),
+            "`url` can only be set on link buttons"
+        );
+        assert_eq!(
+            error(
+                button(ButtonStyle::Danger)
+                    .custom_id("x")
+                    .sku_id(Snowflake::from(1u64))
+            ),
+            "`sku_id` can only be set on premium buttons"
+        );
+    }
+
+    #[test]
+    fn premium_buttons_need_a_sku_and_nothing_else() {
+        assert!(button(ButtonStyle::Premium)
+            .sku_id(Snowflake::from(1u64))
+            .build()
+            .is_ok());
+        assert_eq!(
+            error(button(ButtonStyle::Premium)),
+            "`sku_id` must be set on premium buttons"
+        );
+        assert_eq!(
+            error(
+                button(ButtonStyle::Premium)
+                    .sku_id(Snowflake::from(1u64))
+                    .label("Buy")
+            ),
+            "`label`, `emoji`, `custom_id`, and `url` cannot be set on premium buttons"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use std::{error::Error, fmt};
+
+/// Represents an error encountered while building a component.
+///
+/// These errors mirror the layout rules Discord enforces, so invalid components fail
+/// locally instead of when the message is sent.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ComponentError {
+    /// An action row has no components.
+    EmptyActionRow,
+    /// An action row has more than 5 buttons.
+    TooManyButtons { count: usize },
+    /// A select menu or text input shares an action row with other components.
+    NotAlone { component: &'static str },
+    /// An action row is nested in another action row.
+    NestedActionRow,
+    /// A component that cannot be placed in an action row, such as a label, is in one.
+    NotAllowedInActionRow { component: &'static str },
+}
+
+impl fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentError::EmptyActionRow => write!(f, "an action row must have components"),
+            ComponentError::TooManyButtons { count } => write!(
+                f,
+                "an action row has {} buttons, but at most 5 are allowed",
+                count
+            ),
+            ComponentError::NotAlone { component } => {
+                write!(f, "a {} must be alone in its action row", component)
+            }
+            ComponentError::NestedActionRow => {
+                write!(f, "an action row cannot contain another action row")
+            }
+            ComponentError::NotAllowedInActionRow { component } => {
+                write!(f, "a {} cannot be placed in an action row", component)
+            }
+        }
+    }
+}
+
+impl Error for ComponentError {}
@@ -0,0 +1,158 @@
+use std::error::Error;
+
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+
+use super::MessageComponent;
+use crate::types::ComponentType;
+
+/// The maximum length of the text of a label.
+const MAX_LABEL_LENGTH: usize = 45;
+/// The maximum length of the description of a label.
+const MAX_DESCRIPTION_LENGTH: usize = 100;
+
+/// Represents a label wrapping a text input or select menu in a modal.
+///
+/// Labels can only be used in modals.
+///
+/// # Fields
+/// - `label`: The text shown above the component.
+/// - `description`: The text shown below the label.
+/// - `component`: The text input or select menu being labelled.
+#[derive(Deserialize)]
+#[non_exhaustive]
+pub struct Label {
+    label: String,
+    description: Option<String>,
+    component: Box<MessageComponent>,
+}
+
+impl Label {
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn component(&self) -> &MessageComponent {
+        &self.component
+    }
+}
+
+impl Serialize for Label {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_struct("Label", 4)?;
+        s.serialize_field("type", &ComponentType::Label)?;
+        s.serialize_field("label", &self.label)?;
+        match &self.description {
+            Some(description) => s.serialize_field("description", description)?,
+            None => s.skip_field("description")?,
+        }
+        s.serialize_field("component", &self.component)?;
+        s.end()
+    }
+}
+
+/// A builder for creating a `Label`.
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::response::data::{
+///     LabelBuilder, MessageComponent, SelectMenuBuilder, SelectMenuOptionBuilder,
+/// };
+///
+/// let select = SelectMenuBuilder::new()
+///     .custom_id("rating")
+///     .option(
+///         SelectMenuOptionBuilder::new()
+///             .label("Great")
+///             .value("5")
+///             .build()?,
+///     )
+///     .build()?;
+///
+/// let label = LabelBuilder::new()
+///     .label("How was it?")
+///     .component(MessageComponent::SelectMenu(select))
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct LabelBuilder {
+    label: Option<String>,
+    description: Option<String>,
+    component: Option<MessageComponent>,
+}
+
+impl LabelBuilder {
+    pub fn new() -> Self {
+        Self {
+            label: None,
+            description: None,
+            component: None,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the labelled component, which must be a text input or a select menu.
+    pub fn component(mut self, component: MessageComponent) -> Self {
+        self.component = Some(component);
+        self
+    }
+
+    /// Builds the `Label`.
+    ///
+    /// # Errors
+    /// Returns an error if the label is not set or longer than 45 characters, if the
+    /// description is longer than 100 characters, or if the component is not set or is not
+    /// a text input or a select menu.
+    pub fn build(self) -> Result<Label, Box<dyn Error>> {
+        let label = match self.label {
+            Some(label) => label,
+            None => return Err("`label` must be set".into()),
+        };
+        let len = label.chars().count();
+        if len == 0 || len > MAX_LABEL_LENGTH {
+            return Err("`label` must be between 1 and 45 characters".into());
+        }
+
+        if let Some(description) = &self.description {
+            if description.chars().count() > MAX_DESCRIPTION_LENGTH {
+                return Err("`description` must be at most 100 characters".into());
+            }
+        }
+
+        let component = match self.component {
+            Some(
+                component @ (MessageComponent::TextInput(_) | MessageComponent::SelectMenu(_)),
+            ) => component,
+            Some(_) => return Err("a label can only hold a text input or a select menu".into()),
+            None => return Err("`component` must be set".into()),
+        };
+
+        Ok(Label {
+            label,
+            description: self.description,
+            component: Box::new(component),
+        })
+    }
+}
+
+impl Default for LabelBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,25 +1,30 @@
 mod action_row;
 mod button;
+mod error;
+mod label;
 mod select_menu;
 mod text_input;
 
 pub use action_row::*;
 pub use button::*;
+pub use error::*;
+pub use label::*;
 pub use select_menu::*;
 pub use text_input::*;
 
-use serde::{ser::SerializeStruct, Serialize};
+use serde::{de, ser::SerializeStruct, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::ComponentType;
 
 #[non_exhaustive]
 pub enum MessageComponent {
     ActionRow(ActionRow),
     Button(ButtonComponent),
-    StringSelect(SelectMenu),
+    SelectMenu(SelectMenu),
     TextInput(TextInput),
-    UserSelect(SelectMenu),
-    RoleSelect(SelectMenu),
-    MentionableSelect(SelectMenu),
-    ChannelSelect(SelectMenu),
+    /// A label wrapping a text input or select menu. Only allowed in modals.
+    Label(Label),
 }
 
 impl Serialize for MessageComponent {
@@ -30,29 +35,16 @@ impl Serialize for MessageComponent {
         match &self {
             MessageComponent::ActionRow(action_row) => action_row.serialize(serializer),
             MessageComponent::Button(btn) => btn.serialize(serializer),
-            MessageComponent::StringSelect(select_menu) => {
-                let mut s = serializer.serialize_struct("MessageComponent", 5)?;
-                s.serialize_field("type", &3)?;
-                s.serialize_field("custom_id", &select_menu.custom_id())?;
-                match &select_menu.options() {
-                    Some(opts) => s.serialize_field("options", opts)?,
-                    None => s.skip_field("options")?,
-                }
-                match &select_menu.placeholder() {
-                    Some(placeholder) => s.serialize_field("placeholder", placeholder)?,
-                    None => s.skip_field("placeholder")?,
-                }
-                match &select_menu.disabled() {
-                    Some(disabled) => s.serialize_field("disabled", disabled)?,
-                    None => s.skip_field("disabled")?,
-                }
-                s.end()
-            }
+            MessageComponent::SelectMenu(select_menu) => select_menu.serialize(serializer),
             MessageComponent::TextInput(input) => {
-                let mut s = serializer.serialize_struct("MessageComponent", 8)?;
-                s.serialize_field("type", &4)?;
+                let mut s = serializer.serialize_struct("MessageComponent", 9)?;
+                s.serialize_field("type", &ComponentType::TextInput)?;
                 s.serialize_field("custom_id", &input.custom_id)?;
-                s.serialize_field("label", &input.label)?;
+                s.serialize_field("style", &input.style)?;
+                match &input.label {
+                    Some(label) => s.serialize_field("label", label)?,
+                    None => s.skip_field("label")?,
+                }
                 match &input.min_length {
                     Some(min_length) => s.serialize_field("min_length", min_length)?,
                     None => s.skip_field("min_length")?,
@@ -75,78 +67,48 @@ impl Serialize for MessageComponent {
                 }
                 s.end()
             }
-            MessageComponent::UserSelect(select_menu) => {
-                let mut s = serializer.serialize_struct("MessageComponent", 5)?;
-                s.serialize_field("type", &5)?;
-                s.serialize_field("custom_id", &select_menu.custom_id())?;
-                match &select_menu.options() {
-                    Some(opts) => s.serialize_field("options", opts)?,
-                    None => s.skip_field("options")?,
-                }
-                match &select_menu.placeholder() {
-                    Some(placeholder) => s.serialize_field("placeholder", placeholder)?,
-                    None => s.skip_field("placeholder")?,
-                }
-                match &select_menu.disabled() {
-                    Some(disabled) => s.serialize_field("disabled", disabled)?,
-                    None => s.skip_field("disabled")?,
-                }
-                s.end()
+            MessageComponent::Label(label) => label.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageComponent {
+    /// Deserializes a component, choosing the variant from its `type` field.
+    ///
+    /// # Errors
+    /// Returns an error if the `type` field is missing or is not the type of a supported
+    /// component, or if the component does not match its type.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let kind = value
+            .get("type")
+            .ok_or_else(|| de::Error::missing_field("type"))?;
+        let kind = ComponentType::deserialize(kind).map_err(de::Error::custom)?;
+
+        let component = match kind {
+            ComponentType::ActionRow => {
+                ActionRow::deserialize(value).map(MessageComponent::ActionRow)
             }
-            MessageComponent::RoleSelect(select_menu) => {
-                let mut s = serializer.serialize_struct("MessageComponent", 5)?;
-                s.serialize_field("type", &6)?;
-                s.serialize_field("custom_id", &select_menu.custom_id())?;
-                match &select_menu.options() {
-                    Some(opts) => s.serialize_field("options", opts)?,
-                    None => s.skip_field("options")?,
-                }
-                match &select_menu.placeholder() {
-                    Some(placeholder) => s.serialize_field("placeholder", placeholder)?,
-                    None => s.skip_field("placeholder")?,
-                }
-                match &select_menu.disabled() {
-                    Some(disabled) => s.serialize_field("disabled", disabled)?,
-                    None => s.skip_field("disabled")?,
-                }
-                s.end()
+            ComponentType::Button => {
+                ButtonComponent::deserialize(value).map(MessageComponent::Button)
             }
-            MessageComponent::MentionableSelect(select_menu) => {
-                let mut s = serializer.serialize_struct("MessageComponent", 5)?;
-                s.serialize_field("type", &7)?;
-                s.serialize_field("custom_id", &select_menu.custom_id())?;
-                match &select_menu.options() {
-                    Some(opts) => s.serialize_field("options", opts)?,
-                    None => s.skip_field("options")?,
-                }
-                match &select_menu.placeholder() {
-                    Some(placeholder) => s.serialize_field("placeholder", placeholder)?,
-                    None => s.skip_field("placeholder")?,
-                }
-                match &select_menu.disabled() {
-                    Some(disabled) => s.serialize_field("disabled", disabled)?,
-                    None => s.skip_field("disabled")?,
-                }
-                s.end()
+            kind if kind.is_select_menu() => {
+                SelectMenu::deserialize(value).map(MessageComponent::SelectMenu)
             }
-            MessageComponent::ChannelSelect(select_menu) => {
-                let mut s = serializer.serialize_struct("MessageComponent", 5)?;
-                s.serialize_field("type", &8)?;
-                s.serialize_field("custom_id", &select_menu.custom_id())?;
-                match &select_menu.options() {
-                    Some(opts) => s.serialize_field("options", opts)?,
-                    None => s.skip_field("options")?,
-                }
-                match &select_menu.placeholder() {
-                    Some(placeholder) => s.serialize_field("placeholder", placeholder)?,
-                    None => s.skip_field("placeholder")?,
-                }
-                match &select_menu.disabled() {
-                    Some(disabled) => s.serialize_field("disabled", disabled)?,
-                    None => s.skip_field("disabled")?,
-                }
-                s.end()
+            ComponentType::TextInput => {
+                TextInput::deserialize(value).map(MessageComponent::TextInput)
             }
-        }
+            ComponentType::Label => Label::deserialize(value).map(MessageComponent::Label),
+            other => {
+                return Err(de::Error::custom(format!(
+                    "unsupported component type {:?}",
+                    other
+                )))
+            }
+        };
+        component.map_err(de::Error::custom)
     }
 }
@@ -1,22 +1,28 @@
 use std::error::Error;
 
-use serde::Serialize;
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
-#[derive(Serialize)]
+use crate::types::{ChannelType, ComponentType, CustomId, CustomIdError, Emoji, Snowflake};
+
+#[derive(Serialize, Deserialize)]
 pub struct SelectMenuOption {
     label: String,
     value: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    default: Option<String>,
+    emoji: Option<Emoji>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default: Option<bool>,
 }
 
 pub struct SelectMenuOptionBuilder {
     pub label: Option<String>,
     pub value: Option<String>,
     pub description: Option<String>,
-    pub default: Option<String>,
+    pub emoji: Option<Emoji>,
+    pub default: Option<bool>,
 }
 
 impl SelectMenuOptionBuilder {
@@ -25,26 +31,34 @@ impl SelectMenuOptionBuilder {
             label: None,
             value: None,
             description: None,
+            emoji: None,
             default: None,
         }
     }
 
-    pub fn label(mut self, label: String) -> Self {
-        self.label = Some(label);
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
         self
     }
 
-    pub fn value(mut self, value: String) -> Self {
-        self.value = Some(value);
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
         self
     }
 
-    pub fn description(mut self, description: String) -> Self {
-        self.description = Some(description);
+    /// Shows an emoji next to the label of the option.
+    pub fn emoji(mut self, emoji: Emoji) -> Self {
+        self.emoji = Some(emoji);
         self
     }
 
-    pub fn default(mut self, default: String) -> Self {
+    /// Sets whether the option is selected by default.
+    pub fn default(mut self, default: bool) -> Self {
         self.default = Some(default);
         self
     }
@@ -62,20 +76,131 @@ impl SelectMenuOptionBuilder {
             label: self.label.unwrap(),
             value: self.value.unwrap(),
             description: self.description,
+            emoji: self.emoji,
             default: self.default,
         })
     }
 }
 
+/// The type of entity a default value of a select menu refers to.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectDefaultValueKind {
+    User,
+    Role,
+    Channel,
+}
+
+/// Represents an entity pre-selected in a user, role, mentionable, or channel select menu.
+#[derive(Deserialize)]
+#[non_exhaustive]
+pub struct SelectDefaultValue {
+    id: Snowflake,
+    #[serde(rename = "type")]
+    kind: SelectDefaultValueKind,
+}
+
+impl SelectDefaultValue {
+    /// Pre-selects the user with the given ID.
+    pub fn user(id: Snowflake) -> Self {
+        Self {
+            id,
+            kind: SelectDefaultValueKind::User,
+        }
+    }
+
+    /// Pre-selects the role with the given ID.
+    pub fn role(id: Snowflake) -> Self {
+        Self {
+            id,
+            kind: SelectDefaultValueKind::Role,
+        }
+    }
+
+    /// Pre-selects the channel with the given ID.
+    pub fn channel(id: Snowflake) -> Self {
+        Self {
+            id,
+            kind: SelectDefaultValueKind::Channel,
+        }
+    }
+
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    pub fn kind(&self) -> SelectDefaultValueKind {
+        self.kind
+    }
+}
+
+impl Serialize for SelectDefaultValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_struct("SelectDefaultValue", 2)?;
+        s.serialize_field("id", &self.id.to_string())?;
+        s.serialize_field("type", &self.kind)?;
+        s.end()
+    }
+}
+
+/// The kind of a select menu, which determines what the user chooses from.
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SelectMenuKind {
+    /// Chooses from the options of the select menu.
+    String = 3,
+    /// Chooses from the users of the guild.
+    User = 5,
+    /// Chooses from the roles of the guild.
+    Role = 6,
+    /// Chooses from the users and roles of the guild.
+    Mentionable = 7,
+    /// Chooses from the channels of the guild.
+    Channel = 8,
+}
+
+impl From<SelectMenuKind> for ComponentType {
+    fn from(kind: SelectMenuKind) -> Self {
+        match kind {
+            SelectMenuKind::String => ComponentType::StringSelect,
+            SelectMenuKind::User => ComponentType::UserSelect,
+            SelectMenuKind::Role => ComponentType::RoleSelect,
+            SelectMenuKind::Mentionable => ComponentType::MentionableSelect,
+            SelectMenuKind::Channel => ComponentType::ChannelSelect,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct SelectMenu {
+    #[serde(rename = "type")]
+    kind: SelectMenuKind,
     custom_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<Vec<SelectMenuOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     placeholder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_values: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_values: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_values: Option<Vec<SelectDefaultValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_types: Option<Vec<ChannelType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     disabled: Option<bool>,
 }
 
 impl SelectMenu {
+    pub fn kind(&self) -> SelectMenuKind {
+        self.kind
+    }
+
     pub fn custom_id(&self) -> &str {
         &self.custom_id
     }
@@ -88,33 +213,74 @@ impl SelectMenu {
         &self.placeholder
     }
 
+    pub fn min_values(&self) -> &Option<u8> {
+        &self.min_values
+    }
+
+    pub fn max_values(&self) -> &Option<u8> {
+        &self.max_values
+    }
+
+    pub fn default_values(&self) -> &Option<Vec<SelectDefaultValue>> {
+        &self.default_values
+    }
+
+    pub fn channel_types(&self) -> &Option<Vec<ChannelType>> {
+        &self.channel_types
+    }
+
     pub fn disabled(&self) -> &Option<bool> {
         &self.disabled
     }
 }
 
 pub struct SelectMenuBuilder {
+    kind: SelectMenuKind,
     custom_id: Option<String>,
     options: Option<Vec<SelectMenuOption>>,
     placeholder: Option<String>,
+    min_values: Option<u8>,
+    max_values: Option<u8>,
+    default_values: Option<Vec<SelectDefaultValue>>,
+    channel_types: Option<Vec<ChannelType>>,
     disabled: Option<bool>,
 }
 
 impl SelectMenuBuilder {
     pub fn new() -> Self {
         Self {
+            kind: SelectMenuKind::String,
             custom_id: None,
             options: None,
             placeholder: None,
+            min_values: None,
+            max_values: None,
+            default_values: None,
+            channel_types: None,
             disabled: None,
         }
     }
 
-    pub fn custom_id(mut self, custom_id: String) -> Self {
-        self.custom_id = Some(custom_id);
+    /// Sets the kind of the select menu. Defaults to a string select.
+    pub fn kind(mut self, kind: SelectMenuKind) -> Self {
+        self.kind = kind;
         self
     }
 
+    pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+        self.custom_id = Some(custom_id.into());
+        self
+    }
+
+    /// Sets the custom ID to the encoding of `state`.
+    ///
+    /// # Errors
+    /// Returns an error if `state` cannot be encoded as a custom ID.
+    pub fn state(mut self, state: &impl CustomId) -> Result<Self, CustomIdError> {
+        self.custom_id = Some(state.encode()?);
+        Ok(self)
+    }
+
     pub fn option(mut self, option: SelectMenuOption) -> Self {
         if let Some(options) = &mut self.options {
             options.push(option);
@@ -129,25 +295,115 @@ impl SelectMenuBuilder {
         self
     }
 
-    pub fn placeholder(mut self, placeholder: String) -> Self {
-        self.placeholder = Some(placeholder);
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
         self
     }
 
-    pub fn disabled(mut self) -> Self {
-        self.disabled = Some(true);
+    /// Sets the minimum number of items that must be chosen, between 0 and 25. Defaults to 1.
+    pub fn min_values(mut self, min_values: u8) -> Self {
+        self.min_values = Some(min_values);
         self
     }
 
+    /// Sets the maximum number of items that can be chosen, between 1 and 25. Defaults to 1.
+    pub fn max_values(mut self, max_values: u8) -> Self {
+        self.max_values = Some(max_values);
+        self
+    }
+
+    /// Pre-selects an entity. Only valid on user, role, mentionable, and channel selects.
+    pub fn default_value(mut self, default_value: SelectDefaultValue) -> Self {
+        if let Some(default_values) = &mut self.default_values {
+            default_values.push(default_value);
+        } else {
+            self.default_values = Some(vec![default_value]);
+        }
+        self
+    }
+
+    /// Pre-selects entities. Only valid on user, role, mentionable, and channel selects.
+    pub fn default_values(mut self, default_values: Vec<SelectDefaultValue>) -> Self {
+        self.default_values = Some(default_values);
+        self
+    }
+
+    /// Limits the channels that can be chosen to the given types. Only valid on channel
+    /// selects.
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.channel_types = Some(channel_types);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    /// Builds the `SelectMenu`.
+    ///
+    /// # Errors
+    /// Returns an error if the custom ID is not set, if the options are missing on a string
+    /// select or set on another kind, if default values are set on a string select, if
+    /// channel types are set on a select other than a channel select, or if the numbers of
+    /// items that can be chosen are out of range.
     pub fn build(self) -> Result<SelectMenu, Box<dyn Error>> {
         if self.custom_id.is_none() {
             return Err("`custom_id` must be set".into());
         }
 
+        match self.kind {
+            SelectMenuKind::String => {
+                if self.options.is_none() {
+                    return Err("`options` must be set on string selects".into());
+                }
+                if self.default_values.is_some() {
+                    return Err("`default_values` cannot be set on string selects".into());
+                }
+            }
+            _ if self.options.is_some() => {
+                return Err("`options` can only be set on string selects".into());
+            }
+            _ => {}
+        }
+        if self.channel_types.is_some() && self.kind != SelectMenuKind::Channel {
+            return Err("`channel_types` can only be set on channel selects".into());
+        }
+
+        if let Some(min_values) = self.min_values {
+            if min_values > 25 {
+                return Err("`min_values` must be between 0 and 25".into());
+            }
+        }
+        if let Some(max_values) = self.max_values {
+            if !(1..=25).contains(&max_values) {
+                return Err("`max_values` must be between 1 and 25".into());
+            }
+        }
+        if self.min_values.unwrap_or(1) > self.max_values.unwrap_or(1) {
+            return Err("`min_values` cannot be greater than `max_values`".into());
+        }
+        if let Some(default_values) = &self.default_values {
+            let count = default_values.len();
+            let min = self.min_values.unwrap_or(1) as usize;
+            let max = self.max_values.unwrap_or(1) as usize;
+            if count < min || count > max {
+                return Err(
+                    "the number of `default_values` must be between `min_values` and `max_values`"
+                        .into(),
+                );
+            }
+        }
+
         Ok(SelectMenu {
+            kind: self.kind,
             custom_id: self.custom_id.unwrap(),
             options: self.options,
             placeholder: self.placeholder,
+            min_values: self.min_values,
+            max_values: self.max_values,
+            default_values: self.default_values,
+            channel_types: self.channel_types,
             disabled: self.disabled,
         })
     }
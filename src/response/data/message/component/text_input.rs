@@ -1,20 +1,23 @@
 use std::error::Error;
 
-use serde::Serialize;
-use serde_repr::Serialize_repr;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
-#[derive(Serialize_repr)]
+#[derive(Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 pub enum TextInputStyle {
     Short = 1,
     Paragraph = 2,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 pub struct TextInput {
     pub custom_id: String,
     pub style: TextInputStyle,
-    pub label: String,
+    /// The label of the text input. Must be set when the text input is placed in an action
+    /// row, and left unset when it is wrapped in a `Label`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_length: Option<u16>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -52,8 +55,8 @@ impl TextInputBuilder {
         }
     }
 
-    pub fn custom_id(mut self, custom_id: String) -> Self {
-        self.custom_id = Some(custom_id);
+    pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+        self.custom_id = Some(custom_id.into());
         self
     }
 
@@ -62,8 +65,8 @@ impl TextInputBuilder {
         self
     }
 
-    pub fn label(mut self, label: String) -> Self {
-        self.label = Some(label);
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
         self
     }
 
@@ -77,18 +80,18 @@ impl TextInputBuilder {
         self
     }
 
-    pub fn required(mut self) -> Self {
-        self.required = Some(true);
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
         self
     }
 
-    pub fn value(mut self, value: String) -> Self {
-        self.value = Some(value);
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
         self
     }
 
-    pub fn placeholder(mut self, placeholder: String) -> Self {
-        self.placeholder = Some(placeholder);
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
         self
     }
 
@@ -117,7 +120,7 @@ impl TextInputBuilder {
         Ok(TextInput {
             custom_id: self.custom_id.unwrap(),
             style: self.style.unwrap(),
-            label: self.label.unwrap(),
+            label: self.label,
             min_length: self.min_length,
             max_length: self.max_length,
             required: self.required,
@@ -0,0 +1,331 @@
+use reqwest::multipart::Form;
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use super::{
+    multipart_form, serialize_attachments, validate_components, validate_content, validate_embeds,
+    AllowedMentions, Attachment, Embed, MessageComponent, MessageError, MessageFlags,
+};
+use crate::response::MultipartError;
+
+/// Represents how a field of a message changes when the message is edited.
+///
+/// Discord leaves fields that are omitted from an edit unchanged, and clears fields that are
+/// explicitly `null`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Edit<T> {
+    /// Leaves the field unchanged. The field is omitted from the payload.
+    #[default]
+    Keep,
+    /// Removes the field. The field is sent as `null`.
+    Clear,
+    /// Replaces the field with the given value.
+    Set(T),
+}
+
+impl<T> Edit<T> {
+    /// Returns whether the field is left unchanged.
+    pub fn is_keep(&self) -> bool {
+        matches!(self, Edit::Keep)
+    }
+
+    /// Returns the new value of the field, if it is replaced.
+    pub fn as_set(&self) -> Option<&T> {
+        match self {
+            Edit::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Serializes `field` under `key` according to its `Edit` state.
+fn serialize_edit<S, T>(s: &mut S, key: &'static str, field: &Edit<T>) -> Result<(), S::Error>
+where
+    S: SerializeStruct,
+    T: Serialize,
+{
+    match field {
+        Edit::Keep => s.skip_field(key),
+        Edit::Clear => s.serialize_field(key, &None::<T>),
+        Edit::Set(value) => s.serialize_field(key, value),
+    }
+}
+
+/// Represents the changes made to an existing message, such as the original response to an
+/// interaction.
+///
+/// Each field is either kept, cleared, or replaced. See [`Edit`].
+///
+/// # Fields
+/// - `content`: The new content of the message.
+/// - `embeds`: The new embeds of the message.
+/// - `flags`: The new flags of the message. Only `SUPPRESS_EMBEDS` and `IS_COMPONENTS_V2`
+///   can be changed.
+/// - `allowed_mentions`: The mentions in the new content that notify their targets.
+/// - `components`: The new components of the message.
+/// - `attachments`: The files of the message. Replacing them uploads the given files in
+///   place of the existing attachments, and clearing them removes every attachment.
+#[non_exhaustive]
+pub struct EditMessage {
+    content: Edit<String>,
+    embeds: Edit<Vec<Embed>>,
+    flags: Edit<MessageFlags>,
+    allowed_mentions: Edit<AllowedMentions>,
+    components: Edit<Vec<MessageComponent>>,
+    attachments: Edit<Vec<Attachment>>,
+}
+
+impl EditMessage {
+    pub fn content(&self) -> &Edit<String> {
+        &self.content
+    }
+
+    pub fn embeds(&self) -> &Edit<Vec<Embed>> {
+        &self.embeds
+    }
+
+    pub fn flags(&self) -> &Edit<MessageFlags> {
+        &self.flags
+    }
+
+    pub fn allowed_mentions(&self) -> &Edit<AllowedMentions> {
+        &self.allowed_mentions
+    }
+
+    pub fn components(&self) -> &Edit<Vec<MessageComponent>> {
+        &self.components
+    }
+
+    pub fn attachments(&self) -> &Edit<Vec<Attachment>> {
+        &self.attachments
+    }
+
+    /// Returns the files uploaded with the edit.
+    pub fn files(&self) -> &[Attachment] {
+        self.attachments.as_set().map_or(&[], Vec::as_slice)
+    }
+
+    /// Builds a `multipart/form-data` body with the edit as `payload_json` and its files.
+    ///
+    /// # Errors
+    /// Returns an error if the edit cannot be serialized or a file has an invalid content
+    /// type.
+    pub fn to_multipart(&self) -> Result<Form, MultipartError> {
+        multipart_form(self, self.files())
+    }
+}
+
+impl Serialize for EditMessage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        /// Serializes files as the `attachments` array of the payload.
+        struct Files<'a>(&'a [Attachment]);
+
+        impl Serialize for Files<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_attachments(self.0, serializer)
+            }
+        }
+
+        let mut s = serializer.serialize_struct("EditMessage", 6)?;
+        serialize_edit(&mut s, "content", &self.content)?;
+        serialize_edit(&mut s, "embeds", &self.embeds)?;
+        serialize_edit(&mut s, "flags", &self.flags)?;
+        serialize_edit(&mut s, "allowed_mentions", &self.allowed_mentions)?;
+        serialize_edit(&mut s, "components", &self.components)?;
+        match &self.attachments {
+            Edit::Keep => s.skip_field("attachments")?,
+            Edit::Clear => s.serialize_field("attachments", &[] as &[()])?,
+            Edit::Set(files) => s.serialize_field("attachments", &Files(files))?,
+        }
+        s.end()
+    }
+}
+
+/// A builder for creating an `EditMessage`.
+///
+/// Fields that are not set on the builder are left unchanged.
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::response::data::EditMessageBuilder;
+///
+/// // Replace the content and remove the components, keeping the embeds.
+/// let edit = EditMessageBuilder::new()
+///     .content("Done!")
+///     .clear_components()
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct EditMessageBuilder {
+    content: Edit<String>,
+    embeds: Edit<Vec<Embed>>,
+    flags: Edit<MessageFlags>,
+    allowed_mentions: Edit<AllowedMentions>,
+    components: Edit<Vec<MessageComponent>>,
+    attachments: Edit<Vec<Attachment>>,
+}
+
+impl EditMessageBuilder {
+    pub fn new() -> Self {
+        Self {
+            content: Edit::Keep,
+            embeds: Edit::Keep,
+            flags: Edit::Keep,
+            allowed_mentions: Edit::Keep,
+            components: Edit::Keep,
+            attachments: Edit::Keep,
+        }
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Edit::Set(content.into());
+        self
+    }
+
+    pub fn clear_content(mut self) -> Self {
+        self.content = Edit::Clear;
+        self
+    }
+
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = Edit::Set(embeds);
+        self
+    }
+
+    pub fn clear_embeds(mut self) -> Self {
+        self.embeds = Edit::Clear;
+        self
+    }
+
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Edit::Set(flags);
+        self
+    }
+
+    pub fn clear_flags(mut self) -> Self {
+        self.flags = Edit::Clear;
+        self
+    }
+
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Edit::Set(allowed_mentions);
+        self
+    }
+
+    pub fn clear_allowed_mentions(mut self) -> Self {
+        self.allowed_mentions = Edit::Clear;
+        self
+    }
+
+    pub fn components(mut self, components: Vec<MessageComponent>) -> Self {
+        self.components = Edit::Set(components);
+        self
+    }
+
+    pub fn clear_components(mut self) -> Self {
+        self.components = Edit::Clear;
+        self
+    }
+
+    /// Uploads files in place of the existing attachments of the message.
+    pub fn files(mut self, files: Vec<Attachment>) -> Self {
+        self.attachments = Edit::Set(files);
+        self
+    }
+
+    /// Removes every attachment of the message.
+    pub fn clear_attachments(mut self) -> Self {
+        self.attachments = Edit::Clear;
+        self
+    }
+
+    /// Builds the `EditMessage`.
+    ///
+    /// # Errors
+    /// Returns an error if the new content, embeds, or components do not respect the limits
+    /// `MessageBuilder::build` enforces.
+    pub fn build(self) -> Result<EditMessage, MessageError> {
+        validate_content(self.content.as_set().map(String::as_str))?;
+        validate_embeds(self.embeds.as_set().map_or(&[], Vec::as_slice))?;
+        if let Edit::Set(components) = &self.components {
+            validate_components(components, self.flags.as_set())?;
+        }
+
+        Ok(EditMessage {
+            content: self.content,
+            embeds: self.embeds,
+            flags: self.flags,
+            allowed_mentions: self.allowed_mentions,
+            components: self.components,
+            attachments: self.attachments,
+        })
+    }
+}
+
+impl Default for EditMessageBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn to_json(edit: EditMessageBuilder) -> Value {
+        serde_json::to_value(edit.build().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn kept_fields_are_omitted() {
+        assert_eq!(to_json(EditMessageBuilder::new()), json!({}));
+    }
+
+    #[test]
+    fn cleared_fields_are_sent_as_null() {
+        let edit = EditMessageBuilder::new()
+            .clear_content()
+            .clear_embeds()
+            .clear_flags()
+            .clear_allowed_mentions()
+            .clear_components();
+        assert_eq!(
+            to_json(edit),
+            json!({
+                "content": null,
+                "embeds": null,
+                "flags": null,
+                "allowed_mentions": null,
+                "components": null,
+            })
+        );
+    }
+
+    #[test]
+    fn cleared_attachments_are_sent_as_an_empty_list() {
+        let edit = EditMessageBuilder::new().clear_attachments();
+        assert_eq!(to_json(edit), json!({ "attachments": [] }));
+    }
+
+    #[test]
+    fn replaced_fields_are_sent_with_their_new_value() {
+        let edit = EditMessageBuilder::new()
+            .content("Done!")
+            .files(vec![Attachment::new("log.txt", "done")]);
+        assert_eq!(
+            to_json(edit),
+            json!({
+                "content": "Done!",
+                "attachments": [{ "id": 0, "filename": "log.txt" }],
+            })
+        );
+    }
+}
@@ -0,0 +1,536 @@
+use serde::{Deserialize, Serialize};
+
+use super::EmbedError;
+use crate::types::Color;
+
+/// The maximum length of the title of an embed.
+const MAX_TITLE_LENGTH: usize = 256;
+/// The maximum length of the description of an embed.
+const MAX_DESCRIPTION_LENGTH: usize = 4096;
+/// The maximum number of fields of an embed.
+const MAX_FIELDS: usize = 25;
+/// The maximum length of the name of an embed field.
+const MAX_FIELD_NAME_LENGTH: usize = 256;
+/// The maximum length of the value of an embed field.
+const MAX_FIELD_VALUE_LENGTH: usize = 1024;
+/// The maximum length of the text of an embed footer.
+const MAX_FOOTER_LENGTH: usize = 2048;
+/// The maximum length of the name of an embed author.
+const MAX_AUTHOR_NAME_LENGTH: usize = 256;
+/// The maximum combined length of the text of an embed.
+const MAX_EMBED_LENGTH: usize = 6000;
+
+/// Represents a rich embed attached to a message.
+///
+/// Every field is optional, but Discord rejects embeds without any content.
+///
+/// # Fields
+/// - `title`: The title of the embed.
+/// - `description`: The description of the embed.
+/// - `url`: The URL the title links to.
+/// - `color`: The color of the embed's left border.
+/// - `timestamp`: The ISO8601 timestamp shown in the footer.
+/// - `footer`: The footer of the embed.
+/// - `image`: The image shown below the description.
+/// - `thumbnail`: The thumbnail shown in the top right corner.
+/// - `author`: The author shown above the title.
+/// - `fields`: The fields of the embed.
+#[derive(Serialize, Deserialize, Clone)]
+#[non_exhaustive]
+pub struct Embed {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    footer: Option<EmbedFooter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<EmbedImage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumbnail: Option<EmbedImage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<EmbedAuthor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fields: Option<Vec<EmbedField>>,
+}
+
+impl Embed {
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    pub fn timestamp(&self) -> Option<&str> {
+        self.timestamp.as_deref()
+    }
+
+    pub fn footer(&self) -> Option<&EmbedFooter> {
+        self.footer.as_ref()
+    }
+
+    pub fn image(&self) -> Option<&EmbedImage> {
+        self.image.as_ref()
+    }
+
+    pub fn thumbnail(&self) -> Option<&EmbedImage> {
+        self.thumbnail.as_ref()
+    }
+
+    pub fn author(&self) -> Option<&EmbedAuthor> {
+        self.author.as_ref()
+    }
+
+    pub fn fields(&self) -> Option<&[EmbedField]> {
+        self.fields.as_deref()
+    }
+
+    /// Returns the number of characters Discord counts towards the combined length limit of
+    /// the embeds of a message.
+    ///
+    /// This includes the title, description, field names and values, footer text, and
+    /// author name.
+    pub fn text_len(&self) -> usize {
+        let len = |text: Option<&str>| text.map_or(0, |text| text.chars().count());
+        len(self.title())
+            + len(self.description())
+            + len(self.footer().map(EmbedFooter::text))
+            + len(self.author().map(EmbedAuthor::name))
+            + self
+                .fields()
+                .unwrap_or_default()
+                .iter()
+                .map(|field| len(Some(field.name())) + len(Some(field.value())))
+                .sum::<usize>()
+    }
+}
+
+/// Represents the footer of an embed.
+#[derive(Serialize, Deserialize, Clone)]
+#[non_exhaustive]
+pub struct EmbedFooter {
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon_url: Option<String>,
+}
+
+impl EmbedFooter {
+    /// Creates a footer with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            icon_url: None,
+        }
+    }
+
+    /// Sets the URL of the icon shown next to the text.
+    pub fn icon_url(mut self, icon_url: impl Into<String>) -> Self {
+        self.icon_url = Some(icon_url.into());
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn get_icon_url(&self) -> Option<&str> {
+        self.icon_url.as_deref()
+    }
+}
+
+/// Represents the author of an embed.
+#[derive(Serialize, Deserialize, Clone)]
+#[non_exhaustive]
+pub struct EmbedAuthor {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon_url: Option<String>,
+}
+
+impl EmbedAuthor {
+    /// Creates an author with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            url: None,
+            icon_url: None,
+        }
+    }
+
+    /// Sets the URL the name links to.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets the URL of the icon shown next to the name.
+    pub fn icon_url(mut self, icon_url: impl Into<String>) -> Self {
+        self.icon_url = Some(icon_url.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn get_icon_url(&self) -> Option<&str> {
+        self.icon_url.as_deref()
+    }
+}
+
+/// Represents the image or thumbnail of an embed.
+#[derive(Serialize, Deserialize, Clone)]
+#[non_exhaustive]
+pub struct EmbedImage {
+    url: String,
+}
+
+impl EmbedImage {
+    /// Creates an image from its URL.
+    ///
+    /// Both `http(s)` URLs and `attachment://<filename>` references are accepted.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+/// Represents a field of an embed.
+#[derive(Serialize, Deserialize, Clone)]
+#[non_exhaustive]
+pub struct EmbedField {
+    name: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inline: Option<bool>,
+}
+
+impl EmbedField {
+    /// Creates a field with the given name and value.
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            inline: None,
+        }
+    }
+
+    /// Sets whether the field is displayed next to other inline fields instead of on its own
+    /// line.
+    pub fn inline(mut self, inline: bool) -> Self {
+        self.inline = Some(inline);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.inline.unwrap_or(false)
+    }
+}
+
+/// A builder for creating an `Embed`.
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::{
+///     response::data::{EmbedBuilder, EmbedField, EmbedFooter},
+///     types::Color,
+/// };
+///
+/// let embed = EmbedBuilder::new()
+///     .title("Server status")
+///     .description("All systems operational.")
+///     .color(Color::GREEN)
+///     .field(EmbedField::new("Uptime", "12 days").inline(true))
+///     .field(EmbedField::new("Players", "42").inline(true))
+///     .footer(EmbedFooter::new("Last checked"))
+///     .timestamp("2024-01-01T12:30:00.000Z")
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct EmbedBuilder {
+    title: Option<String>,
+    description: Option<String>,
+    url: Option<String>,
+    color: Option<Color>,
+    timestamp: Option<String>,
+    footer: Option<EmbedFooter>,
+    image: Option<EmbedImage>,
+    thumbnail: Option<EmbedImage>,
+    author: Option<EmbedAuthor>,
+    fields: Option<Vec<EmbedField>>,
+}
+
+impl EmbedBuilder {
+    pub fn new() -> Self {
+        Self {
+            title: None,
+            description: None,
+            url: None,
+            color: None,
+            timestamp: None,
+            footer: None,
+            image: None,
+            thumbnail: None,
+            author: None,
+            fields: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets the color of the embed's left border, either a `Color` or a `0xRRGGBB`
+    /// integer.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the ISO8601 timestamp shown in the footer, e.g. `2024-01-01T12:30:00.000Z`.
+    pub fn timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn footer(mut self, footer: EmbedFooter) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    pub fn image(mut self, url: impl Into<String>) -> Self {
+        self.image = Some(EmbedImage::new(url));
+        self
+    }
+
+    pub fn thumbnail(mut self, url: impl Into<String>) -> Self {
+        self.thumbnail = Some(EmbedImage::new(url));
+        self
+    }
+
+    pub fn author(mut self, author: EmbedAuthor) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    pub fn field(mut self, field: EmbedField) -> Self {
+        if let Some(fields) = &mut self.fields {
+            fields.push(field);
+        } else {
+            self.fields = Some(vec![field]);
+        }
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<EmbedField>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Builds the `Embed`.
+    ///
+    /// # Errors
+    /// Returns an error if the title is longer than 256 characters, the description longer
+    /// than 4096, the footer text longer than 2048, or the author name longer than 256, if
+    /// there are more than 25 fields, if a field name is longer than 256 characters or a
+    /// field value longer than 1024, or if the text of the embed exceeds 6000 characters in
+    /// total.
+    pub fn build(self) -> Result<Embed, EmbedError> {
+        let len = |text: &Option<String>| text.as_deref().map_or(0, |text| text.chars().count());
+
+        let title_len = len(&self.title);
+        if title_len > MAX_TITLE_LENGTH {
+            return Err(EmbedError::TitleTooLong { len: title_len });
+        }
+        let description_len = len(&self.description);
+        if description_len > MAX_DESCRIPTION_LENGTH {
+            return Err(EmbedError::DescriptionTooLong {
+                len: description_len,
+            });
+        }
+        if let Some(footer) = &self.footer {
+            let len = footer.text.chars().count();
+            if len > MAX_FOOTER_LENGTH {
+                return Err(EmbedError::FooterTooLong { len });
+            }
+        }
+        if let Some(author) = &self.author {
+            let len = author.name.chars().count();
+            if len > MAX_AUTHOR_NAME_LENGTH {
+                return Err(EmbedError::AuthorNameTooLong { len });
+            }
+        }
+        let fields = self.fields.as_deref().unwrap_or_default();
+        if fields.len() > MAX_FIELDS {
+            return Err(EmbedError::TooManyFields {
+                count: fields.len(),
+            });
+        }
+        for (index, field) in fields.iter().enumerate() {
+            let len = field.name.chars().count();
+            if len > MAX_FIELD_NAME_LENGTH {
+                return Err(EmbedError::FieldNameTooLong { index, len });
+            }
+            let len = field.value.chars().count();
+            if len > MAX_FIELD_VALUE_LENGTH {
+                return Err(EmbedError::FieldValueTooLong { index, len });
+            }
+        }
+
+        let embed = Embed {
+            title: self.title,
+            description: self.description,
+            url: self.url,
+            color: self.color,
+            timestamp: self.timestamp,
+            footer: self.footer,
+            image: self.image,
+            thumbnail: self.thumbnail,
+            author: self.author,
+            fields: self.fields,
+        };
+        let len = embed.text_len();
+        if len > MAX_EMBED_LENGTH {
+            return Err(EmbedError::TooLong { len });
+        }
+        Ok(embed)
+    }
+}
+
+impl Default for EmbedBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns `len` multi-byte characters.
+    fn text(len: usize) -> String {
+        "日".repeat(len)
+    }
+
+    #[test]
+    fn titles_and_descriptions_are_limited() {
+        assert!(EmbedBuilder::new().title(text(256)).build().is_ok());
+        assert!(matches!(
+            EmbedBuilder::new().title(text(257)).build(),
+            Err(EmbedError::TitleTooLong { len: 257 })
+        ));
+        assert!(EmbedBuilder::new().description(text(4096)).build().is_ok());
+        assert!(matches!(
+            EmbedBuilder::new().description(text(4097)).build(),
+            Err(EmbedError::DescriptionTooLong { len: 4097 })
+        ));
+    }
+
+    #[test]
+    fn footers_and_authors_are_limited() {
+        let footer = |len| EmbedBuilder::new().footer(EmbedFooter::new(text(len)));
+        assert!(footer(2048).build().is_ok());
+        assert!(matches!(
+            footer(2049).build(),
+            Err(EmbedError::FooterTooLong { len: 2049 })
+        ));
+
+        let author = |len| EmbedBuilder::new().author(EmbedAuthor::new(text(len)));
+        assert!(author(256).build().is_ok());
+        assert!(matches!(
+            author(257).build(),
+            Err(EmbedError::AuthorNameTooLong { len: 257 })
+        ));
+    }
+
+    #[test]
+    fn embeds_have_at_most_25_fields() {
+        let fields = |count| (0..count).map(|_| EmbedField::new("a", "b")).collect();
+        assert!(EmbedBuilder::new().fields(fields(25)).build().is_ok());
+        assert!(matches!(
+            EmbedBuilder::new().fields(fields(26)).build(),
+            Err(EmbedError::TooManyFields { count: 26 })
+        ));
+    }
+
+    #[test]
+    fn field_names_and_values_are_limited() {
+        let field = |name, value| {
+            EmbedBuilder::new()
+                .field(EmbedField::new("a", "b"))
+                .field(EmbedField::new(text(name), text(value)))
+                .build()
+        };
+        assert!(field(256, 1024).is_ok());
+        assert!(matches!(
+            field(257, 1),
+            Err(EmbedError::FieldNameTooLong { index: 1, len: 257 })
+        ));
+        assert!(matches!(
+            field(1, 1025),
+            Err(EmbedError::FieldValueTooLong {
+                index: 1,
+                len: 1025
+            })
+        ));
+    }
+
+    #[test]
+    fn embeds_are_limited_to_6000_characters_in_total() {
+        let embed = || {
+            EmbedBuilder::new()
+                .description(text(4096))
+                .footer(EmbedFooter::new(text(1904)))
+        };
+        assert_eq!(embed().build().unwrap().text_len(), 6000);
+        assert!(matches!(
+            embed().title(text(1)).build(),
+            Err(EmbedError::TooLong { len: 6001 })
+        ));
+    }
+}
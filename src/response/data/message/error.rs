@@ -0,0 +1,156 @@
+use std::{error::Error, fmt};
+
+/// Represents an error encountered while building a message.
+///
+/// These errors mirror the limits Discord enforces, so invalid messages fail locally
+/// instead of when they are sent.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MessageError {
+    /// The content of a message is longer than 2000 characters.
+    ContentTooLong { len: usize },
+    /// A message has more than 3 stickers.
+    TooManyStickers { count: usize },
+    /// A message has more than 10 embeds.
+    TooManyEmbeds { count: usize },
+    /// The combined length of the text of the embeds of a message exceeds 6000 characters.
+    EmbedsTooLong { len: usize },
+    /// A message has more than 5 action rows.
+    TooManyActionRows { count: usize },
+    /// A top-level component of a message that does not use components V2 is not an action
+    /// row.
+    NotInActionRow,
+    /// A message using components V2 has more than 40 components in total.
+    TooManyComponents { count: usize },
+    /// A message with the `IS_VOICE_MESSAGE` flag does not have exactly one audio file with
+    /// a duration and waveform, or has content or components.
+    InvalidVoiceMessage { reason: &'static str },
+    /// A message has a component that is only allowed in modals, such as a text input or a
+    /// label.
+    NotAllowedInMessage { component: &'static str },
+    /// Attachment metadata was set for a file index the message has no file for.
+    UnknownAttachment { id: usize },
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageError::ContentTooLong { len } => write!(
+                f,
+                "content has {} characters, but at most 2000 are allowed",
+                len
+            ),
+            MessageError::TooManyStickers { count } => write!(
+                f,
+                "message has {} stickers, but at most 3 are allowed",
+                count
+            ),
+            MessageError::TooManyEmbeds { count } => write!(
+                f,
+                "message has {} embeds, but at most 10 are allowed",
+                count
+            ),
+            MessageError::EmbedsTooLong { len } => write!(
+                f,
+                "embeds have {} characters in total, but at most 6000 are allowed",
+                len
+            ),
+            MessageError::TooManyActionRows { count } => write!(
+                f,
+                "message has {} action rows, but at most 5 are allowed",
+                count
+            ),
+            MessageError::NotInActionRow => write!(
+                f,
+                "top-level components must be action rows unless the message uses components V2"
+            ),
+            MessageError::TooManyComponents { count } => write!(
+                f,
+                "message has {} components, but at most 40 are allowed",
+                count
+            ),
+            MessageError::InvalidVoiceMessage { reason } => {
+                write!(f, "invalid voice message: {}", reason)
+            }
+            MessageError::NotAllowedInMessage { component } => {
+                write!(f, "a {} can only be used in a modal", component)
+            }
+            MessageError::UnknownAttachment { id } => {
+                write!(f, "attachment {} does not match any uploaded file", id)
+            }
+        }
+    }
+}
+
+impl Error for MessageError {}
+
+/// Represents an error encountered while building an embed.
+///
+/// Lengths are counted in characters, as Discord does.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EmbedError {
+    /// The title is longer than 256 characters.
+    TitleTooLong { len: usize },
+    /// The description is longer than 4096 characters.
+    DescriptionTooLong { len: usize },
+    /// The embed has more than 25 fields.
+    TooManyFields { count: usize },
+    /// The name of the field at `index` is longer than 256 characters.
+    FieldNameTooLong { index: usize, len: usize },
+    /// The value of the field at `index` is longer than 1024 characters.
+    FieldValueTooLong { index: usize, len: usize },
+    /// The text of the footer is longer than 2048 characters.
+    FooterTooLong { len: usize },
+    /// The name of the author is longer than 256 characters.
+    AuthorNameTooLong { len: usize },
+    /// The combined length of the text of the embed exceeds 6000 characters.
+    TooLong { len: usize },
+}
+
+impl fmt::Display for EmbedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmbedError::TitleTooLong { len } => write!(
+                f,
+                "title has {} characters, but at most 256 are allowed",
+                len
+            ),
+            EmbedError::DescriptionTooLong { len } => write!(
+                f,
+                "description has {} characters, but at most 4096 are allowed",
+                len
+            ),
+            EmbedError::TooManyFields { count } => {
+                write!(f, "embed has {} fields, but at most 25 are allowed", count)
+            }
+            EmbedError::FieldNameTooLong { index, len } => write!(
+                f,
+                "name of field {} has {} characters, but at most 256 are allowed",
+                index, len
+            ),
+            EmbedError::FieldValueTooLong { index, len } => write!(
+                f,
+                "value of field {} has {} characters, but at most 1024 are allowed",
+                index, len
+            ),
+            EmbedError::FooterTooLong { len } => write!(
+                f,
+                "footer has {} characters, but at most 2048 are allowed",
+                len
+            ),
+            EmbedError::AuthorNameTooLong { len } => write!(
+                f,
+                "author name has {} characters, but at most 256 are allowed",
+                len
+            ),
+            EmbedError::TooLong { len } => write!(
+                f,
+                "embed has {} characters in total, but at most 6000 are allowed",
+                len
+            ),
+        }
+    }
+}
+
+impl Error for EmbedError {}
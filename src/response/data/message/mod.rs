@@ -1,35 +1,46 @@
+mod attachment;
 mod component;
+mod edit;
+mod embed;
+mod error;
+mod poll;
+mod update;
 
+pub use attachment::*;
 pub use component::*;
+pub use edit::*;
+pub use embed::*;
+pub use error::*;
+pub use poll::*;
+pub use update::*;
 
-use serde::Serialize;
-
-bitflags::bitflags! {
-    pub struct MessageFlags: u32 {
-        const CROSSPOSTED                      = 1 << 0;
-        const IS_CROSSPOST                     = 1 << 1;
-        const SUPPRESS_EMBEDS                  = 1 << 2;
-        const SOURCE_MESSAGE_DELETED           = 1 << 3;
-        const URGENT                           = 1 << 4;
-        const HAS_THREAD                       = 1 << 5;
-        const EPHEMERAL                        = 1 << 6;
-        const LOADING                          = 1 << 7;
-        const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
-        const SUPPRESS_NOTIFICATIONS           = 1 << 12;
-        const IS_VOICE_MESSAGE                 = 1 << 13;
-    }
-}
+use reqwest::multipart::Form;
+use serde::{Deserialize, Serialize};
 
-impl Serialize for MessageFlags {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        serializer.serialize_u32(self.bits())
-    }
-}
+pub use crate::types::{
+    AllowedMentionType, AllowedMentions, AllowedMentionsBuilder, Emoji, MessageFlags,
+};
+
+/// An emoji shown on a component, select menu option, or poll answer.
+#[deprecated(note = "use `Emoji`, which is shared with the other modules")]
+pub type PartialEmoji = Emoji;
 
-#[derive(Serialize)]
+use crate::{response::MultipartError, types::Snowflake};
+
+/// The maximum length of the content of a message.
+const MAX_CONTENT_LENGTH: usize = 2000;
+/// The maximum number of stickers of a message.
+const MAX_STICKERS: usize = 3;
+/// The maximum number of embeds of a message.
+const MAX_EMBEDS: usize = 10;
+/// The maximum combined length of the text of the embeds of a message.
+const MAX_EMBEDS_LENGTH: usize = 6000;
+/// The maximum number of action rows of a message that does not use components V2.
+const MAX_ACTION_ROWS: usize = 5;
+/// The maximum number of components of a message that uses components V2.
+const MAX_COMPONENTS_V2: usize = 40;
+
+#[derive(Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Message {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -37,12 +48,40 @@ pub struct Message {
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<Poll>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<MessageFlags>,
     #[serde(skip_serializing_if = "Option::is_none")]
     components: Option<Vec<MessageComponent>>,
+    #[serde(
+        rename = "attachments",
+        skip_deserializing,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_attachments"
+    )]
+    files: Vec<Attachment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thread_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    applied_tags: Vec<Snowflake>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    sticker_ids: Vec<Snowflake>,
 }
 
 impl Message {
+    /// Creates a message with the given content that only the user who triggered the
+    /// interaction can see.
+    ///
+    /// # Errors
+    /// Returns an error if the content is longer than 2000 characters.
+    pub fn ephemeral(content: impl Into<String>) -> Result<Self, MessageError> {
+        MessageBuilder::new().content(content).ephemeral().build()
+    }
+
     pub fn tts(&self) -> &Option<bool> {
         &self.tts
     }
@@ -51,6 +90,18 @@ impl Message {
         &self.content
     }
 
+    pub fn embeds(&self) -> &Option<Vec<Embed>> {
+        &self.embeds
+    }
+
+    pub fn allowed_mentions(&self) -> &Option<AllowedMentions> {
+        &self.allowed_mentions
+    }
+
+    pub fn poll(&self) -> &Option<Poll> {
+        &self.poll
+    }
+
     pub fn flags(&self) -> &Option<MessageFlags> {
         &self.flags
     }
@@ -58,13 +109,50 @@ impl Message {
     pub fn components(&self) -> &Option<Vec<MessageComponent>> {
         &self.components
     }
+
+    /// Returns the files uploaded together with the message.
+    pub fn files(&self) -> &[Attachment] {
+        &self.files
+    }
+
+    pub fn thread_name(&self) -> &Option<String> {
+        &self.thread_name
+    }
+
+    pub fn applied_tags(&self) -> &[Snowflake] {
+        &self.applied_tags
+    }
+
+    pub fn sticker_ids(&self) -> &[Snowflake] {
+        &self.sticker_ids
+    }
+
+    /// Builds a `multipart/form-data` body with the message as `payload_json` and its files.
+    ///
+    /// Messages with files must be sent this way, e.g. as followup messages. Use
+    /// `InteractionResponse::to_multipart` for the initial response instead.
+    ///
+    /// # Errors
+    /// Returns an error if the message cannot be serialized or a file has an invalid
+    /// content type.
+    pub fn to_multipart(&self) -> Result<Form, MultipartError> {
+        multipart_form(self, &self.files)
+    }
 }
 
 pub struct MessageBuilder {
     tts: Option<bool>,
     content: Option<String>,
+    embeds: Option<Vec<Embed>>,
+    allowed_mentions: Option<AllowedMentions>,
+    poll: Option<Poll>,
     flags: Option<MessageFlags>,
     components: Option<Vec<MessageComponent>>,
+    files: Vec<Attachment>,
+    attachments: Vec<AttachmentMetadata>,
+    thread_name: Option<String>,
+    applied_tags: Vec<Snowflake>,
+    sticker_ids: Vec<Snowflake>,
 }
 
 impl MessageBuilder {
@@ -72,13 +160,21 @@ impl MessageBuilder {
         Self {
             tts: None,
             content: None,
+            embeds: None,
+            allowed_mentions: None,
+            poll: None,
             flags: None,
             components: None,
+            files: vec![],
+            attachments: vec![],
+            thread_name: None,
+            applied_tags: vec![],
+            sticker_ids: vec![],
         }
     }
 
-    pub fn tts(mut self) -> Self {
-        self.tts = Some(true);
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = Some(tts);
         self
     }
 
@@ -87,11 +183,62 @@ impl MessageBuilder {
         self
     }
 
+    pub fn embed(mut self, embed: Embed) -> Self {
+        if let Some(embeds) = &mut self.embeds {
+            embeds.push(embed);
+        } else {
+            self.embeds = Some(vec![embed]);
+        }
+        self
+    }
+
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = Some(embeds);
+        self
+    }
+
+    /// Sets which mentions in the content notify their targets.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Sends a poll with the message.
+    pub fn poll(mut self, poll: Poll) -> Self {
+        self.poll = Some(poll);
+        self
+    }
+
     pub fn flags(mut self, flags: MessageFlags) -> Self {
         self.flags = Some(flags);
         self
     }
 
+    /// Makes the message visible only to the user who triggered the interaction, keeping any
+    /// other flags that were set.
+    pub fn ephemeral(self) -> Self {
+        self.add_flags(MessageFlags::EPHEMERAL)
+    }
+
+    /// Hides the embeds Discord generates for links in the content, keeping any other flags
+    /// that were set.
+    pub fn suppress_embeds(self) -> Self {
+        self.add_flags(MessageFlags::SUPPRESS_EMBEDS)
+    }
+
+    /// Sends the message without push and desktop notifications, keeping any other flags
+    /// that were set.
+    pub fn suppress_notifications(self) -> Self {
+        self.add_flags(MessageFlags::SUPPRESS_NOTIFICATIONS)
+    }
+
+    /// Sets `flags` in addition to the flags that were already set.
+    fn add_flags(mut self, flags: MessageFlags) -> Self {
+        let current = self.flags.take().unwrap_or(MessageFlags::empty());
+        self.flags = Some(current | flags);
+        self
+    }
+
     pub fn component(mut self, component: MessageComponent) -> Self {
         if let Some(components) = &mut self.components {
             components.push(component);
@@ -106,12 +253,364 @@ impl MessageBuilder {
         self
     }
 
-    pub fn build(self) -> Message {
-        Message {
+    /// Adds a button, packing it into the last action row if that row only holds buttons
+    /// and has fewer than 5, or into a new action row otherwise.
+    pub fn button(mut self, button: ButtonComponent) -> Self {
+        push_button(self.components.get_or_insert_with(Vec::new), button);
+        self
+    }
+
+    /// Adds buttons, packing them into action rows five at a time. See
+    /// [`button`](Self::button).
+    pub fn buttons(mut self, buttons: impl IntoIterator<Item = ButtonComponent>) -> Self {
+        for button in buttons {
+            self = self.button(button);
+        }
+        self
+    }
+
+    /// Uploads a file together with the message.
+    pub fn file(mut self, file: Attachment) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    /// Sends the message as a voice message, uploading `file` as its audio.
+    ///
+    /// The file must have a duration and waveform, see [`Attachment::voice`]. Voice messages
+    /// cannot have content or components.
+    pub fn voice_message(mut self, file: Attachment) -> Self {
+        self.files = vec![file];
+        self.add_flags(MessageFlags::IS_VOICE_MESSAGE)
+    }
+
+    /// Sets the entry of the `attachments` array for the file uploaded as `files[id]`, i.e.
+    /// the `id`-th file added to the message.
+    ///
+    /// The file is sent as `filename`, prefixed with `SPOILER_` if `spoiler` is set, and
+    /// `description` becomes its alt text for screen readers. Embeds showing the file must
+    /// use the final filename in their `attachment://` URL.
+    pub fn attachment(
+        mut self,
+        id: usize,
+        filename: impl Into<String>,
+        description: Option<&str>,
+        spoiler: bool,
+    ) -> Self {
+        self.attachments.push(AttachmentMetadata {
+            id,
+            filename: filename.into(),
+            description: description.map(str::to_string),
+            spoiler,
+        });
+        self
+    }
+
+    /// Uploads files together with the message, replacing any previously added ones.
+    pub fn files(mut self, files: Vec<Attachment>) -> Self {
+        self.files = files;
+        self
+    }
+
+    /// Creates a forum post with the given name when the message is sent as a followup to a
+    /// forum or media channel.
+    pub fn thread_name(mut self, thread_name: impl Into<String>) -> Self {
+        self.thread_name = Some(thread_name.into());
+        self
+    }
+
+    /// Applies a tag of the forum or media channel to the post created with
+    /// [`thread_name`](Self::thread_name).
+    pub fn applied_tag(mut self, tag_id: Snowflake) -> Self {
+        self.applied_tags.push(tag_id);
+        self
+    }
+
+    /// Sets the tags of the forum or media channel applied to the post created with
+    /// [`thread_name`](Self::thread_name).
+    pub fn applied_tags(mut self, tag_ids: Vec<Snowflake>) -> Self {
+        self.applied_tags = tag_ids;
+        self
+    }
+
+    /// Sends a sticker with the message. Stickers can only be sent in followup messages.
+    pub fn sticker(mut self, sticker_id: Snowflake) -> Self {
+        self.sticker_ids.push(sticker_id);
+        self
+    }
+
+    /// Sets the stickers sent with the message, up to 3.
+    pub fn stickers(mut self, sticker_ids: Vec<Snowflake>) -> Self {
+        self.sticker_ids = sticker_ids;
+        self
+    }
+
+    /// Builds the `Message`.
+    ///
+    /// # Errors
+    /// Returns an error if the content is longer than 2000 characters, if there are more
+    /// than 3 stickers, if there are more than 10 embeds or their text exceeds 6000
+    /// characters in total, or if the components do not respect Discord's limits. Unless the
+    /// `IS_COMPONENTS_V2` flag is set, a message can have at most 5 top-level components,
+    /// which must all be action rows. With the flag, a message can have at most 40
+    /// components in total, counting nested ones.
+    ///
+    /// Messages with the `IS_VOICE_MESSAGE` flag must have exactly one audio file with a
+    /// duration and waveform, and no content or components. Metadata set with
+    /// [`attachment`](Self::attachment) must refer to one of the added files.
+    pub fn build(mut self) -> Result<Message, MessageError> {
+        for metadata in std::mem::take(&mut self.attachments) {
+            match self.files.get_mut(metadata.id) {
+                Some(file) => metadata.apply(file),
+                None => return Err(MessageError::UnknownAttachment { id: metadata.id }),
+            }
+        }
+        if self
+            .flags
+            .as_ref()
+            .is_some_and(|flags| flags.contains(MessageFlags::IS_VOICE_MESSAGE))
+        {
+            validate_voice_message(&self)?;
+        }
+        validate_content(self.content.as_deref())?;
+        if self.sticker_ids.len() > MAX_STICKERS {
+            return Err(MessageError::TooManyStickers {
+                count: self.sticker_ids.len(),
+            });
+        }
+        validate_embeds(self.embeds.as_deref().unwrap_or_default())?;
+        validate_components(
+            self.components.as_deref().unwrap_or_default(),
+            self.flags.as_ref(),
+        )?;
+
+        Ok(Message {
             tts: self.tts,
             content: self.content,
+            embeds: self.embeds,
+            allowed_mentions: self.allowed_mentions,
+            poll: self.poll,
             flags: self.flags,
             components: self.components,
+            files: self.files,
+            thread_name: self.thread_name,
+            applied_tags: self.applied_tags,
+            sticker_ids: self.sticker_ids,
+        })
+    }
+}
+
+/// Adds `button` to the last action row of `components` if that row only holds buttons and
+/// has fewer than 5, or to a new action row otherwise.
+pub(super) fn push_button(components: &mut Vec<MessageComponent>, button: ButtonComponent) {
+    match components.last_mut() {
+        Some(MessageComponent::ActionRow(row)) if row.has_room_for_button() => {
+            row.push_button(button)
         }
+        _ => components.push(MessageComponent::ActionRow(ActionRow::from_button(button))),
+    }
+}
+
+/// Validates that a voice message has a single audio file and nothing else to show.
+fn validate_voice_message(builder: &MessageBuilder) -> Result<(), MessageError> {
+    let reason = match builder.files.as_slice() {
+        [file] if !file.is_voice() => "the file must be audio with a duration and waveform",
+        [_] if builder.content.is_some() => "voice messages cannot have content",
+        [_] if builder.components.is_some() => "voice messages cannot have components",
+        [_] => return Ok(()),
+        _ => "voice messages must have exactly one file",
+    };
+    Err(MessageError::InvalidVoiceMessage { reason })
+}
+
+/// Validates that `content` is at most 2000 characters long.
+pub(super) fn validate_content(content: Option<&str>) -> Result<(), MessageError> {
+    let len = content.map_or(0, |content| content.chars().count());
+    if len > MAX_CONTENT_LENGTH {
+        return Err(MessageError::ContentTooLong { len });
+    }
+    Ok(())
+}
+
+/// Validates the number of `embeds` and the combined length of their text.
+pub(super) fn validate_embeds(embeds: &[Embed]) -> Result<(), MessageError> {
+    if embeds.len() > MAX_EMBEDS {
+        return Err(MessageError::TooManyEmbeds {
+            count: embeds.len(),
+        });
+    }
+    let len = embeds.iter().map(Embed::text_len).sum();
+    if len > MAX_EMBEDS_LENGTH {
+        return Err(MessageError::EmbedsTooLong { len });
+    }
+    Ok(())
+}
+
+/// Validates the layout of `components` for a message with the given `flags`.
+pub(super) fn validate_components(
+    components: &[MessageComponent],
+    flags: Option<&MessageFlags>,
+) -> Result<(), MessageError> {
+    if let Some(component) = find_modal_only(components) {
+        return Err(MessageError::NotAllowedInMessage { component });
+    }
+
+    let v2 = flags.is_some_and(|flags| flags.contains(MessageFlags::IS_COMPONENTS_V2));
+    if v2 {
+        let count = count_components(components);
+        if count > MAX_COMPONENTS_V2 {
+            return Err(MessageError::TooManyComponents { count });
+        }
+    } else {
+        if components
+            .iter()
+            .any(|component| !matches!(component, MessageComponent::ActionRow(_)))
+        {
+            return Err(MessageError::NotInActionRow);
+        }
+        if components.len() > MAX_ACTION_ROWS {
+            return Err(MessageError::TooManyActionRows {
+                count: components.len(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the name of the first component in `components`, or nested in them, that is only
+/// allowed in modals.
+fn find_modal_only(components: &[MessageComponent]) -> Option<&'static str> {
+    components.iter().find_map(|component| match component {
+        MessageComponent::ActionRow(row) => find_modal_only(row.components()),
+        MessageComponent::TextInput(_) => Some("text input"),
+        MessageComponent::Label(_) => Some("label"),
+        _ => None,
+    })
+}
+
+/// Counts `components` and all of the components nested in them.
+fn count_components(components: &[MessageComponent]) -> usize {
+    components
+        .iter()
+        .map(|component| match component {
+            MessageComponent::ActionRow(row) => 1 + count_components(row.components()),
+            _ => 1,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button(custom_id: usize) -> ButtonComponent {
+        ButtonComponentBuilder::new()
+            .style(ButtonStyle::Secondary)
+            .custom_id(custom_id.to_string())
+            .build()
+            .unwrap()
+    }
+
+    fn buttons(count: usize) -> impl Iterator<Item = ButtonComponent> {
+        (0..count).map(button)
+    }
+
+    fn action_row(buttons: impl Iterator<Item = ButtonComponent>) -> MessageComponent {
+        let row = ActionRowBuilder::new()
+            .components(buttons.map(MessageComponent::Button).collect())
+            .build()
+            .unwrap();
+        MessageComponent::ActionRow(row)
+    }
+
+    fn embed(description: &str) -> Embed {
+        EmbedBuilder::new()
+            .description(description)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn content_is_limited_to_2000_characters() {
+        for content in ["a".repeat(2000), "日".repeat(2000), "🦀".repeat(2000)] {
+            assert!(MessageBuilder::new().content(content).build().is_ok());
+        }
+        for content in ["a".repeat(2001), "日".repeat(2001), "🦀".repeat(2001)] {
+            assert!(matches!(
+                MessageBuilder::new().content(content).build(),
+                Err(MessageError::ContentTooLong { len: 2001 })
+            ));
+        }
+        assert!(matches!(
+            Message::ephemeral("日".repeat(2001)),
+            Err(MessageError::ContentTooLong { len: 2001 })
+        ));
+    }
+
+    #[test]
+    fn embeds_are_limited_to_6000_characters_in_total() {
+        let half = "日".repeat(3000);
+        let message = MessageBuilder::new()
+            .embeds(vec![embed(&half), embed(&half)])
+            .build()
+            .unwrap();
+        assert_eq!(message.embeds().as_ref().unwrap().len(), 2);
+
+        let result = MessageBuilder::new()
+            .embeds(vec![embed(&half), embed(&half), embed("a")])
+            .build();
+        assert!(matches!(
+            result,
+            Err(MessageError::EmbedsTooLong { len: 6001 })
+        ));
+    }
+
+    #[test]
+    fn messages_have_at_most_10_embeds() {
+        assert!(MessageBuilder::new()
+            .embeds((0..10).map(|_| embed("a")).collect())
+            .build()
+            .is_ok());
+        assert!(matches!(
+            MessageBuilder::new()
+                .embeds((0..11).map(|_| embed("a")).collect())
+                .build(),
+            Err(MessageError::TooManyEmbeds { count: 11 })
+        ));
+    }
+
+    #[test]
+    fn messages_have_at_most_5_action_rows() {
+        // Buttons are packed five to a row.
+        let message = MessageBuilder::new().buttons(buttons(25)).build().unwrap();
+        assert_eq!(message.components().as_ref().unwrap().len(), 5);
+
+        assert!(matches!(
+            MessageBuilder::new().buttons(buttons(26)).build(),
+            Err(MessageError::TooManyActionRows { count: 6 })
+        ));
+        assert!(matches!(
+            MessageBuilder::new()
+                .component(MessageComponent::Button(button(0)))
+                .build(),
+            Err(MessageError::NotInActionRow)
+        ));
+    }
+
+    #[test]
+    fn components_v2_messages_have_at_most_40_components() {
+        let v2 = || MessageBuilder::new().flags(MessageFlags::IS_COMPONENTS_V2);
+
+        // 8 rows of 4 buttons, counting the rows themselves.
+        let rows = |buttons_in_last_row| {
+            (0..8)
+                .map(|row| action_row(buttons(if row == 7 { buttons_in_last_row } else { 4 })))
+                .collect::<Vec<_>>()
+        };
+        assert!(v2().components(rows(4)).build().is_ok());
+        assert!(matches!(
+            v2().components(rows(5)).build(),
+            Err(MessageError::TooManyComponents { count: 41 })
+        ));
     }
 }
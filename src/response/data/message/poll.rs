@@ -0,0 +1,238 @@
+use std::error::Error;
+
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::types::Emoji;
+
+/// The maximum length of a poll question.
+const MAX_QUESTION_LENGTH: usize = 300;
+/// The maximum length of the text of a poll answer.
+const MAX_ANSWER_LENGTH: usize = 55;
+/// The maximum number of answers of a poll.
+const MAX_ANSWERS: usize = 10;
+/// The maximum duration of a poll, in hours.
+const MAX_DURATION: u32 = 768;
+
+/// The layout of a poll.
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PollLayoutType {
+    /// The default layout.
+    Default = 1,
+}
+
+/// Represents the text and emoji of a poll question or answer.
+///
+/// Questions only support text.
+#[derive(Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PollMedia {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji: Option<Emoji>,
+}
+
+impl PollMedia {
+    /// Creates a poll media with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: Some(text.into()),
+            emoji: None,
+        }
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn emoji(&self) -> Option<&Emoji> {
+        self.emoji.as_ref()
+    }
+}
+
+/// Represents an answer of a poll.
+#[derive(Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PollAnswer {
+    poll_media: PollMedia,
+}
+
+impl PollAnswer {
+    /// Creates an answer with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            poll_media: PollMedia::new(text),
+        }
+    }
+
+    /// Shows an emoji next to the text of the answer.
+    pub fn emoji(mut self, emoji: Emoji) -> Self {
+        self.poll_media.emoji = Some(emoji);
+        self
+    }
+
+    pub fn poll_media(&self) -> &PollMedia {
+        &self.poll_media
+    }
+}
+
+/// Represents a poll sent with a message.
+///
+/// # Fields
+/// - `question`: The question of the poll.
+/// - `answers`: The answers users can vote for.
+/// - `duration`: The number of hours the poll is open for. Defaults to 24.
+/// - `allow_multiselect`: Whether users can vote for more than one answer.
+/// - `layout_type`: The layout of the poll.
+#[derive(Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Poll {
+    question: PollMedia,
+    answers: Vec<PollAnswer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_multiselect: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout_type: Option<PollLayoutType>,
+}
+
+impl Poll {
+    pub fn question(&self) -> &PollMedia {
+        &self.question
+    }
+
+    pub fn answers(&self) -> &[PollAnswer] {
+        &self.answers
+    }
+
+    pub fn duration(&self) -> Option<u32> {
+        self.duration
+    }
+
+    pub fn allow_multiselect(&self) -> bool {
+        self.allow_multiselect.unwrap_or(false)
+    }
+
+    pub fn layout_type(&self) -> Option<PollLayoutType> {
+        self.layout_type
+    }
+}
+
+/// A builder for creating a `Poll`.
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::response::data::{Emoji, PollAnswer, PollBuilder};
+///
+/// let poll = PollBuilder::new()
+///     .question("Which map next?")
+///     .answer(PollAnswer::new("Desert").emoji(Emoji::unicode("🏜️")))
+///     .answer(PollAnswer::new("Forest").emoji(Emoji::unicode("🌲")))
+///     .duration(2)
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct PollBuilder {
+    question: Option<String>,
+    answers: Vec<PollAnswer>,
+    duration: Option<u32>,
+    allow_multiselect: Option<bool>,
+    layout_type: Option<PollLayoutType>,
+}
+
+impl PollBuilder {
+    pub fn new() -> Self {
+        Self {
+            question: None,
+            answers: vec![],
+            duration: None,
+            allow_multiselect: None,
+            layout_type: None,
+        }
+    }
+
+    pub fn question(mut self, question: impl Into<String>) -> Self {
+        self.question = Some(question.into());
+        self
+    }
+
+    pub fn answer(mut self, answer: PollAnswer) -> Self {
+        self.answers.push(answer);
+        self
+    }
+
+    pub fn answers(mut self, answers: Vec<PollAnswer>) -> Self {
+        self.answers = answers;
+        self
+    }
+
+    /// Sets the number of hours the poll is open for, up to 768 (32 days).
+    pub fn duration(mut self, hours: u32) -> Self {
+        self.duration = Some(hours);
+        self
+    }
+
+    /// Sets whether users can vote for more than one answer.
+    pub fn allow_multiselect(mut self, allow_multiselect: bool) -> Self {
+        self.allow_multiselect = Some(allow_multiselect);
+        self
+    }
+
+    pub fn layout_type(mut self, layout_type: PollLayoutType) -> Self {
+        self.layout_type = Some(layout_type);
+        self
+    }
+
+    /// Builds the `Poll`.
+    ///
+    /// # Errors
+    /// Returns an error if the question is not set or longer than 300 characters, if there
+    /// are no answers or more than 10, if the text of an answer is empty or longer than 55
+    /// characters, or if the duration is not between 1 and 768 hours.
+    pub fn build(self) -> Result<Poll, Box<dyn Error>> {
+        let question = match self.question {
+            Some(question) => question,
+            None => return Err("`question` must be set".into()),
+        };
+        if question.chars().count() > MAX_QUESTION_LENGTH {
+            return Err("`question` must be at most 300 characters".into());
+        }
+
+        if self.answers.is_empty() || self.answers.len() > MAX_ANSWERS {
+            return Err("a poll must have between 1 and 10 answers".into());
+        }
+        for answer in &self.answers {
+            let len = answer
+                .poll_media
+                .text()
+                .map_or(0, |text| text.chars().count());
+            if len == 0 || len > MAX_ANSWER_LENGTH {
+                return Err("the text of an answer must be between 1 and 55 characters".into());
+            }
+        }
+
+        if let Some(duration) = self.duration {
+            if duration == 0 || duration > MAX_DURATION {
+                return Err("`duration` must be between 1 and 768 hours".into());
+            }
+        }
+
+        Ok(Poll {
+            question: PollMedia::new(question),
+            answers: self.answers,
+            duration: self.duration,
+            allow_multiselect: self.allow_multiselect,
+            layout_type: self.layout_type,
+        })
+    }
+}
+
+impl Default for PollBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,240 @@
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use super::{
+    push_button, validate_components, validate_content, validate_embeds, ButtonComponent, Edit,
+    Embed, MessageComponent, MessageError, MessageFlags,
+};
+
+/// Represents the changes made to the message a component is attached to, sent as an
+/// `UpdateMessage` interaction response.
+///
+/// Unlike [`EditMessage`](super::EditMessage), cleared fields are sent as empty values
+/// rather than `null`: Discord keeps the fields omitted from an update and clears those
+/// sent empty, such as `components: []`.
+///
+/// # Fields
+/// - `content`: The new content of the message.
+/// - `embeds`: The new embeds of the message.
+/// - `components`: The new components of the message.
+/// - `flags`: The new flags of the message, if any.
+#[non_exhaustive]
+pub struct MessageUpdate {
+    content: Edit<String>,
+    embeds: Edit<Vec<Embed>>,
+    components: Edit<Vec<MessageComponent>>,
+    flags: Option<MessageFlags>,
+}
+
+impl MessageUpdate {
+    pub fn content(&self) -> &Edit<String> {
+        &self.content
+    }
+
+    pub fn embeds(&self) -> &Edit<Vec<Embed>> {
+        &self.embeds
+    }
+
+    pub fn components(&self) -> &Edit<Vec<MessageComponent>> {
+        &self.components
+    }
+
+    pub fn flags(&self) -> &Option<MessageFlags> {
+        &self.flags
+    }
+}
+
+impl Serialize for MessageUpdate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("MessageUpdate", 4)?;
+        match &self.content {
+            Edit::Keep => s.skip_field("content")?,
+            Edit::Clear => s.serialize_field("content", "")?,
+            Edit::Set(content) => s.serialize_field("content", content)?,
+        }
+        match &self.embeds {
+            Edit::Keep => s.skip_field("embeds")?,
+            Edit::Clear => s.serialize_field("embeds", &[] as &[()])?,
+            Edit::Set(embeds) => s.serialize_field("embeds", embeds)?,
+        }
+        match &self.components {
+            Edit::Keep => s.skip_field("components")?,
+            Edit::Clear => s.serialize_field("components", &[] as &[()])?,
+            Edit::Set(components) => s.serialize_field("components", components)?,
+        }
+        match &self.flags {
+            Some(flags) => s.serialize_field("flags", flags)?,
+            None => s.skip_field("flags")?,
+        }
+        s.end()
+    }
+}
+
+/// A builder for creating a `MessageUpdate`.
+///
+/// Fields that are not set on the builder are left unchanged.
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::response::{data::MessageUpdateBuilder, InteractionResponse};
+///
+/// // Confirm the choice and remove the buttons, keeping the embeds.
+/// let update = MessageUpdateBuilder::new()
+///     .content("Confirmed!")
+///     .clear_components()
+///     .build()?;
+/// let response = InteractionResponse::UpdateMessage(update);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct MessageUpdateBuilder {
+    content: Edit<String>,
+    embeds: Edit<Vec<Embed>>,
+    components: Edit<Vec<MessageComponent>>,
+    flags: Option<MessageFlags>,
+}
+
+impl MessageUpdateBuilder {
+    pub fn new() -> Self {
+        Self {
+            content: Edit::Keep,
+            embeds: Edit::Keep,
+            components: Edit::Keep,
+            flags: None,
+        }
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Edit::Set(content.into());
+        self
+    }
+
+    pub fn clear_content(mut self) -> Self {
+        self.content = Edit::Clear;
+        self
+    }
+
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = Edit::Set(embeds);
+        self
+    }
+
+    pub fn clear_embeds(mut self) -> Self {
+        self.embeds = Edit::Clear;
+        self
+    }
+
+    pub fn components(mut self, components: Vec<MessageComponent>) -> Self {
+        self.components = Edit::Set(components);
+        self
+    }
+
+    pub fn clear_components(mut self) -> Self {
+        self.components = Edit::Clear;
+        self
+    }
+
+    /// Adds a button to the new components, packing it into action rows like
+    /// [`MessageBuilder::button`](super::MessageBuilder::button). The existing components
+    /// are replaced unless new ones were already set.
+    pub fn button(mut self, button: ButtonComponent) -> Self {
+        let mut components = match std::mem::take(&mut self.components) {
+            Edit::Set(components) => components,
+            _ => vec![],
+        };
+        push_button(&mut components, button);
+        self.components = Edit::Set(components);
+        self
+    }
+
+    /// Adds buttons, packing them into action rows five at a time. See
+    /// [`button`](Self::button).
+    pub fn buttons(mut self, buttons: impl IntoIterator<Item = ButtonComponent>) -> Self {
+        for button in buttons {
+            self = self.button(button);
+        }
+        self
+    }
+
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Builds the `MessageUpdate`.
+    ///
+    /// # Errors
+    /// Returns an error if the new content, embeds, or components do not respect the limits
+    /// `MessageBuilder::build` enforces.
+    pub fn build(self) -> Result<MessageUpdate, MessageError> {
+        validate_content(self.content.as_set().map(String::as_str))?;
+        validate_embeds(self.embeds.as_set().map_or(&[], Vec::as_slice))?;
+        if let Edit::Set(components) = &self.components {
+            validate_components(components, self.flags.as_ref())?;
+        }
+
+        Ok(MessageUpdate {
+            content: self.content,
+            embeds: self.embeds,
+            components: self.components,
+            flags: self.flags,
+        })
+    }
+}
+
+impl Default for MessageUpdateBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::response::data::EmbedBuilder;
+
+    fn to_json(update: MessageUpdateBuilder) -> Value {
+        serde_json::to_value(update.build().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn kept_fields_are_omitted() {
+        assert_eq!(to_json(MessageUpdateBuilder::new()), json!({}));
+    }
+
+    #[test]
+    fn cleared_fields_are_sent_empty() {
+        let update = MessageUpdateBuilder::new()
+            .clear_content()
+            .clear_embeds()
+            .clear_components();
+        assert_eq!(
+            to_json(update),
+            json!({ "content": "", "embeds": [], "components": [] })
+        );
+    }
+
+    #[test]
+    fn replaced_fields_are_sent_with_their_new_value() {
+        let embed = EmbedBuilder::new()
+            .description("Rolled a 4")
+            .build()
+            .unwrap();
+        let update = MessageUpdateBuilder::new()
+            .content("Confirmed!")
+            .embeds(vec![embed])
+            .components(vec![]);
+        assert_eq!(
+            to_json(update),
+            json!({
+                "content": "Confirmed!",
+                "embeds": [{ "description": "Rolled a 4" }],
+                "components": [],
+            })
+        );
+    }
+}
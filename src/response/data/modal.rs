@@ -1,5 +1,201 @@
+use std::error::Error;
+
 use serde::Serialize;
 
+use super::{ActionRowBuilder, Label, MessageComponent, SelectMenuKind, TextInput};
+use crate::types::MAX_CUSTOM_ID_LENGTH;
+
+/// The maximum length of a modal's title.
+const MAX_TITLE_LENGTH: usize = 45;
+/// The maximum number of top-level components of a modal.
+const MAX_COMPONENTS: usize = 5;
+
+/// Represents a modal, a popup form presented to the user.
+///
+/// # Fields
+/// - `custom_id`: The developer-defined identifier of the modal, sent back on submission.
+/// - `title`: The title of the modal.
+/// - `components`: The top-level components of the modal, each either an action row holding
+///   a single text input or a label.
 #[derive(Serialize)]
-// TODO: implement this struct
-pub struct Modal;
+#[non_exhaustive]
+pub struct Modal {
+    custom_id: String,
+    title: String,
+    components: Vec<MessageComponent>,
+}
+
+impl Modal {
+    pub fn custom_id(&self) -> &str {
+        &self.custom_id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn components(&self) -> &[MessageComponent] {
+        &self.components
+    }
+}
+
+/// A builder for creating a `Modal`.
+///
+/// Each text input added with [`ModalBuilder::text_input`] is placed in its own row, since
+/// Discord allows only one text input per row. Labels, which can also hold string select
+/// menus, are added with [`ModalBuilder::label`]. Components appear in the order they were
+/// added.
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::response::data::{ModalBuilder, TextInputBuilder, TextInputStyle};
+///
+/// let feedback = TextInputBuilder::new()
+///     .custom_id("feedback")
+///     .style(TextInputStyle::Paragraph)
+///     .label("What do you think?")
+///     .build()?;
+///
+/// let modal = ModalBuilder::new()
+///     .custom_id("feedback_form")
+///     .title("Feedback")
+///     .text_input(feedback)
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct ModalBuilder {
+    custom_id: Option<String>,
+    title: Option<String>,
+    components: Vec<MessageComponent>,
+}
+
+impl ModalBuilder {
+    pub fn new() -> Self {
+        Self {
+            custom_id: None,
+            title: None,
+            components: vec![],
+        }
+    }
+
+    pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+        self.custom_id = Some(custom_id.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Adds a row with the given text input.
+    pub fn text_input(mut self, text_input: TextInput) -> Self {
+        self.components
+            .push(MessageComponent::TextInput(text_input));
+        self
+    }
+
+    /// Sets the components of the modal to the given text inputs, one per row.
+    pub fn text_inputs(mut self, text_inputs: Vec<TextInput>) -> Self {
+        self.components = text_inputs
+            .into_iter()
+            .map(MessageComponent::TextInput)
+            .collect();
+        self
+    }
+
+    /// Adds the given label, holding a text input or a string select menu.
+    pub fn label(mut self, label: Label) -> Self {
+        self.components.push(MessageComponent::Label(label));
+        self
+    }
+
+    /// Builds the `Modal`.
+    ///
+    /// # Errors
+    /// Returns an error if the custom ID is not set or longer than 100 characters, if the
+    /// title is not set or longer than 45 characters, if there are no components or more
+    /// than 5, if a text input in a row has no label, if a text input in a label has a label
+    /// of its own, if a label holds a select menu that is not a string select, or if two
+    /// components share a custom ID.
+    pub fn build(self) -> Result<Modal, Box<dyn Error>> {
+        let custom_id = match self.custom_id {
+            Some(custom_id) => custom_id,
+            None => return Err("`custom_id` must be set".into()),
+        };
+        let len = custom_id.chars().count();
+        if len == 0 || len > MAX_CUSTOM_ID_LENGTH {
+            return Err("`custom_id` must be between 1 and 100 characters".into());
+        }
+
+        let title = match self.title {
+            Some(title) => title,
+            None => return Err("`title` must be set".into()),
+        };
+        let len = title.chars().count();
+        if len == 0 || len > MAX_TITLE_LENGTH {
+            return Err("`title` must be between 1 and 45 characters".into());
+        }
+
+        if self.components.is_empty() || self.components.len() > MAX_COMPONENTS {
+            return Err("a modal must have between 1 and 5 components".into());
+        }
+
+        let mut custom_ids: Vec<&str> = vec![];
+        for component in &self.components {
+            let custom_id: &str = match component {
+                MessageComponent::TextInput(input) => {
+                    if input.label.is_none() {
+                        return Err("a text input in an action row must have a label".into());
+                    }
+                    &input.custom_id
+                }
+                MessageComponent::Label(label) => match label.component() {
+                    MessageComponent::TextInput(input) => {
+                        if input.label.is_some() {
+                            return Err("a text input in a label cannot have its own label".into());
+                        }
+                        &input.custom_id
+                    }
+                    MessageComponent::SelectMenu(select) => {
+                        if select.kind() != SelectMenuKind::String {
+                            return Err("only string select menus can be used in a modal".into());
+                        }
+                        select.custom_id()
+                    }
+                    _ => return Err("a label can only hold a text input or a select menu".into()),
+                },
+                _ => return Err("a modal can only contain text inputs and labels".into()),
+            };
+            if custom_ids.contains(&custom_id) {
+                return Err(format!("duplicate `custom_id` {:?}", custom_id).into());
+            }
+            custom_ids.push(custom_id);
+        }
+
+        let components = self
+            .components
+            .into_iter()
+            .map(|component| match component {
+                MessageComponent::TextInput(_) => ActionRowBuilder::new()
+                    .component(component)
+                    .build()
+                    .map(MessageComponent::ActionRow),
+                component => Ok(component),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Modal {
+            custom_id,
+            title,
+            components,
+        })
+    }
+}
+
+impl Default for ModalBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
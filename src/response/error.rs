@@ -0,0 +1,106 @@
+use std::{error::Error, fmt, time::Duration};
+
+use reqwest::StatusCode;
+
+use crate::http::{ApiError, StatusError};
+
+/// Represents an error encountered while building a multipart request.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MultipartError {
+    /// The payload could not be serialized to JSON.
+    Json(serde_json::Error),
+    /// The content type of a file is not a valid media type.
+    ContentType(reqwest::Error),
+}
+
+impl fmt::Display for MultipartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultipartError::Json(err) => write!(f, "invalid payload JSON: {}", err),
+            MultipartError::ContentType(err) => write!(f, "invalid content type: {}", err),
+        }
+    }
+}
+
+impl Error for MultipartError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MultipartError::Json(err) => Some(err),
+            MultipartError::ContentType(err) => Some(err),
+        }
+    }
+}
+
+/// Represents an error encountered while sending a response or followup message.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ResponseError {
+    /// The request could not be sent, or its response could not be read or deserialized.
+    Http(reqwest::Error),
+    /// The multipart body of a message with files could not be built.
+    Multipart(MultipartError),
+    /// Discord kept rate limiting the request after all retries were used. `retry_after` is
+    /// the delay Discord asked for, if it sent one.
+    RateLimited { retry_after: Option<Duration> },
+    /// Discord responded with a non-success status code.
+    Api(ApiError),
+}
+
+impl ResponseError {
+    /// Returns the HTTP status code of the response, if Discord rejected the request.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            ResponseError::Api(err) => Some(err.status()),
+            ResponseError::Http(err) => err.status(),
+            ResponseError::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResponseError::Http(err) => write!(f, "request failed: {}", err),
+            ResponseError::Multipart(err) => write!(f, "invalid multipart body: {}", err),
+            ResponseError::RateLimited { retry_after } => match retry_after {
+                Some(delay) => write!(f, "rate limited, retry after {:?}", delay),
+                None => write!(f, "rate limited"),
+            },
+            ResponseError::Api(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for ResponseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ResponseError::Http(err) => Some(err),
+            ResponseError::Multipart(err) => Some(err),
+            ResponseError::RateLimited { .. } | ResponseError::Api(_) => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ResponseError {
+    fn from(err: reqwest::Error) -> Self {
+        ResponseError::Http(err)
+    }
+}
+
+impl From<MultipartError> for ResponseError {
+    fn from(err: MultipartError) -> Self {
+        ResponseError::Multipart(err)
+    }
+}
+
+impl From<StatusError> for ResponseError {
+    fn from(err: StatusError) -> Self {
+        match err {
+            StatusError::RateLimited { retry_after } => ResponseError::RateLimited { retry_after },
+            StatusError::Api(err) => ResponseError::Api(err),
+            StatusError::Http(err) => ResponseError::Http(err),
+        }
+    }
+}
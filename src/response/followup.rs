@@ -0,0 +1,297 @@
+//! Followup messages sent after the initial response to an interaction.
+//!
+//! Interaction tokens are valid for 15 minutes, during which followup messages can be sent,
+//! and the original response managed, through the webhook of the application. No bot token
+//! is needed, since the interaction token authenticates the requests.
+
+use serde::Deserialize;
+use tracing::info;
+
+use super::{
+    data::{EditMessage, Message},
+    request::{execute, with_payload},
+    ResponseError,
+};
+use crate::{http::HttpClient, types::Snowflake};
+
+/// Represents a message created by Discord in response to a request.
+///
+/// # Fields
+/// - `id`: The ID of the message.
+/// - `channel_id`: The ID of the channel the message was sent in.
+#[derive(Deserialize, Debug)]
+#[non_exhaustive]
+pub struct SentMessage {
+    id: Snowflake,
+    channel_id: Snowflake,
+}
+
+impl SentMessage {
+    /// Returns the ID of the message.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the ID of the channel the message was sent in.
+    pub fn channel_id(&self) -> Snowflake {
+        self.channel_id
+    }
+}
+
+/// Returns the webhook endpoint of the interaction with the given token.
+pub(crate) fn webhook_endpoint(http: &HttpClient, app_id: &str, token: &str) -> String {
+    http.url(&format!("webhooks/{}/{}", app_id, token))
+}
+
+/// Returns the endpoint of a message sent through the webhook of an interaction.
+///
+/// `message` is either the ID of a followup message or `@original`.
+fn message_endpoint(http: &HttpClient, app_id: &str, token: &str, message: &str) -> String {
+    format!(
+        "{}/messages/{}",
+        webhook_endpoint(http, app_id, token),
+        message
+    )
+}
+
+/// Sends a followup message for an interaction.
+///
+/// Messages with files are sent as a `multipart/form-data` request.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The token of the interaction.
+/// * `msg` - The message to send.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or if Discord rejects the message.
+///
+/// # Example
+///
+/// ```no_run
+/// use serde_discord::response::{data::MessageBuilder, followup::create_followup};
+///
+/// # async fn example(app_id: &str, token: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let msg = MessageBuilder::new().content("Done!").build()?;
+/// let sent = create_followup(app_id, token, &msg).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_followup(
+    app_id: &str,
+    token: &str,
+    msg: &Message,
+) -> Result<SentMessage, ResponseError> {
+    create_followup_with(&HttpClient::new(), app_id, token, msg).await
+}
+
+/// Sends a followup message for an interaction using `http`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, if it is still rate limited after all retries
+/// were used, or if the response cannot be deserialized.
+pub async fn create_followup_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    msg: &Message,
+) -> Result<SentMessage, ResponseError> {
+    let request = http.inner().post(webhook_endpoint(http, app_id, token));
+    let request = with_payload(request, msg, msg.files())?;
+    let sent = execute(http, request).await?.json::<SentMessage>().await?;
+    info!(app_id, message_id = %sent.id, "sent followup message");
+    Ok(sent)
+}
+
+/// Edits the original response to an interaction.
+///
+/// Fields left unchanged by `edit` are omitted from the request, and cleared fields are
+/// sent as `null`. Edits with files are sent as a `multipart/form-data` request.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The token of the interaction.
+/// * `edit` - The changes to make to the response.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or if Discord rejects the edit.
+///
+/// # Example
+///
+/// ```no_run
+/// use serde_discord::response::{data::EditMessageBuilder, followup::edit_original_response};
+///
+/// # async fn example(app_id: &str, token: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let edit = EditMessageBuilder::new()
+///     .content("Done!")
+///     .clear_components()
+///     .build()?;
+/// edit_original_response(app_id, token, &edit).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn edit_original_response(
+    app_id: &str,
+    token: &str,
+    edit: &EditMessage,
+) -> Result<SentMessage, ResponseError> {
+    edit_original_response_with(&HttpClient::new(), app_id, token, edit).await
+}
+
+/// Edits the original response to an interaction using `http`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, if it is still rate limited after all retries
+/// were used, or if the response cannot be deserialized.
+pub async fn edit_original_response_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    edit: &EditMessage,
+) -> Result<SentMessage, ResponseError> {
+    let url = message_endpoint(http, app_id, token, "@original");
+    let request = with_payload(http.inner().patch(url), edit, edit.files())?;
+    let sent = execute(http, request).await?.json::<SentMessage>().await?;
+    info!(app_id, message_id = %sent.id, "edited original response");
+    Ok(sent)
+}
+
+/// Deletes the original response to an interaction.
+///
+/// This is useful for cleaning up temporary status messages, such as a deferred response
+/// that is superseded by a followup message.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The token of the interaction.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or if Discord rejects it, e.g. because the
+/// response was already deleted.
+pub async fn delete_original_response(app_id: &str, token: &str) -> Result<(), ResponseError> {
+    delete_original_response_with(&HttpClient::new(), app_id, token).await
+}
+
+/// Deletes the original response to an interaction using `http`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, or if it is still rate limited after all retries
+/// were used.
+pub async fn delete_original_response_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+) -> Result<(), ResponseError> {
+    let url = message_endpoint(http, app_id, token, "@original");
+    execute(http, http.inner().delete(url)).await?;
+    info!(app_id, "deleted original response");
+    Ok(())
+}
+
+/// Edits a followup message of an interaction.
+///
+/// Fields left unchanged by `edit` are omitted from the request, and cleared fields are
+/// sent as `null`. Edits with files are sent as a `multipart/form-data` request.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The token of the interaction.
+/// * `message_id` - The ID of the followup message.
+/// * `edit` - The changes to make to the message.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or if Discord rejects the edit.
+///
+/// # Example
+///
+/// ```no_run
+/// use serde_discord::response::{
+///     data::{EditMessageBuilder, MessageBuilder},
+///     followup::{create_followup, edit_followup},
+/// };
+///
+/// # async fn example(app_id: &str, token: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let progress = MessageBuilder::new().content("Step 1 of 3").build()?;
+/// let sent = create_followup(app_id, token, &progress).await?;
+/// let edit = EditMessageBuilder::new().content("Step 2 of 3").build()?;
+/// edit_followup(app_id, token, sent.id(), &edit).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn edit_followup(
+    app_id: &str,
+    token: &str,
+    message_id: Snowflake,
+    edit: &EditMessage,
+) -> Result<SentMessage, ResponseError> {
+    edit_followup_with(&HttpClient::new(), app_id, token, message_id, edit).await
+}
+
+/// Edits a followup message of an interaction using `http`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, if it is still rate limited after all retries
+/// were used, or if the response cannot be deserialized.
+pub async fn edit_followup_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    message_id: Snowflake,
+    edit: &EditMessage,
+) -> Result<SentMessage, ResponseError> {
+    let url = message_endpoint(http, app_id, token, &message_id.to_string());
+    let request = with_payload(http.inner().patch(url), edit, edit.files())?;
+    let sent = execute(http, request).await?.json::<SentMessage>().await?;
+    info!(app_id, %message_id, "edited followup message");
+    Ok(sent)
+}
+
+/// Deletes a followup message of an interaction.
+///
+/// # Arguments
+///
+/// * `app_id` - The Discord application ID.
+/// * `token` - The token of the interaction.
+/// * `message_id` - The ID of the followup message.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or if Discord rejects it, e.g. because the
+/// message was already deleted.
+pub async fn delete_followup(
+    app_id: &str,
+    token: &str,
+    message_id: Snowflake,
+) -> Result<(), ResponseError> {
+    delete_followup_with(&HttpClient::new(), app_id, token, message_id).await
+}
+
+/// Deletes a followup message of an interaction using `http`.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, or if it is still rate limited after all retries
+/// were used.
+pub async fn delete_followup_with(
+    http: &HttpClient,
+    app_id: &str,
+    token: &str,
+    message_id: Snowflake,
+) -> Result<(), ResponseError> {
+    let url = message_endpoint(http, app_id, token, &message_id.to_string());
+    execute(http, http.inner().delete(url)).await?;
+    info!(app_id, %message_id, "deleted followup message");
+    Ok(())
+}
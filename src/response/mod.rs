@@ -1,4 +1,11 @@
+mod callback;
 pub mod data;
+mod error;
+pub mod followup;
+pub mod pagination;
+mod request;
 mod response;
 
+pub use callback::*;
+pub use error::*;
 pub use response::*;
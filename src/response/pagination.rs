@@ -0,0 +1,331 @@
+//! Paginated messages navigated with buttons.
+//!
+//! A [`Paginator`] renders one of its pages together with a row of buttons to jump to the
+//! first page, go to the previous or next page, and jump to the last page. The page each
+//! button leads to is encoded in its `custom_id`, so no state has to be kept between
+//! interactions: when a button is clicked, [`Paginator::update`] decodes the target page and
+//! returns the `UpdateMessage` response showing it.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use serde_discord::{
+//!     interaction::{Interaction, MessageComponentData},
+//!     response::{
+//!         pagination::{Page, PaginationError, Paginator},
+//!         send, InteractionResponse,
+//!     },
+//! };
+//!
+//! # async fn example(
+//! #     interaction: &Interaction,
+//! #     data: &MessageComponentData,
+//! # ) -> Result<(), Box<dyn std::error::Error>> {
+//! let paginator = Paginator::new(
+//!     "help",
+//!     vec![Page::new().content("Page one"), Page::new().content("Page two")],
+//! );
+//!
+//! // Send the first page as the initial response.
+//! let response = InteractionResponse::Message(paginator.message()?);
+//!
+//! // Later, when one of its buttons is clicked:
+//! match paginator.update(data.custom_id()) {
+//!     Ok(response) => send(interaction.id(), interaction.token(), &response).await?,
+//!     Err(PaginationError::OtherPaginator) => { /* not one of our buttons */ }
+//!     Err(err) => return Err(err.into()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{error::Error, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    data::{
+        ButtonComponent, ButtonComponentBuilder, ButtonStyle, Embed, Message, MessageBuilder,
+        MessageError, MessageUpdateBuilder,
+    },
+    InteractionResponse,
+};
+use crate::types::{CustomId, CustomIdError};
+
+/// Represents a page of a paginated message.
+///
+/// # Fields
+/// - `content`: The text of the page.
+/// - `embeds`: The embeds of the page.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Page {
+    content: Option<String>,
+    embeds: Vec<Embed>,
+}
+
+impl Page {
+    pub fn new() -> Self {
+        Self {
+            content: None,
+            embeds: vec![],
+        }
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = embeds;
+        self
+    }
+}
+
+impl Default for Page {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents the navigation buttons of a paginated message.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageButton {
+    /// Jumps to the first page.
+    First,
+    /// Goes to the previous page.
+    Previous,
+    /// Shows the current page number. Always disabled.
+    Current,
+    /// Goes to the next page.
+    Next,
+    /// Jumps to the last page.
+    Last,
+}
+
+/// Represents the state encoded in the `custom_id` of a navigation button.
+///
+/// The button is part of the state since Discord requires the custom IDs of a message to be
+/// unique, and several buttons can lead to the same page. Its custom IDs start with
+/// `serde_discord.page`, so they do not collide with those of the application's own types.
+///
+/// # Fields
+/// - `paginator`: The ID of the paginator the button belongs to.
+/// - `button`: Which navigation button it is.
+/// - `page`: The index of the page the button leads to.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PageState {
+    pub paginator: String,
+    pub button: PageButton,
+    pub page: usize,
+}
+
+impl CustomId for PageState {
+    const PREFIX: &'static str = "serde_discord.page";
+}
+
+/// Represents an error encountered while rendering a page or handling a navigation button.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PaginationError {
+    /// The paginator has no pages.
+    NoPages,
+    /// A page past the last page was requested.
+    PageOutOfRange { page: usize, count: usize },
+    /// The button belongs to a different paginator, or is not a navigation button.
+    OtherPaginator,
+    /// The state of a button could not be encoded or decoded.
+    CustomId(CustomIdError),
+    /// The page could not be built into a message.
+    Message(MessageError),
+}
+
+impl fmt::Display for PaginationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaginationError::NoPages => write!(f, "a paginator must have pages"),
+            PaginationError::PageOutOfRange { page, count } => write!(
+                f,
+                "page {} was requested, but there are only {} pages",
+                page, count
+            ),
+            PaginationError::OtherPaginator => {
+                write!(f, "the button does not belong to this paginator")
+            }
+            PaginationError::CustomId(err) => write!(f, "invalid button state: {}", err),
+            PaginationError::Message(err) => write!(f, "invalid page: {}", err),
+        }
+    }
+}
+
+impl Error for PaginationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PaginationError::CustomId(err) => Some(err),
+            PaginationError::Message(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<CustomIdError> for PaginationError {
+    fn from(err: CustomIdError) -> Self {
+        PaginationError::CustomId(err)
+    }
+}
+
+impl From<MessageError> for PaginationError {
+    fn from(err: MessageError) -> Self {
+        PaginationError::Message(err)
+    }
+}
+
+/// Renders pages as messages with navigation buttons.
+///
+/// # Fields
+/// - `id`: Identifies the buttons of this paginator among those of other paginators. It is
+///   encoded in every button, so it should be short.
+/// - `pages`: The pages of the paginated message.
+#[non_exhaustive]
+pub struct Paginator {
+    id: String,
+    pages: Vec<Page>,
+}
+
+impl Paginator {
+    pub fn new(id: impl Into<String>, pages: Vec<Page>) -> Self {
+        Self {
+            id: id.into(),
+            pages,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn pages(&self) -> &[Page] {
+        &self.pages
+    }
+
+    /// Renders the first page.
+    ///
+    /// # Errors
+    /// Returns an error if there are no pages. See [`render`](Self::render).
+    pub fn message(&self) -> Result<Message, PaginationError> {
+        self.render(0)
+    }
+
+    /// Renders the page at index `page` with its navigation buttons.
+    ///
+    /// # Errors
+    /// Returns an error if `page` is out of range, if the paginator ID is too long to fit in
+    /// the custom IDs of the buttons, or if the page is not a valid message.
+    pub fn render(&self, page: usize) -> Result<Message, PaginationError> {
+        let (current, buttons) = self.page(page)?;
+        let mut builder = MessageBuilder::new();
+        if let Some(content) = &current.content {
+            builder = builder.content(content.clone());
+        }
+        if !current.embeds.is_empty() {
+            builder = builder.embeds(current.embeds.clone());
+        }
+        Ok(builder.buttons(buttons).build()?)
+    }
+
+    /// Returns the page at index `page` together with its navigation buttons.
+    fn page(&self, page: usize) -> Result<(&Page, [ButtonComponent; 5]), PaginationError> {
+        let count = self.pages.len();
+        if count == 0 {
+            return Err(PaginationError::NoPages);
+        }
+        let current = self
+            .pages
+            .get(page)
+            .ok_or(PaginationError::PageOutOfRange { page, count })?;
+
+        let last = count - 1;
+        let buttons = [
+            self.button(PageButton::First, "«", 0, page == 0)?,
+            self.button(PageButton::Previous, "‹", page.saturating_sub(1), page == 0)?,
+            self.button(
+                PageButton::Current,
+                &format!("{}/{}", page + 1, count),
+                page,
+                true,
+            )?,
+            self.button(PageButton::Next, "›", (page + 1).min(last), page == last)?,
+            self.button(PageButton::Last, "»", last, page == last)?,
+        ];
+        Ok((current, buttons))
+    }
+
+    /// Computes the response to a click on one of the navigation buttons, given the
+    /// `custom_id` of the clicked component.
+    ///
+    /// # Errors
+    /// Returns [`PaginationError::OtherPaginator`] if the component is not a navigation
+    /// button of this paginator, so that it can be handled elsewhere. Otherwise, returns an
+    /// error if the page cannot be rendered, such as when the pages changed since the
+    /// button was sent.
+    pub fn update(&self, custom_id: &str) -> Result<InteractionResponse, PaginationError> {
+        let state = match PageState::decode(custom_id) {
+            Ok(state) => state,
+            Err(CustomIdError::PrefixMismatch { .. }) => {
+                return Err(PaginationError::OtherPaginator)
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if state.paginator != self.id {
+            return Err(PaginationError::OtherPaginator);
+        }
+
+        // Content and embeds are cleared when the page has none, so that those of the
+        // previous page do not remain.
+        let (page, buttons) = self.page(state.page)?;
+        let mut builder = MessageUpdateBuilder::new().buttons(buttons);
+        builder = match &page.content {
+            Some(content) => builder.content(content.clone()),
+            None => builder.clear_content(),
+        };
+        builder = if page.embeds.is_empty() {
+            builder.clear_embeds()
+        } else {
+            builder.embeds(page.embeds.clone())
+        };
+        Ok(InteractionResponse::UpdateMessage(builder.build()?))
+    }
+
+    /// Builds a navigation button leading to `page`.
+    fn button(
+        &self,
+        button: PageButton,
+        label: &str,
+        page: usize,
+        disabled: bool,
+    ) -> Result<ButtonComponent, PaginationError> {
+        let state = PageState {
+            paginator: self.id.clone(),
+            button,
+            page,
+        };
+        let style = match button {
+            PageButton::Current => ButtonStyle::Primary,
+            _ => ButtonStyle::Secondary,
+        };
+        let builder = ButtonComponentBuilder::new()
+            .style(style)
+            .label(label)
+            .disabled(disabled)
+            .state(&state)?;
+        // Navigation buttons have a short label and an encoded custom ID, so they are valid.
+        Ok(builder.build().expect("navigation buttons are valid"))
+    }
+}
@@ -0,0 +1,30 @@
+use reqwest::{RequestBuilder, Response};
+use serde::Serialize;
+
+use super::{data::multipart_form, data::Attachment, ResponseError};
+use crate::http::{error_for_status, HttpClient};
+
+/// Attaches `payload` to `request` as JSON, or as a multipart body if `files` is not empty.
+pub(crate) fn with_payload(
+    request: RequestBuilder,
+    payload: &impl Serialize,
+    files: &[Attachment],
+) -> Result<RequestBuilder, ResponseError> {
+    if files.is_empty() {
+        Ok(request.json(payload))
+    } else {
+        Ok(request.multipart(multipart_form(payload, files)?))
+    }
+}
+
+/// Sends `request` with `http`, returning the response if it has a success status.
+///
+/// Requests with files cannot be retried when they are rate limited, since their multipart
+/// body is streamed.
+pub(crate) async fn execute(
+    http: &HttpClient,
+    request: RequestBuilder,
+) -> Result<Response, ResponseError> {
+    let response = http.send(request).await?;
+    Ok(error_for_status(response).await?)
+}
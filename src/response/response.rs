@@ -1,6 +1,16 @@
+use std::io;
+
+use reqwest::multipart::Form;
 use serde::{ser::SerializeStruct, Serialize};
 
-use super::data::{Autocomplete, Message, Modal};
+use super::{
+    data::{multipart_form, Attachment, Autocomplete, Message, MessageUpdate, Modal},
+    MultipartError,
+};
+
+/// The initial capacity of the buffer `InteractionResponse::to_json_vec` serializes into,
+/// large enough for typical messages to be serialized without reallocating.
+const JSON_BUFFER_CAPACITY: usize = 1024;
 
 /// Represents different types of interaction responses.
 /// The interaction response can range from simple acknowledgments like Pong to complex messages, modals, or autocomplete suggestions.
@@ -14,12 +24,82 @@ pub enum InteractionResponse {
     DeferResponse,
     /// A deferred message update, which may contain content or components that will be sent later.
     DeferredUpdateMessage(Message),
-    /// An updated message response, allowing an existing message to be modified.
-    UpdateMessage(Message),
+    /// An updated message response, modifying the message the component is attached to.
+    UpdateMessage(MessageUpdate),
     /// A response that provides autocomplete suggestions.
     Autocomplete(Autocomplete),
     /// A modal response, typically used to present a form to the user.
     Modal(Modal),
+    /// A response that tells the user an upgrade is required to use the command.
+    ///
+    /// Deprecated by Discord in favor of premium buttons, which are sent with a regular
+    /// message. It is kept for apps that still rely on it.
+    #[deprecated(note = "Discord deprecated this response in favor of premium buttons")]
+    PremiumRequired,
+    /// A response that launches the Activity of the application, typically in response to
+    /// a primary entry point command.
+    LaunchActivity,
+}
+
+impl InteractionResponse {
+    /// Returns the files uploaded together with the response message, if any.
+    pub fn files(&self) -> &[Attachment] {
+        match self {
+            InteractionResponse::Message(msg) | InteractionResponse::DeferredUpdateMessage(msg) => {
+                msg.files()
+            }
+            _ => &[],
+        }
+    }
+
+    /// Builds a `multipart/form-data` body with the response as `payload_json` and the files
+    /// of its message.
+    ///
+    /// Responses with files must be sent this way to the interaction callback endpoint.
+    ///
+    /// # Errors
+    /// Returns an error if the response cannot be serialized or a file has an invalid
+    /// content type.
+    pub fn to_multipart(&self) -> Result<Form, MultipartError> {
+        multipart_form(self, self.files())
+    }
+
+    /// Serializes the response to JSON, into a buffer sized up front for typical responses.
+    ///
+    /// # Errors
+    /// Returns an error if the response cannot be serialized.
+    pub fn to_json_vec(&self) -> Result<Vec<u8>, serde_json::Error> {
+        let mut buf = Vec::with_capacity(JSON_BUFFER_CAPACITY);
+        self.to_json_writer(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Serializes the response to JSON into `writer`.
+    ///
+    /// Passing a `&mut Vec<u8>` that is cleared between responses reuses its allocation
+    /// across requests.
+    ///
+    /// # Errors
+    /// Returns an error if the response cannot be serialized or `writer` fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use serde_discord::response::InteractionResponse;
+    /// # async fn send(_body: &[u8]) -> std::io::Result<()> { Ok(()) }
+    /// # async fn example(responses: &[InteractionResponse]) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut buf = Vec::new();
+    /// for response in responses {
+    ///     buf.clear();
+    ///     response.to_json_writer(&mut buf)?;
+    ///     send(&buf).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_json_writer<W: io::Write>(&self, writer: W) -> Result<(), serde_json::Error> {
+        serde_json::to_writer(writer, self)
+    }
 }
 
 impl Serialize for InteractionResponse {
@@ -45,9 +125,9 @@ impl Serialize for InteractionResponse {
                 s.serialize_field("type", &6)?;
                 s.serialize_field("data", &msg)?;
             }
-            InteractionResponse::UpdateMessage(msg) => {
+            InteractionResponse::UpdateMessage(update) => {
                 s.serialize_field("type", &7)?;
-                s.serialize_field("data", &msg)?;
+                s.serialize_field("data", &update)?;
             }
             InteractionResponse::Autocomplete(autocomplete) => {
                 s.serialize_field("type", &8)?;
@@ -57,6 +137,15 @@ impl Serialize for InteractionResponse {
                 s.serialize_field("type", &9)?;
                 s.serialize_field("data", &modal)?;
             }
+            #[allow(deprecated)]
+            InteractionResponse::PremiumRequired => {
+                s.serialize_field("type", &10)?;
+                s.skip_field("data")?;
+            }
+            InteractionResponse::LaunchActivity => {
+                s.serialize_field("type", &12)?;
+                s.skip_field("data")?;
+            }
         };
         s.end()
     }
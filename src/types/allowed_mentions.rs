@@ -0,0 +1,200 @@
+use std::error::Error;
+
+use serde::{Deserialize, Serialize};
+
+use super::Snowflake;
+
+/// The maximum number of users or roles that can be allowed.
+const MAX_IDS: usize = 100;
+
+/// The types of mentions Discord parses from the content of a message.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AllowedMentionType {
+    /// Role mentions, such as `<@&123>`.
+    Roles,
+    /// User mentions, such as `<@123>`.
+    Users,
+    /// `@everyone` and `@here` mentions.
+    Everyone,
+}
+
+/// Controls which mentions in a message notify their targets.
+///
+/// Mentions that are not allowed are still rendered, but nobody is pinged. This is useful
+/// when echoing user input, which may contain `@everyone` or role mentions.
+///
+/// Allowed mentions are both serialized, when building messages, and deserialized, such as
+/// when reading back a message sent with them.
+///
+/// # Fields
+/// - `parse`: The types of mentions parsed from the content.
+/// - `users`: The users that may be mentioned. Cannot be combined with `Users` in `parse`.
+/// - `roles`: The roles that may be mentioned. Cannot be combined with `Roles` in `parse`.
+/// - `replied_user`: Whether the author of the replied-to message is mentioned.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AllowedMentions {
+    #[serde(default)]
+    parse: Vec<AllowedMentionType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    users: Vec<Snowflake>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    roles: Vec<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replied_user: Option<bool>,
+}
+
+impl AllowedMentions {
+    /// Returns an `AllowedMentions` that suppresses every mention.
+    pub fn none() -> Self {
+        Self {
+            parse: vec![],
+            users: vec![],
+            roles: vec![],
+            replied_user: None,
+        }
+    }
+
+    pub fn parse(&self) -> &[AllowedMentionType] {
+        &self.parse
+    }
+
+    pub fn users(&self) -> &[Snowflake] {
+        &self.users
+    }
+
+    pub fn roles(&self) -> &[Snowflake] {
+        &self.roles
+    }
+
+    pub fn replied_user(&self) -> bool {
+        self.replied_user.unwrap_or(false)
+    }
+}
+
+impl Default for AllowedMentions {
+    /// Returns an `AllowedMentions` that suppresses every mention. See
+    /// [`none`](Self::none).
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+/// A builder for creating an `AllowedMentions`.
+///
+/// Nothing is allowed unless enabled on the builder, so building it without calling any
+/// method suppresses every mention.
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::types::{AllowedMentionType, AllowedMentionsBuilder, Snowflake};
+///
+/// // Ping mentioned users, but never roles, `@everyone`, or `@here`.
+/// let allowed_mentions = AllowedMentionsBuilder::new()
+///     .parse(AllowedMentionType::Users)
+///     .build()
+///     .unwrap();
+///
+/// // Only ping the given role and the author of the replied-to message.
+/// let allowed_mentions = AllowedMentionsBuilder::new()
+///     .roles(vec![Snowflake::new(41771983423143936)])
+///     .replied_user(true)
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     serde_json::to_string(&allowed_mentions).unwrap(),
+///     r#"{"parse":[],"roles":["41771983423143936"],"replied_user":true}"#
+/// );
+/// ```
+pub struct AllowedMentionsBuilder {
+    parse: Vec<AllowedMentionType>,
+    users: Vec<Snowflake>,
+    roles: Vec<Snowflake>,
+    replied_user: Option<bool>,
+}
+
+impl AllowedMentionsBuilder {
+    pub fn new() -> Self {
+        Self {
+            parse: vec![],
+            users: vec![],
+            roles: vec![],
+            replied_user: None,
+        }
+    }
+
+    /// Allows every mention of the given type in the content.
+    pub fn parse(mut self, kind: AllowedMentionType) -> Self {
+        if !self.parse.contains(&kind) {
+            self.parse.push(kind);
+        }
+        self
+    }
+
+    /// Allows `@everyone` and `@here` mentions in the content.
+    pub fn everyone(self) -> Self {
+        self.parse(AllowedMentionType::Everyone)
+    }
+
+    /// Allows mentions of the given user. At most 100 users can be allowed.
+    pub fn user(mut self, user_id: Snowflake) -> Self {
+        self.users.push(user_id);
+        self
+    }
+
+    /// Allows mentions of the given users. At most 100 users can be allowed.
+    pub fn users(mut self, user_ids: Vec<Snowflake>) -> Self {
+        self.users = user_ids;
+        self
+    }
+
+    /// Allows mentions of the given role. At most 100 roles can be allowed.
+    pub fn role(mut self, role_id: Snowflake) -> Self {
+        self.roles.push(role_id);
+        self
+    }
+
+    /// Allows mentions of the given roles. At most 100 roles can be allowed.
+    pub fn roles(mut self, role_ids: Vec<Snowflake>) -> Self {
+        self.roles = role_ids;
+        self
+    }
+
+    /// Sets whether the author of the message being replied to is mentioned.
+    pub fn replied_user(mut self, replied_user: bool) -> Self {
+        self.replied_user = Some(replied_user);
+        self
+    }
+
+    /// Builds the `AllowedMentions`.
+    ///
+    /// # Errors
+    /// Returns an error if users or roles are both parsed and listed, since Discord rejects
+    /// that combination, or if more than 100 users or roles are listed.
+    pub fn build(self) -> Result<AllowedMentions, Box<dyn Error>> {
+        if !self.users.is_empty() && self.parse.contains(&AllowedMentionType::Users) {
+            return Err("`users` cannot be set when parsing user mentions".into());
+        }
+        if !self.roles.is_empty() && self.parse.contains(&AllowedMentionType::Roles) {
+            return Err("`roles` cannot be set when parsing role mentions".into());
+        }
+        if self.users.len() > MAX_IDS || self.roles.len() > MAX_IDS {
+            return Err("at most 100 users and 100 roles can be allowed".into());
+        }
+
+        Ok(AllowedMentions {
+            parse: self.parse,
+            users: self.users,
+            roles: self.roles,
+            replied_user: self.replied_user,
+        })
+    }
+}
+
+impl Default for AllowedMentionsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
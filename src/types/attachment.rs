@@ -0,0 +1,88 @@
+use serde::Deserialize;
+
+use super::Snowflake;
+
+/// Represents a file attached to a message or passed to an attachment option.
+///
+/// This is the attachment as received from Discord. To upload files with a response, see
+/// `response::data::Attachment`.
+///
+/// # Fields
+/// - `id`: The ID of the attachment.
+/// - `filename`: The name of the attached file.
+/// - `description`: The description of the file, used as alt text.
+/// - `content_type`: The media type of the file, e.g. `"image/png"`.
+/// - `size`: The size of the file in bytes.
+/// - `url`: The source URL of the file.
+/// - `proxy_url`: The proxied URL of the file.
+/// - `height`: The height of the file, for images and videos.
+/// - `width`: The width of the file, for images and videos.
+/// - `ephemeral`: Whether the attachment is ephemeral, such as those passed to options.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Attachment {
+    id: Snowflake,
+    filename: String,
+    description: Option<String>,
+    content_type: Option<String>,
+    size: u64,
+    url: String,
+    proxy_url: String,
+    height: Option<u32>,
+    width: Option<u32>,
+    #[serde(default)]
+    ephemeral: bool,
+}
+
+impl Attachment {
+    /// Returns the ID of the attachment.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the name of the attached file.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Returns the description of the file, if set.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the media type of the file, if known.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the source URL of the file.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the proxied URL of the file.
+    pub fn proxy_url(&self) -> &str {
+        &self.proxy_url
+    }
+
+    /// Returns the height of the file, for images and videos.
+    pub fn height(&self) -> Option<u32> {
+        self.height
+    }
+
+    /// Returns the width of the file, for images and videos.
+    pub fn width(&self) -> Option<u32> {
+        self.width
+    }
+
+    /// Returns whether the attachment is ephemeral. Ephemeral attachments are removed after a
+    /// set period of time.
+    pub fn ephemeral(&self) -> bool {
+        self.ephemeral
+    }
+}
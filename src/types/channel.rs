@@ -0,0 +1,182 @@
+use serde::Deserialize;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use super::{Permissions, Snowflake};
+
+/// Represents the type of a channel.
+///
+/// - `GuildText`: A text channel within a guild (value 0).
+/// - `Dm`: A direct message between users (value 1).
+/// - `GuildVoice`: A voice channel within a guild (value 2).
+/// - `GroupDm`: A direct message between multiple users (value 3).
+/// - `GuildCategory`: A category that contains up to 50 channels (value 4).
+/// - `GuildAnnouncement`: A channel that users can follow and crosspost into their own guild (value 5).
+/// - `AnnouncementThread`: A temporary sub-channel within an announcement channel (value 10).
+/// - `PublicThread`: A temporary sub-channel within a text, forum, or media channel (value 11).
+/// - `PrivateThread`: A temporary sub-channel within a text channel, visible to invitees (value 12).
+/// - `GuildStageVoice`: A voice channel for hosting events with an audience (value 13).
+/// - `GuildDirectory`: The channel in a hub containing the listed guilds (value 14).
+/// - `GuildForum`: A channel that can only contain threads (value 15).
+/// - `GuildMedia`: A channel that can only contain threads, similar to forums (value 16).
+#[derive(Deserialize_repr, Serialize_repr, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ChannelType {
+    /// A text channel within a guild.
+    GuildText = 0,
+    /// A direct message between users.
+    Dm = 1,
+    /// A voice channel within a guild.
+    GuildVoice = 2,
+    /// A direct message between multiple users.
+    GroupDm = 3,
+    /// A category that contains up to 50 channels.
+    GuildCategory = 4,
+    /// A channel that users can follow and crosspost into their own guild.
+    GuildAnnouncement = 5,
+    /// A temporary sub-channel within an announcement channel.
+    AnnouncementThread = 10,
+    /// A temporary sub-channel within a text, forum, or media channel.
+    PublicThread = 11,
+    /// A temporary sub-channel within a text channel, only visible to those invited.
+    PrivateThread = 12,
+    /// A voice channel for hosting events with an audience.
+    GuildStageVoice = 13,
+    /// The channel in a hub containing the listed guilds.
+    GuildDirectory = 14,
+    /// A channel that can only contain threads.
+    GuildForum = 15,
+    /// A channel that can only contain threads, similar to forum channels.
+    GuildMedia = 16,
+}
+
+impl ChannelType {
+    /// Returns whether the channel is a thread.
+    pub fn is_thread(&self) -> bool {
+        matches!(
+            self,
+            ChannelType::AnnouncementThread
+                | ChannelType::PublicThread
+                | ChannelType::PrivateThread
+        )
+    }
+
+    /// Returns whether the channel is a direct message or group direct message.
+    pub fn is_dm(&self) -> bool {
+        matches!(self, ChannelType::Dm | ChannelType::GroupDm)
+    }
+}
+
+/// Represents the partial channel object Discord sends with interactions, such as the
+/// channel an interaction was sent from or a resolved channel option.
+///
+/// # Fields
+/// - `id`: The ID of the channel.
+/// - `name`: The name of the channel, absent for DMs.
+/// - `kind`: The type of the channel.
+/// - `permissions`: The permissions of the invoking user in the channel, including overwrites.
+/// - `thread_metadata`: The thread-specific fields, for threads.
+/// - `parent_id`: The ID of the category of a channel, or the channel a thread was created in.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct PartialChannel {
+    id: Snowflake,
+    name: Option<String>,
+    #[serde(rename = "type")]
+    kind: ChannelType,
+    permissions: Option<Permissions>,
+    thread_metadata: Option<ThreadMetadata>,
+    parent_id: Option<Snowflake>,
+}
+
+impl PartialChannel {
+    /// Returns the ID of the channel.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the name of the channel, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the type of the channel.
+    pub fn kind(&self) -> ChannelType {
+        self.kind
+    }
+
+    /// Returns the permissions of the invoking user in the channel, if provided.
+    pub fn permissions(&self) -> Option<Permissions> {
+        self.permissions
+    }
+
+    /// Returns the thread-specific fields, if the channel is a thread.
+    pub fn thread_metadata(&self) -> Option<&ThreadMetadata> {
+        self.thread_metadata.as_ref()
+    }
+
+    /// Returns the ID of the category of the channel, or the ID of the channel a thread was
+    /// created in.
+    pub fn parent_id(&self) -> Option<Snowflake> {
+        self.parent_id
+    }
+
+    /// Returns the markdown that renders as a mention of the channel, such as `<#123>`.
+    pub fn mention(&self) -> String {
+        format!("<#{}>", self.id)
+    }
+}
+
+/// Represents the fields specific to threads.
+///
+/// # Fields
+/// - `archived`: Whether the thread is archived.
+/// - `auto_archive_duration`: The minutes of inactivity after which the thread is archived.
+/// - `archive_timestamp`: When the thread was last archived or unarchived, as an ISO8601
+///   timestamp.
+/// - `locked`: Whether only moderators can unarchive the thread.
+/// - `invitable`: Whether non-moderators can add other non-moderators to a private thread.
+/// - `create_timestamp`: When the thread was created, as an ISO8601 timestamp.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ThreadMetadata {
+    archived: bool,
+    auto_archive_duration: u32,
+    archive_timestamp: String,
+    locked: bool,
+    invitable: Option<bool>,
+    create_timestamp: Option<String>,
+}
+
+impl ThreadMetadata {
+    /// Returns whether the thread is archived.
+    pub fn archived(&self) -> bool {
+        self.archived
+    }
+
+    /// Returns the minutes of inactivity after which the thread is archived.
+    pub fn auto_archive_duration(&self) -> u32 {
+        self.auto_archive_duration
+    }
+
+    /// Returns when the thread was last archived or unarchived, as an ISO8601 timestamp.
+    pub fn archive_timestamp(&self) -> &str {
+        &self.archive_timestamp
+    }
+
+    /// Returns whether only moderators can unarchive the thread.
+    pub fn locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Returns whether non-moderators can add other non-moderators to the thread, for
+    /// private threads.
+    pub fn invitable(&self) -> Option<bool> {
+        self.invitable
+    }
+
+    /// Returns when the thread was created, as an ISO8601 timestamp, for threads created
+    /// after 2022-01-09.
+    pub fn create_timestamp(&self) -> Option<&str> {
+        self.create_timestamp.as_deref()
+    }
+}
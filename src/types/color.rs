@@ -0,0 +1,130 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// Represents an RGB color, such as the color of an embed or a role.
+///
+/// Colors are (de)serialized as the `0xRRGGBB` integer Discord expects.
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::types::Color;
+///
+/// assert_eq!(Color::from_rgb(88, 101, 242), Color::BLURPLE);
+/// assert_eq!(Color::from_hex("#5865F2").unwrap(), Color::BLURPLE);
+/// assert_eq!(Color::BLURPLE.value(), 0x5865F2);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(transparent)]
+pub struct Color(u32);
+
+impl Color {
+    /// Discord's blurple, `#5865F2`.
+    pub const BLURPLE: Color = Color(0x5865F2);
+    /// Discord's green, `#57F287`.
+    pub const GREEN: Color = Color(0x57F287);
+    /// Discord's yellow, `#FEE75C`.
+    pub const YELLOW: Color = Color(0xFEE75C);
+    /// Discord's fuchsia, `#EB459E`.
+    pub const FUCHSIA: Color = Color(0xEB459E);
+    /// Discord's red, `#ED4245`.
+    pub const RED: Color = Color(0xED4245);
+    /// White, `#FFFFFF`.
+    pub const WHITE: Color = Color(0xFFFFFF);
+    /// Black, `#000000`.
+    pub const BLACK: Color = Color(0x000000);
+
+    /// Creates a color from its `0xRRGGBB` value. Bits above the lowest 24 are ignored.
+    pub const fn new(value: u32) -> Self {
+        Color(value & 0xFFFFFF)
+    }
+
+    /// Creates a color from its red, green, and blue components.
+    pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Color((r as u32) << 16 | (g as u32) << 8 | b as u32)
+    }
+
+    /// Parses a color from a hex string such as `"#5865F2"`, with or without the `#`.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not 6 hexadecimal digits, optionally preceded by
+    /// `#`.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseColorError {
+                input: hex.to_string(),
+            });
+        }
+        let value = u32::from_str_radix(digits, 16).map_err(|_| ParseColorError {
+            input: hex.to_string(),
+        })?;
+        Ok(Color(value))
+    }
+
+    /// Returns the `0xRRGGBB` value of the color.
+    pub const fn value(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the red component of the color.
+    pub const fn r(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Returns the green component of the color.
+    pub const fn g(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Returns the blue component of the color.
+    pub const fn b(&self) -> u8 {
+        self.0 as u8
+    }
+}
+
+impl From<u32> for Color {
+    fn from(value: u32) -> Self {
+        Color::new(value)
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Color::from_rgb(r, g, b)
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::from_hex(s)
+    }
+}
+
+impl fmt::Display for Color {
+    /// Formats the color as a hex string, such as `#5865F2`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:06X}", self.0)
+    }
+}
+
+/// Represents an error encountered while parsing a hex color string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid color {:?}, expected 6 hex digits such as \"#5865F2\"",
+            self.input
+        )
+    }
+}
+
+impl Error for ParseColorError {}
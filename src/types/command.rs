@@ -9,7 +9,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 /// - `User`: A command that is related to user interactions (value 2).
 /// - `Message`: A command that is tied to message interactions (value 3).
 /// - `PrimaryEntryPoint`: A command that serves as the primary entry point for interactions (value 4).
-#[derive(Deserialize_repr, Serialize_repr, Debug)]
+#[derive(Deserialize_repr, Serialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum CommandKind {
     /// Command for chat input.
@@ -21,3 +21,19 @@ pub enum CommandKind {
     /// Command serving as the primary entry point.
     PrimaryEntryPoint = 4,
 }
+
+/// Determines how an interaction with a primary entry point command is handled.
+///
+/// This enum is only applicable to commands of kind `PrimaryEntryPoint`:
+///
+/// - `AppHandler`: The app handles the interaction using an interaction token (value 1).
+/// - `DiscordLaunchActivity`: Discord handles the interaction by launching the app's
+///   Activity and sending a follow-up message without coordinating with the app (value 2).
+#[derive(Deserialize_repr, Serialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EntryPointHandler {
+    /// The app handles the interaction.
+    AppHandler = 1,
+    /// Discord launches the associated Activity.
+    DiscordLaunchActivity = 2,
+}
@@ -16,7 +16,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 /// - `Mentionable`: A command option that can mention users, roles, or channels (value 9).
 /// - `Number`: A command option that represents a floating-point number (value 10).
 /// - `Attachment`: A command option that represents a file attachment (value 11).
-#[derive(Serialize_repr, Deserialize_repr, Debug)]
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum CommandOptionKind {
     /// Represents a sub-command.
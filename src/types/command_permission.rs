@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use super::Snowflake;
+
+/// Represents the type of entity a command permission applies to.
+///
+/// - `Role`: The permission applies to a role (value 1).
+/// - `User`: The permission applies to a user (value 2).
+/// - `Channel`: The permission applies to a channel (value 3).
+#[derive(Deserialize_repr, Serialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ApplicationCommandPermissionType {
+    /// The permission applies to a role.
+    Role = 1,
+    /// The permission applies to a user.
+    User = 2,
+    /// The permission applies to a channel.
+    Channel = 3,
+}
+
+/// Represents a permission overwrite of a command for a role, user, or channel.
+///
+/// # Fields
+/// - `id`: The ID of the role, user, or channel.
+/// - `kind`: The type of entity the permission applies to.
+/// - `permission`: Whether the command is allowed (`true`) or denied (`false`).
+///
+/// Discord uses two constants in place of IDs for guild-wide overwrites: the guild ID stands
+/// for the `@everyone` role, and the guild ID minus one stands for all channels of the guild.
+/// See [`everyone`](Self::everyone) and [`all_channels`](Self::all_channels).
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::types::{ApplicationCommandPermission, Snowflake};
+///
+/// let guild_id = Snowflake::new(613425648685547541);
+/// let permission = ApplicationCommandPermission::all_channels(guild_id, false);
+/// assert_eq!(permission.id(), Snowflake::new(613425648685547540));
+/// assert!(permission.is_all_channels(guild_id));
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ApplicationCommandPermission {
+    id: Snowflake,
+    #[serde(rename = "type")]
+    kind: ApplicationCommandPermissionType,
+    permission: bool,
+}
+
+impl ApplicationCommandPermission {
+    /// Creates a permission overwrite allowing or denying the command for the role, user, or
+    /// channel with the given ID.
+    pub fn new(id: Snowflake, kind: ApplicationCommandPermissionType, permission: bool) -> Self {
+        Self {
+            id,
+            kind,
+            permission,
+        }
+    }
+
+    /// Creates a permission overwrite allowing or denying the command for every member of
+    /// the guild, through its `@everyone` role.
+    pub fn everyone(guild_id: Snowflake, permission: bool) -> Self {
+        Self::new(
+            everyone_role_id(guild_id),
+            ApplicationCommandPermissionType::Role,
+            permission,
+        )
+    }
+
+    /// Creates a permission overwrite allowing or denying the command in every channel of
+    /// the guild.
+    pub fn all_channels(guild_id: Snowflake, permission: bool) -> Self {
+        Self::new(
+            all_channels_id(guild_id),
+            ApplicationCommandPermissionType::Channel,
+            permission,
+        )
+    }
+
+    /// Returns the ID of the role, user, or channel the permission applies to.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the type of entity the permission applies to.
+    pub fn kind(&self) -> ApplicationCommandPermissionType {
+        self.kind
+    }
+
+    /// Returns whether the command is allowed for the entity.
+    pub fn permission(&self) -> bool {
+        self.permission
+    }
+
+    /// Returns whether the overwrite applies to every member of the guild with the given ID.
+    pub fn is_everyone(&self, guild_id: Snowflake) -> bool {
+        self.kind == ApplicationCommandPermissionType::Role && self.id == everyone_role_id(guild_id)
+    }
+
+    /// Returns whether the overwrite applies to every channel of the guild with the given ID.
+    pub fn is_all_channels(&self, guild_id: Snowflake) -> bool {
+        self.kind == ApplicationCommandPermissionType::Channel
+            && self.id == all_channels_id(guild_id)
+    }
+}
+
+/// Returns the ID of the `@everyone` role of a guild, which is the ID of the guild.
+pub const fn everyone_role_id(guild_id: Snowflake) -> Snowflake {
+    guild_id
+}
+
+/// Returns the ID standing for all channels of a guild in command permissions, which is the
+/// ID of the guild minus one.
+pub const fn all_channels_id(guild_id: Snowflake) -> Snowflake {
+    Snowflake::new(guild_id.get().saturating_sub(1))
+}
+
+/// Represents the permission overwrites of a command, or of all commands, in a guild.
+///
+/// # Fields
+/// - `id`: The ID of the command, or the application ID for app-wide permissions.
+/// - `application_id`: The ID of the application the command belongs to.
+/// - `guild_id`: The ID of the guild.
+/// - `permissions`: The permission overwrites.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct GuildApplicationCommandPermissions {
+    id: Snowflake,
+    application_id: Snowflake,
+    guild_id: Snowflake,
+    permissions: Vec<ApplicationCommandPermission>,
+}
+
+impl GuildApplicationCommandPermissions {
+    /// Returns the ID of the command, or the application ID for app-wide permissions.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the ID of the application the command belongs to.
+    pub fn application_id(&self) -> Snowflake {
+        self.application_id
+    }
+
+    /// Returns the ID of the guild.
+    pub fn guild_id(&self) -> Snowflake {
+        self.guild_id
+    }
+
+    /// Returns the permission overwrites.
+    pub fn permissions(&self) -> &[ApplicationCommandPermission] {
+        &self.permissions
+    }
+
+    /// Returns whether the overwrites apply to every command of the application.
+    pub fn is_application_wide(&self) -> bool {
+        self.id == self.application_id
+    }
+}
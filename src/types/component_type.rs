@@ -0,0 +1,76 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Represents the type of a message component.
+///
+/// - `ActionRow`: A container for other components (value 1).
+/// - `Button`: A button (value 2).
+/// - `StringSelect`: A select menu with developer-defined options (value 3).
+/// - `TextInput`: A text input, only allowed in modals (value 4).
+/// - `UserSelect`: A select menu of users (value 5).
+/// - `RoleSelect`: A select menu of roles (value 6).
+/// - `MentionableSelect`: A select menu of users and roles (value 7).
+/// - `ChannelSelect`: A select menu of channels (value 8).
+/// - `Section`: Text with an accessory, such as a thumbnail or button (value 9).
+/// - `TextDisplay`: Markdown text (value 10).
+/// - `Thumbnail`: A small image, used as the accessory of a section (value 11).
+/// - `MediaGallery`: A gallery of images and videos (value 12).
+/// - `File`: An attached file (value 13).
+/// - `Separator`: Vertical padding between components (value 14).
+/// - `Container`: A visually grouped set of components (value 17).
+/// - `Label`: A label wrapping a text input or select menu in a modal (value 18).
+/// - `FileUpload`: A file upload in a modal (value 19).
+///
+/// Components from `Section` onwards require the `IS_COMPONENTS_V2` message flag when used
+/// in messages.
+#[derive(Deserialize_repr, Serialize_repr, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ComponentType {
+    /// A container for other components.
+    ActionRow = 1,
+    /// A button.
+    Button = 2,
+    /// A select menu with developer-defined options.
+    StringSelect = 3,
+    /// A text input, only allowed in modals.
+    TextInput = 4,
+    /// A select menu of users.
+    UserSelect = 5,
+    /// A select menu of roles.
+    RoleSelect = 6,
+    /// A select menu of users and roles.
+    MentionableSelect = 7,
+    /// A select menu of channels.
+    ChannelSelect = 8,
+    /// Text with an accessory, such as a thumbnail or button.
+    Section = 9,
+    /// Markdown text.
+    TextDisplay = 10,
+    /// A small image, used as the accessory of a section.
+    Thumbnail = 11,
+    /// A gallery of images and videos.
+    MediaGallery = 12,
+    /// An attached file.
+    File = 13,
+    /// Vertical padding between components.
+    Separator = 14,
+    /// A visually grouped set of components.
+    Container = 17,
+    /// A label wrapping a text input or select menu in a modal.
+    Label = 18,
+    /// A file upload in a modal.
+    FileUpload = 19,
+}
+
+impl ComponentType {
+    /// Returns whether the component is a select menu.
+    pub fn is_select_menu(&self) -> bool {
+        matches!(
+            self,
+            ComponentType::StringSelect
+                | ComponentType::UserSelect
+                | ComponentType::RoleSelect
+                | ComponentType::MentionableSelect
+                | ComponentType::ChannelSelect
+        )
+    }
+}
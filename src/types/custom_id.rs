@@ -0,0 +1,664 @@
+use std::{error::Error, fmt, vec};
+
+use serde::{
+    de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor},
+    ser::{self, Impossible},
+    Deserializer, Serialize, Serializer,
+};
+
+/// The maximum length of the `custom_id` of a component or modal.
+pub const MAX_CUSTOM_ID_LENGTH: usize = 100;
+
+/// The character separating the segments of an encoded custom ID.
+const SEPARATOR: char = ':';
+
+/// Represents an error encountered while encoding or decoding a custom ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CustomIdError {
+    /// The encoded custom ID is longer than 100 characters.
+    TooLong { len: usize },
+    /// The custom ID does not start with the prefix of the type it is decoded as.
+    PrefixMismatch { expected: &'static str },
+    /// The value cannot be represented as a custom ID, or the custom ID is malformed.
+    Message(String),
+}
+
+impl fmt::Display for CustomIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CustomIdError::TooLong { len } => write!(
+                f,
+                "custom ID is {} characters long, but at most {} are allowed",
+                len, MAX_CUSTOM_ID_LENGTH
+            ),
+            CustomIdError::PrefixMismatch { expected } => {
+                write!(f, "custom ID does not start with {:?}", expected)
+            }
+            CustomIdError::Message(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl Error for CustomIdError {}
+
+impl ser::Error for CustomIdError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        CustomIdError::Message(msg.to_string())
+    }
+}
+
+impl de::Error for CustomIdError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        CustomIdError::Message(msg.to_string())
+    }
+}
+
+/// A codec for state stored in the `custom_id` of a component or modal.
+///
+/// Implementors are encoded as their `PREFIX` followed by the values of their fields, in
+/// declaration order, separated by `:`. For example, `PageState { page: 3, user: 123 }`
+/// with the prefix `"page"` is encoded as `page:3:123`. Field names are left out to save
+/// room in the 100 character budget, so reordering fields changes the encoding.
+///
+/// Structs, tuples, booleans, numbers, characters, strings, options, and enums whose
+/// variants are units or hold a single value are supported. `None` is encoded as an empty
+/// segment, so `Some(String::new())` decodes as `None`. Sequences and maps are not
+/// supported.
+///
+/// # Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use serde_discord::types::CustomId;
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct PageState {
+///     page: u32,
+///     user: u64,
+/// }
+///
+/// impl CustomId for PageState {
+///     const PREFIX: &'static str = "page";
+/// }
+///
+/// let custom_id = PageState { page: 3, user: 123 }.encode().unwrap();
+/// assert_eq!(custom_id, "page:3:123");
+/// assert_eq!(
+///     PageState::decode(&custom_id).unwrap(),
+///     PageState { page: 3, user: 123 }
+/// );
+/// ```
+pub trait CustomId: Serialize + DeserializeOwned {
+    /// The prefix identifying the type, used to route interactions to their handler.
+    ///
+    /// The prefix must not contain `:`.
+    const PREFIX: &'static str;
+
+    /// Encodes the value as a custom ID.
+    ///
+    /// # Errors
+    /// Returns an error if the value contains unsupported types, or if the encoded custom ID
+    /// is longer than 100 characters.
+    fn encode(&self) -> Result<String, CustomIdError> {
+        let mut encoder = Encoder {
+            output: Self::PREFIX.to_string(),
+        };
+        self.serialize(&mut encoder)?;
+
+        let len = encoder.output.chars().count();
+        if len > MAX_CUSTOM_ID_LENGTH {
+            return Err(CustomIdError::TooLong { len });
+        }
+        Ok(encoder.output)
+    }
+
+    /// Decodes a value from a custom ID.
+    ///
+    /// # Errors
+    /// Returns an error if the custom ID does not start with `PREFIX`, or if its segments do
+    /// not match the fields of the type.
+    fn decode(custom_id: &str) -> Result<Self, CustomIdError> {
+        let mut segments = custom_id.split(SEPARATOR);
+        if segments.next() != Some(Self::PREFIX) {
+            return Err(CustomIdError::PrefixMismatch {
+                expected: Self::PREFIX,
+            });
+        }
+
+        let mut decoder = Decoder {
+            segments: segments.map(unescape).collect::<Vec<_>>().into_iter(),
+        };
+        let value = Self::deserialize(&mut decoder)?;
+        if decoder.segments.next().is_some() {
+            return Err(de::Error::custom("custom ID has trailing segments"));
+        }
+        Ok(value)
+    }
+}
+
+/// Escapes the separator, and the escape character, in a string segment.
+fn escape(segment: &str) -> String {
+    segment.replace('%', "%25").replace(SEPARATOR, "%3A")
+}
+
+/// Reverses `escape`.
+fn unescape(segment: &str) -> String {
+    segment.replace("%3A", ":").replace("%25", "%")
+}
+
+/// Serializes values as the segments of a custom ID.
+struct Encoder {
+    output: String,
+}
+
+impl Encoder {
+    fn push(&mut self, segment: &str) {
+        self.output.push(SEPARATOR);
+        self.output.push_str(&escape(segment));
+    }
+
+    fn unsupported(kind: &str) -> CustomIdError {
+        CustomIdError::Message(format!("{} cannot be encoded in a custom ID", kind))
+    }
+}
+
+macro_rules! serialize_display {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<(), CustomIdError> {
+                self.push(&v.to_string());
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl Serializer for &mut Encoder {
+    type Ok = ();
+    type Error = CustomIdError;
+    type SerializeSeq = Impossible<(), CustomIdError>;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Impossible<(), CustomIdError>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    serialize_display! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), CustomIdError> {
+        Err(Encoder::unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<(), CustomIdError> {
+        self.push("");
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), CustomIdError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), CustomIdError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), CustomIdError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), CustomIdError> {
+        self.push(variant);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CustomIdError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), CustomIdError> {
+        self.push(variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, CustomIdError> {
+        Err(Encoder::unsupported("sequences"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self, CustomIdError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self, CustomIdError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self, CustomIdError> {
+        self.push(variant);
+        Ok(self)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, CustomIdError> {
+        Err(Encoder::unsupported("maps"))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self, CustomIdError> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self, CustomIdError> {
+        self.push(variant);
+        Ok(self)
+    }
+}
+
+macro_rules! serialize_fields {
+    ($($trait:ident::$method:ident($($key:ty)?)),*) => {
+        $(
+            impl ser::$trait for &mut Encoder {
+                type Ok = ();
+                type Error = CustomIdError;
+
+                fn $method<T: ?Sized + Serialize>(
+                    &mut self,
+                    $(_: $key,)?
+                    value: &T,
+                ) -> Result<(), CustomIdError> {
+                    value.serialize(&mut **self)
+                }
+
+                fn end(self) -> Result<(), CustomIdError> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+serialize_fields! {
+    SerializeTuple::serialize_element(),
+    SerializeTupleStruct::serialize_field(),
+    SerializeTupleVariant::serialize_field(),
+    SerializeStruct::serialize_field(&'static str),
+    SerializeStructVariant::serialize_field(&'static str)
+}
+
+/// Deserializes values from the segments of a custom ID.
+struct Decoder {
+    segments: vec::IntoIter<String>,
+}
+
+impl Decoder {
+    fn next_segment(&mut self) -> Result<String, CustomIdError> {
+        self.segments
+            .next()
+            .ok_or_else(|| de::Error::custom("custom ID has too few segments"))
+    }
+
+    fn parse<T: std::str::FromStr>(&mut self, kind: &str) -> Result<T, CustomIdError> {
+        let segment = self.next_segment()?;
+        segment
+            .parse()
+            .map_err(|_| de::Error::custom(format!("invalid {}: {:?}", kind, segment)))
+    }
+}
+
+macro_rules! deserialize_parse {
+    ($($method:ident => $visit:ident: $ty:ty),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CustomIdError> {
+                visitor.$visit(self.parse::<$ty>(stringify!($ty))?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for &mut Decoder {
+    type Error = CustomIdError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, CustomIdError> {
+        Err(de::Error::custom(
+            "custom IDs can only be decoded into types with a known layout",
+        ))
+    }
+
+    deserialize_parse! {
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+        deserialize_char => visit_char: char
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CustomIdError> {
+        visitor.visit_string(self.next_segment()?)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CustomIdError> {
+        visitor.visit_string(self.next_segment()?)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        visitor.visit_string(self.next_segment()?)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CustomIdError> {
+        match self.segments.as_slice().first() {
+            Some(segment) if segment.is_empty() => {
+                self.segments.next();
+                visitor.visit_none()
+            }
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CustomIdError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        visitor.visit_seq(Fields {
+            decoder: self,
+            remaining: len,
+        })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        self.next_segment()?;
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bytes byte_buf seq map
+    }
+}
+
+/// Gives access to the fields of a struct or tuple, in order.
+struct Fields<'a> {
+    decoder: &'a mut Decoder,
+    remaining: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for Fields<'_> {
+    type Error = CustomIdError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, CustomIdError> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.decoder).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> de::EnumAccess<'de> for &mut Decoder {
+    type Error = CustomIdError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self), CustomIdError> {
+        let variant = self.next_segment()?;
+        let value = seed.deserialize(variant.into_deserializer())?;
+        Ok((value, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for &mut Decoder {
+    type Error = CustomIdError;
+
+    fn unit_variant(self) -> Result<(), CustomIdError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, CustomIdError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        de::Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CustomIdError> {
+        de::Deserializer::deserialize_tuple(self, fields.len(), visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum Action {
+        Open,
+        Goto(u32),
+        Move(i8, i8),
+        Rename { from: String, to: String },
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct State {
+        label: String,
+        action: Action,
+        owner: Option<u64>,
+    }
+
+    impl CustomId for State {
+        const PREFIX: &'static str = "state";
+    }
+
+    fn round_trip(state: State) -> String {
+        let custom_id = state.encode().unwrap();
+        assert_eq!(State::decode(&custom_id).unwrap(), state);
+        custom_id
+    }
+
+    fn state(label: &str) -> State {
+        State {
+            label: label.to_string(),
+            action: Action::Open,
+            owner: None,
+        }
+    }
+
+    #[test]
+    fn separators_and_escape_characters_are_escaped() {
+        assert_eq!(round_trip(state("a:b")), "state:a%3Ab:Open:");
+        assert_eq!(round_trip(state("100%")), "state:100%25:Open:");
+        // Escaped text must not be unescaped twice.
+        assert_eq!(round_trip(state("%3A")), "state:%253A:Open:");
+        assert_eq!(round_trip(state("%:%25")), "state:%25%3A%2525:Open:");
+    }
+
+    #[test]
+    fn options_are_encoded_as_empty_segments_when_none() {
+        let owned = State {
+            owner: Some(123),
+            ..state("x")
+        };
+        assert_eq!(round_trip(owned), "state:x:Open:123");
+        assert_eq!(round_trip(state("x")), "state:x:Open:");
+    }
+
+    #[test]
+    fn enum_variants_are_encoded_by_name() {
+        let with = |action| State {
+            action,
+            ..state("x")
+        };
+        assert_eq!(round_trip(with(Action::Goto(4))), "state:x:Goto:4:");
+        assert_eq!(round_trip(with(Action::Move(-1, 2))), "state:x:Move:-1:2:");
+        let rename = Action::Rename {
+            from: "a".to_string(),
+            to: "b:c".to_string(),
+        };
+        assert_eq!(round_trip(with(rename)), "state:x:Rename:a:b%3Ac:");
+
+        assert!(matches!(
+            State::decode("state:x:Close:"),
+            Err(CustomIdError::Message(_))
+        ));
+    }
+
+    #[test]
+    fn custom_ids_are_limited_to_100_characters() {
+        // "state:" and ":Open:" take 12 characters.
+        let custom_id = round_trip(state(&"a".repeat(88)));
+        assert_eq!(custom_id.chars().count(), MAX_CUSTOM_ID_LENGTH);
+        assert_eq!(
+            state(&"a".repeat(89)).encode(),
+            Err(CustomIdError::TooLong { len: 101 })
+        );
+
+        // Multi-byte characters count once, escapes count in full.
+        let custom_id = round_trip(state(&"日".repeat(88)));
+        assert!(custom_id.len() > MAX_CUSTOM_ID_LENGTH);
+        assert_eq!(
+            state(&":".repeat(30)).encode(),
+            Err(CustomIdError::TooLong { len: 102 })
+        );
+    }
+
+    #[test]
+    fn trailing_and_missing_segments_are_rejected() {
+        assert_eq!(
+            State::decode("state:x:Open::extra"),
+            Err(CustomIdError::Message(
+                "custom ID has trailing segments".to_string()
+            ))
+        );
+        assert_eq!(
+            State::decode("state:x:Open"),
+            Err(CustomIdError::Message(
+                "custom ID has too few segments".to_string()
+            ))
+        );
+        assert_eq!(
+            State::decode("other:x:Open:"),
+            Err(CustomIdError::PrefixMismatch { expected: "state" })
+        );
+    }
+}
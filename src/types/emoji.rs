@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use super::Snowflake;
+
+/// Represents an emoji shown on a button, select menu option, poll answer, or reaction.
+///
+/// Unicode emojis are identified by their character and custom emojis by their ID. Emojis
+/// are serialized in Discord's partial emoji shape, `{"id": .., "name": .., "animated": ..}`,
+/// omitting the fields that are not set. The name of a custom emoji may be absent when it was
+/// deleted.
+///
+/// # Fields
+/// - `id`: The ID of a custom emoji.
+/// - `name`: The unicode character of the emoji, or the name of a custom emoji.
+/// - `animated`: Whether the custom emoji is animated.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Emoji {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    animated: Option<bool>,
+}
+
+impl Emoji {
+    /// Creates a unicode emoji, such as `"🎲"`.
+    pub fn unicode(emoji: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            name: Some(emoji.into()),
+            animated: None,
+        }
+    }
+
+    /// Creates a custom emoji from its ID and name.
+    pub fn custom(id: Snowflake, name: impl Into<String>) -> Self {
+        Self {
+            id: Some(id),
+            name: Some(name.into()),
+            animated: None,
+        }
+    }
+
+    /// Sets whether the custom emoji is animated.
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = Some(animated);
+        self
+    }
+
+    /// Returns the ID of a custom emoji. Unicode emojis have no ID.
+    pub fn id(&self) -> Option<Snowflake> {
+        self.id
+    }
+
+    /// Returns the unicode character of the emoji, or the name of a custom emoji.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns whether the custom emoji is animated.
+    pub fn is_animated(&self) -> bool {
+        self.animated.unwrap_or(false)
+    }
+
+    /// Returns whether the emoji is a custom emoji rather than a unicode emoji.
+    pub fn is_custom(&self) -> bool {
+        self.id.is_some()
+    }
+}
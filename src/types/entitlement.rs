@@ -0,0 +1,119 @@
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+use super::{timestamp::parse_timestamp, Snowflake};
+
+/// Represents an entitlement, granting a user or guild access to a premium offering.
+///
+/// # Fields
+/// - `id`: The ID of the entitlement.
+/// - `sku_id`: The ID of the SKU this entitlement grants access to.
+/// - `application_id`: The ID of the parent application.
+/// - `user_id`: The ID of the user that is granted access, if any.
+/// - `guild_id`: The ID of the guild that is granted access, if any.
+/// - `kind`: The numeric type of the entitlement (e.g. purchase, subscription, test mode).
+/// - `deleted`: Whether the entitlement was deleted.
+/// - `starts_at`: Start date of the entitlement, as an ISO8601 timestamp.
+/// - `ends_at`: Date the entitlement expires, as an ISO8601 timestamp.
+/// - `consumed`: For consumable items, whether the entitlement has been consumed.
+#[derive(Deserialize, Debug)]
+#[non_exhaustive]
+pub struct Entitlement {
+    id: Snowflake,
+    sku_id: Snowflake,
+    application_id: Snowflake,
+    #[serde(default)]
+    user_id: Option<Snowflake>,
+    #[serde(default)]
+    guild_id: Option<Snowflake>,
+    #[serde(rename = "type")]
+    kind: u8,
+    deleted: bool,
+    starts_at: Option<String>,
+    ends_at: Option<String>,
+    consumed: Option<bool>,
+}
+
+impl Entitlement {
+    /// Returns the ID of the entitlement.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the ID of the SKU this entitlement grants access to.
+    pub fn sku_id(&self) -> Snowflake {
+        self.sku_id
+    }
+
+    /// Returns the ID of the parent application.
+    pub fn application_id(&self) -> Snowflake {
+        self.application_id
+    }
+
+    /// Returns the ID of the user that is granted access, if any.
+    pub fn user_id(&self) -> Option<Snowflake> {
+        self.user_id
+    }
+
+    /// Returns the ID of the guild that is granted access, if any.
+    pub fn guild_id(&self) -> Option<Snowflake> {
+        self.guild_id
+    }
+
+    /// Returns the numeric type of the entitlement.
+    pub fn kind(&self) -> u8 {
+        self.kind
+    }
+
+    /// Returns whether the entitlement was deleted.
+    pub fn deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// Returns the start date of the entitlement, if any.
+    pub fn starts_at(&self) -> Option<&str> {
+        self.starts_at.as_deref()
+    }
+
+    /// Returns the date the entitlement expires, if any.
+    pub fn ends_at(&self) -> Option<&str> {
+        self.ends_at.as_deref()
+    }
+
+    /// Returns whether a consumable entitlement has been consumed.
+    pub fn consumed(&self) -> bool {
+        self.consumed.unwrap_or(false)
+    }
+
+    /// Returns whether the entitlement currently grants access to its SKU.
+    ///
+    /// See [`Entitlement::is_active_at`].
+    pub fn is_active(&self) -> bool {
+        self.is_active_at(SystemTime::now())
+    }
+
+    /// Returns whether the entitlement grants access to its SKU at the given time.
+    ///
+    /// An entitlement is active if it is not deleted, not consumed, has started, and has
+    /// not yet expired. Entitlements without `starts_at`/`ends_at` are not time-limited.
+    /// If either date cannot be parsed, the entitlement is treated as inactive.
+    pub fn is_active_at(&self, now: SystemTime) -> bool {
+        if self.deleted || self.consumed() {
+            return false;
+        }
+        if let Some(starts_at) = &self.starts_at {
+            match parse_timestamp(starts_at) {
+                Some(starts_at) if starts_at <= now => (),
+                _ => return false,
+            }
+        }
+        if let Some(ends_at) = &self.ends_at {
+            match parse_timestamp(ends_at) {
+                Some(ends_at) if now < ends_at => (),
+                _ => return false,
+            }
+        }
+        true
+    }
+}
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+use super::Snowflake;
+
+/// Represents the partial guild object included on interactions invoked inside a guild.
+///
+/// # Fields
+/// - `id`: The ID of the guild.
+/// - `locale`: The preferred locale of the guild, e.g. `"en-US"`.
+/// - `features`: The enabled guild features, such as `"COMMUNITY"` or `"DISCOVERABLE"`.
+#[derive(Deserialize, Debug)]
+#[non_exhaustive]
+pub struct PartialGuild {
+    id: Snowflake,
+    locale: String,
+    #[serde(default)]
+    features: Vec<String>,
+}
+
+impl PartialGuild {
+    /// Returns the ID of the guild.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the preferred locale of the guild.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Returns the enabled guild features.
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
+    /// Returns whether the given guild feature, such as `"COMMUNITY"`, is enabled.
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+}
@@ -0,0 +1,14 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Represents where an application can be installed.
+///
+/// - `GuildInstall`: The app is installable to guilds (value 0).
+/// - `UserInstall`: The app is installable to users (value 1).
+#[derive(Deserialize_repr, Serialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ApplicationIntegrationType {
+    /// App is installable to guilds.
+    GuildInstall = 0,
+    /// App is installable to users.
+    UserInstall = 1,
+}
@@ -0,0 +1,18 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Represents the context in which an interaction can be used or was triggered from.
+///
+/// - `Guild`: The interaction happened inside a guild (value 0).
+/// - `BotDm`: The interaction happened inside a DM with the app's bot user (value 1).
+/// - `PrivateChannel`: The interaction happened inside a group DM or a DM other than the
+///   app's bot user (value 2).
+#[derive(Deserialize_repr, Serialize_repr, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum InteractionContextType {
+    /// Interaction inside a guild.
+    Guild = 0,
+    /// Interaction inside a DM with the app's bot user.
+    BotDm = 1,
+    /// Interaction inside a group DM or a DM other than the app's bot user.
+    PrivateChannel = 2,
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A map of locales to localized strings, used for command names, descriptions, and choices.
+///
+/// A `BTreeMap` is used so that localizations are always serialized in the same order.
+pub type LocalizationMap = BTreeMap<Locale, String>;
+
+/// Represents a locale supported by Discord.
+///
+/// Each variant is (de)serialized as the locale code Discord expects, e.g. `Locale::EnglishUs`
+/// is serialized as `"en-US"`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Locale {
+    /// Indonesian (`id`).
+    #[serde(rename = "id")]
+    Indonesian,
+    /// Danish (`da`).
+    #[serde(rename = "da")]
+    Danish,
+    /// German (`de`).
+    #[serde(rename = "de")]
+    German,
+    /// English, UK (`en-GB`).
+    #[serde(rename = "en-GB")]
+    EnglishUk,
+    /// English, US (`en-US`).
+    #[serde(rename = "en-US")]
+    EnglishUs,
+    /// Spanish (`es-ES`).
+    #[serde(rename = "es-ES")]
+    Spanish,
+    /// Spanish, LATAM (`es-419`).
+    #[serde(rename = "es-419")]
+    SpanishLatam,
+    /// French (`fr`).
+    #[serde(rename = "fr")]
+    French,
+    /// Croatian (`hr`).
+    #[serde(rename = "hr")]
+    Croatian,
+    /// Italian (`it`).
+    #[serde(rename = "it")]
+    Italian,
+    /// Lithuanian (`lt`).
+    #[serde(rename = "lt")]
+    Lithuanian,
+    /// Hungarian (`hu`).
+    #[serde(rename = "hu")]
+    Hungarian,
+    /// Dutch (`nl`).
+    #[serde(rename = "nl")]
+    Dutch,
+    /// Norwegian (`no`).
+    #[serde(rename = "no")]
+    Norwegian,
+    /// Polish (`pl`).
+    #[serde(rename = "pl")]
+    Polish,
+    /// Portuguese, Brazilian (`pt-BR`).
+    #[serde(rename = "pt-BR")]
+    PortugueseBrazilian,
+    /// Romanian (`ro`).
+    #[serde(rename = "ro")]
+    Romanian,
+    /// Finnish (`fi`).
+    #[serde(rename = "fi")]
+    Finnish,
+    /// Swedish (`sv-SE`).
+    #[serde(rename = "sv-SE")]
+    Swedish,
+    /// Vietnamese (`vi`).
+    #[serde(rename = "vi")]
+    Vietnamese,
+    /// Turkish (`tr`).
+    #[serde(rename = "tr")]
+    Turkish,
+    /// Czech (`cs`).
+    #[serde(rename = "cs")]
+    Czech,
+    /// Greek (`el`).
+    #[serde(rename = "el")]
+    Greek,
+    /// Bulgarian (`bg`).
+    #[serde(rename = "bg")]
+    Bulgarian,
+    /// Russian (`ru`).
+    #[serde(rename = "ru")]
+    Russian,
+    /// Ukrainian (`uk`).
+    #[serde(rename = "uk")]
+    Ukrainian,
+    /// Hindi (`hi`).
+    #[serde(rename = "hi")]
+    Hindi,
+    /// Thai (`th`).
+    #[serde(rename = "th")]
+    Thai,
+    /// Chinese, China (`zh-CN`).
+    #[serde(rename = "zh-CN")]
+    ChineseChina,
+    /// Japanese (`ja`).
+    #[serde(rename = "ja")]
+    Japanese,
+    /// Chinese, Taiwan (`zh-TW`).
+    #[serde(rename = "zh-TW")]
+    ChineseTaiwan,
+    /// Korean (`ko`).
+    #[serde(rename = "ko")]
+    Korean,
+}
+
+impl Locale {
+    /// Returns the locale code of the locale, e.g. `"en-US"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::Indonesian => "id",
+            Locale::Danish => "da",
+            Locale::German => "de",
+            Locale::EnglishUk => "en-GB",
+            Locale::EnglishUs => "en-US",
+            Locale::Spanish => "es-ES",
+            Locale::SpanishLatam => "es-419",
+            Locale::French => "fr",
+            Locale::Croatian => "hr",
+            Locale::Italian => "it",
+            Locale::Lithuanian => "lt",
+            Locale::Hungarian => "hu",
+            Locale::Dutch => "nl",
+            Locale::Norwegian => "no",
+            Locale::Polish => "pl",
+            Locale::PortugueseBrazilian => "pt-BR",
+            Locale::Romanian => "ro",
+            Locale::Finnish => "fi",
+            Locale::Swedish => "sv-SE",
+            Locale::Vietnamese => "vi",
+            Locale::Turkish => "tr",
+            Locale::Czech => "cs",
+            Locale::Greek => "el",
+            Locale::Bulgarian => "bg",
+            Locale::Russian => "ru",
+            Locale::Ukrainian => "uk",
+            Locale::Hindi => "hi",
+            Locale::Thai => "th",
+            Locale::ChineseChina => "zh-CN",
+            Locale::Japanese => "ja",
+            Locale::ChineseTaiwan => "zh-TW",
+            Locale::Korean => "ko",
+        }
+    }
+}
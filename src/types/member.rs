@@ -0,0 +1,51 @@
+use serde::Deserialize;
+
+use super::{Permissions, Snowflake, User};
+
+/// Represents a member of a guild.
+///
+/// Discord includes this object on interactions invoked inside a guild. The `user` field
+/// is always present in that case.
+///
+/// # Fields
+/// - `user`: The user this guild member represents.
+/// - `nick`: The member's guild nickname, if set.
+/// - `roles`: The IDs of the roles assigned to the member.
+/// - `joined_at`: When the user joined the guild, as an ISO8601 timestamp.
+/// - `permissions`: The member's total permissions in the channel.
+#[derive(Deserialize, Debug)]
+#[non_exhaustive]
+pub struct Member {
+    user: Option<User>,
+    nick: Option<String>,
+    roles: Vec<Snowflake>,
+    joined_at: Option<String>,
+    permissions: Option<Permissions>,
+}
+
+impl Member {
+    /// Returns the user this guild member represents.
+    pub fn user(&self) -> Option<&User> {
+        self.user.as_ref()
+    }
+
+    /// Returns the member's guild nickname, if set.
+    pub fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    /// Returns the IDs of the roles assigned to the member.
+    pub fn roles(&self) -> &[Snowflake] {
+        &self.roles
+    }
+
+    /// Returns when the user joined the guild, as an ISO8601 timestamp.
+    pub fn joined_at(&self) -> Option<&str> {
+        self.joined_at.as_deref()
+    }
+
+    /// Returns the member's total permissions in the channel, including overwrites.
+    pub fn permissions(&self) -> Option<Permissions> {
+        self.permissions
+    }
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Snowflake, User};
+
+bitflags::bitflags! {
+    /// Represents the flags of a message, such as whether it is ephemeral.
+    ///
+    /// Flags are transmitted as an integer. Unknown bits are kept, so flags parsed from
+    /// Discord are serialized back unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_discord::types::MessageFlags;
+    ///
+    /// let flags: MessageFlags = serde_json::from_str("65600").unwrap();
+    /// assert!(flags.contains(MessageFlags::EPHEMERAL));
+    /// assert_eq!(flags, MessageFlags::from_bits_retain(65600));
+    /// assert_eq!(serde_json::to_string(&flags).unwrap(), "65600");
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct MessageFlags: u32 {
+        const CROSSPOSTED                      = 1 << 0;
+        const IS_CROSSPOST                     = 1 << 1;
+        const SUPPRESS_EMBEDS                  = 1 << 2;
+        const SOURCE_MESSAGE_DELETED           = 1 << 3;
+        const URGENT                           = 1 << 4;
+        const HAS_THREAD                       = 1 << 5;
+        const EPHEMERAL                        = 1 << 6;
+        const LOADING                          = 1 << 7;
+        const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
+        const SUPPRESS_NOTIFICATIONS           = 1 << 12;
+        const IS_VOICE_MESSAGE                 = 1 << 13;
+        const IS_COMPONENTS_V2                 = 1 << 15;
+    }
+}
+
+impl Serialize for MessageFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u32(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageFlags {
+    /// Deserializes the flags from their integer representation, keeping unknown bits.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        u32::deserialize(deserializer).map(MessageFlags::from_bits_retain)
+    }
+}
+
+/// Represents a message received from Discord, such as the message a component belongs to
+/// or the target of a message command.
+///
+/// Embeds and components are kept as raw JSON, since their types are part of the `response`
+/// module. They can be deserialized into `response::data::Embed` and
+/// `response::data::MessageComponent` with `serde_json::from_value`.
+///
+/// # Fields
+/// - `id`: The ID of the message.
+/// - `channel_id`: The ID of the channel the message was sent in.
+/// - `author`: The author of the message.
+/// - `content`: The text of the message.
+/// - `embeds`: The embeds of the message, as raw JSON.
+/// - `components`: The components of the message, as raw JSON.
+/// - `flags`: The flags of the message.
+/// - `timestamp`: When the message was sent, as an ISO8601 timestamp.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Message {
+    id: Snowflake,
+    channel_id: Snowflake,
+    author: User,
+    #[serde(default)]
+    content: String,
+    #[serde(default)]
+    embeds: Vec<Value>,
+    #[serde(default)]
+    components: Vec<Value>,
+    #[serde(default = "MessageFlags::empty")]
+    flags: MessageFlags,
+    timestamp: String,
+}
+
+impl Message {
+    /// Returns the ID of the message.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the ID of the channel the message was sent in.
+    pub fn channel_id(&self) -> Snowflake {
+        self.channel_id
+    }
+
+    /// Returns the author of the message.
+    pub fn author(&self) -> &User {
+        &self.author
+    }
+
+    /// Returns the text of the message. Empty if the message has no text.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Returns the embeds of the message, as raw JSON.
+    pub fn embeds(&self) -> &[Value] {
+        &self.embeds
+    }
+
+    /// Returns the components of the message, as raw JSON.
+    pub fn components(&self) -> &[Value] {
+        &self.components
+    }
+
+    /// Returns the flags of the message.
+    pub fn flags(&self) -> MessageFlags {
+        self.flags
+    }
+
+    /// Returns when the message was sent, as an ISO8601 timestamp.
+    pub fn timestamp(&self) -> &str {
+        &self.timestamp
+    }
+}
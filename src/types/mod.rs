@@ -1,12 +1,49 @@
 /// Contains types used in command interactions.
 ///
 /// This module defines the data structures needed for command processing.
+mod allowed_mentions;
+mod attachment;
+mod channel;
+mod color;
 mod command;
 mod command_option;
+mod command_permission;
+mod component_type;
+mod custom_id;
+mod emoji;
+mod entitlement;
+mod guild;
+mod integration_type;
+mod interaction_context;
+mod locale;
+mod member;
+mod message;
 mod multi_type_value;
+mod permissions;
+mod role;
 mod snowflake;
+mod timestamp;
+mod user;
 
+pub use allowed_mentions::*;
+pub use attachment::*;
+pub use channel::*;
+pub use color::*;
 pub use command::*;
 pub use command_option::*;
+pub use command_permission::*;
+pub use component_type::*;
+pub use custom_id::*;
+pub use emoji::*;
+pub use entitlement::*;
+pub use guild::*;
+pub use integration_type::*;
+pub use interaction_context::*;
+pub use locale::*;
+pub use member::*;
+pub use message::*;
 pub use multi_type_value::*;
+pub use permissions::*;
+pub use role::*;
 pub use snowflake::*;
+pub use user::*;
@@ -1,4 +1,8 @@
-use serde::{de, Deserialize};
+use std::{error::Error, fmt};
+
+use serde::{de, Deserialize, Serialize, Serializer};
+
+use super::Snowflake;
 
 /// Represents a value that can be one of multiple types.
 ///
@@ -7,7 +11,26 @@ use serde::{de, Deserialize};
 /// - `Integer`: A signed integer value.
 /// - `Double`: A floating-point number.
 /// - `Boolean`: A boolean value (true or false).
-#[derive(Debug)]
+///
+/// The value can be read with accessors such as [`as_str`](Self::as_str), or converted
+/// with `TryFrom`, which reports the type that was found instead.
+///
+/// # Example
+///
+/// ```
+/// use serde_discord::types::{MultiTypeValue, Snowflake};
+///
+/// let value: MultiTypeValue = serde_json::from_str("\"175928847299117063\"").unwrap();
+/// assert_eq!(value.as_snowflake(), Some(Snowflake::new(175928847299117063)));
+/// assert_eq!(value.as_i64(), None);
+///
+/// let err = i64::try_from(&value).unwrap_err();
+/// assert_eq!(err.to_string(), "expected integer, found string");
+///
+/// // Values are serialized as plain JSON values, without a tag.
+/// assert_eq!(serde_json::to_string(&MultiTypeValue::Integer(3)).unwrap(), "3");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
 pub enum MultiTypeValue {
     /// A string value.
     String(String),
@@ -19,6 +42,163 @@ pub enum MultiTypeValue {
     Boolean(bool),
 }
 
+impl MultiTypeValue {
+    /// Returns the string value, or `None` if the value is not a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MultiTypeValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer value, or `None` if the value is not an integer.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            MultiTypeValue::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the numeric value, or `None` if the value is not a number.
+    ///
+    /// Integers are converted, since Discord sends whole numbers without a fractional part.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            MultiTypeValue::Double(value) => Some(*value),
+            MultiTypeValue::Integer(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean value, or `None` if the value is not a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            MultiTypeValue::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the ID held by the value, such as the value of a user, channel, role, or
+    /// attachment option, or `None` if the value is not a valid snowflake.
+    pub fn as_snowflake(&self) -> Option<Snowflake> {
+        match self {
+            MultiTypeValue::String(value) => value.parse().ok(),
+            MultiTypeValue::Integer(value) => u64::try_from(*value).ok().map(Snowflake::new),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the type of the value, used in error messages.
+    fn type_name(&self) -> &'static str {
+        match self {
+            MultiTypeValue::String(_) => "string",
+            MultiTypeValue::Integer(_) => "integer",
+            MultiTypeValue::Double(_) => "double",
+            MultiTypeValue::Boolean(_) => "boolean",
+        }
+    }
+
+    /// Returns an error for a conversion of the value to `expected`.
+    fn mismatch(&self, expected: &'static str) -> MultiTypeValueError {
+        MultiTypeValueError {
+            expected,
+            found: self.type_name(),
+        }
+    }
+}
+
+/// Represents an error encountered while converting a `MultiTypeValue` to a primitive type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiTypeValueError {
+    expected: &'static str,
+    found: &'static str,
+}
+
+impl MultiTypeValueError {
+    /// Returns the name of the type the value was converted to.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Returns the name of the type of the value.
+    pub fn found(&self) -> &'static str {
+        self.found
+    }
+}
+
+impl fmt::Display for MultiTypeValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {}, found {}", self.expected, self.found)
+    }
+}
+
+impl Error for MultiTypeValueError {}
+
+impl<'a> TryFrom<&'a MultiTypeValue> for &'a str {
+    type Error = MultiTypeValueError;
+
+    fn try_from(value: &'a MultiTypeValue) -> Result<Self, Self::Error> {
+        value.as_str().ok_or_else(|| value.mismatch("string"))
+    }
+}
+
+impl TryFrom<&MultiTypeValue> for String {
+    type Error = MultiTypeValueError;
+
+    fn try_from(value: &MultiTypeValue) -> Result<Self, Self::Error> {
+        <&str>::try_from(value).map(str::to_owned)
+    }
+}
+
+impl TryFrom<&MultiTypeValue> for i64 {
+    type Error = MultiTypeValueError;
+
+    fn try_from(value: &MultiTypeValue) -> Result<Self, Self::Error> {
+        value.as_i64().ok_or_else(|| value.mismatch("integer"))
+    }
+}
+
+impl TryFrom<&MultiTypeValue> for f64 {
+    type Error = MultiTypeValueError;
+
+    fn try_from(value: &MultiTypeValue) -> Result<Self, Self::Error> {
+        value.as_f64().ok_or_else(|| value.mismatch("number"))
+    }
+}
+
+impl TryFrom<&MultiTypeValue> for bool {
+    type Error = MultiTypeValueError;
+
+    fn try_from(value: &MultiTypeValue) -> Result<Self, Self::Error> {
+        value.as_bool().ok_or_else(|| value.mismatch("boolean"))
+    }
+}
+
+impl TryFrom<&MultiTypeValue> for Snowflake {
+    type Error = MultiTypeValueError;
+
+    fn try_from(value: &MultiTypeValue) -> Result<Self, Self::Error> {
+        value
+            .as_snowflake()
+            .ok_or_else(|| value.mismatch("snowflake"))
+    }
+}
+
+impl Serialize for MultiTypeValue {
+    /// Serializes the value as the plain JSON value Discord sends, without a tag.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MultiTypeValue::String(value) => serializer.serialize_str(value),
+            MultiTypeValue::Integer(value) => serializer.serialize_i64(*value),
+            MultiTypeValue::Double(value) => serializer.serialize_f64(*value),
+            MultiTypeValue::Boolean(value) => serializer.serialize_bool(*value),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for MultiTypeValue {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -0,0 +1,94 @@
+use serde::Deserialize;
+
+use super::{Color, Permissions, Snowflake};
+
+/// Represents a role of a guild.
+///
+/// Discord sends roles as resolved data of role and mentionable options and selects.
+///
+/// # Fields
+/// - `id`: The role's unique ID.
+/// - `name`: The name of the role.
+/// - `color`: The color of the role, black if the role has no color.
+/// - `hoist`: Whether members with the role are displayed separately in the member list.
+/// - `icon`: The role's icon hash, if set.
+/// - `unicode_emoji`: The role's unicode emoji, if set.
+/// - `position`: The position of the role in the role hierarchy.
+/// - `permissions`: The permissions granted by the role.
+/// - `managed`: Whether the role is managed by an integration, such as a bot.
+/// - `mentionable`: Whether the role can be mentioned by anyone.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Role {
+    id: Snowflake,
+    name: String,
+    #[serde(default)]
+    color: Color,
+    #[serde(default)]
+    hoist: bool,
+    icon: Option<String>,
+    unicode_emoji: Option<String>,
+    position: i32,
+    permissions: Permissions,
+    #[serde(default)]
+    managed: bool,
+    #[serde(default)]
+    mentionable: bool,
+}
+
+impl Role {
+    /// Returns the role's unique ID.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the name of the role.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the color of the role. Roles without a color have the color `0`.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns whether members with the role are displayed separately in the member list.
+    pub fn hoist(&self) -> bool {
+        self.hoist
+    }
+
+    /// Returns the role's icon hash, if set.
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// Returns the role's unicode emoji, if set.
+    pub fn unicode_emoji(&self) -> Option<&str> {
+        self.unicode_emoji.as_deref()
+    }
+
+    /// Returns the position of the role in the role hierarchy.
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    /// Returns the permissions granted by the role.
+    pub fn permissions(&self) -> Permissions {
+        self.permissions
+    }
+
+    /// Returns whether the role is managed by an integration, such as a bot.
+    pub fn managed(&self) -> bool {
+        self.managed
+    }
+
+    /// Returns whether the role can be mentioned by anyone.
+    pub fn mentionable(&self) -> bool {
+        self.mentionable
+    }
+
+    /// Returns the markdown that renders as a mention of the role, such as `<@&123>`.
+    pub fn mention(&self) -> String {
+        format!("<@&{}>", self.id)
+    }
+}
@@ -1,11 +1,105 @@
-/// A unique identifier represented as a 64-bit unsigned integer.
+use std::{
+    fmt,
+    num::ParseIntError,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The Discord epoch, the first millisecond of 2015, in milliseconds since the Unix epoch.
+const DISCORD_EPOCH_MS: u64 = 1_420_070_400_000;
+
+/// A unique identifier of a Discord entity, such as a user, message, or channel.
 ///
-/// This type alias is commonly used in various applications, such as
-/// Discord, to represent unique entities like users, messages, or channels.
-/// The underlying type is a `u64`, which allows for a large number of unique
-/// identifiers.
+/// Discord transmits snowflakes as strings to avoid precision loss in JavaScript clients,
+/// so snowflakes are serialized as strings and deserialized from either strings or
+/// numbers.
 ///
-/// # TODO
-/// - Implement validation or utility functions to manage operations related
-///   to Snowflakes (e.g., converting to/from strings).
-pub type Snowflake = u64;
+/// # Example
+///
+/// ```
+/// use serde_discord::types::Snowflake;
+///
+/// let id: Snowflake = "175928847299117063".parse().unwrap();
+/// assert_eq!(id, Snowflake::new(175928847299117063));
+/// assert_eq!(id.to_string(), "175928847299117063");
+/// assert_eq!(serde_json::to_string(&id).unwrap(), "\"175928847299117063\"");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Snowflake(u64);
+
+impl Snowflake {
+    /// Creates a snowflake from its integer value.
+    pub const fn new(value: u64) -> Self {
+        Snowflake(value)
+    }
+
+    /// Returns the integer value of the snowflake.
+    pub const fn get(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns when the snowflake was created, encoded in its upper 42 bits as milliseconds
+    /// since the Discord epoch.
+    pub fn timestamp(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis((self.0 >> 22) + DISCORD_EPOCH_MS)
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(value: u64) -> Self {
+        Snowflake(value)
+    }
+}
+
+impl From<Snowflake> for u64 {
+    fn from(snowflake: Snowflake) -> Self {
+        snowflake.0
+    }
+}
+
+impl fmt::Display for Snowflake {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for Snowflake {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Snowflake)
+    }
+}
+
+impl Serialize for Snowflake {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Snowflake {
+    /// Deserializes a snowflake from either its string or its numeric representation.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawSnowflake {
+            String(String),
+            Number(u64),
+        }
+
+        match RawSnowflake::deserialize(deserializer)? {
+            RawSnowflake::String(s) => s
+                .parse()
+                .map_err(|_| de::Error::custom(format!("invalid snowflake: {:?}", s))),
+            RawSnowflake::Number(n) => Ok(Snowflake(n)),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Parses an ISO8601 timestamp as sent by Discord into a `SystemTime`.
+///
+/// Discord timestamps look like `2024-01-01T12:30:00.000000+00:00`. The fractional seconds
+/// are optional and the offset may be either `Z` or `±HH:MM`.
+///
+/// Returns `None` if the timestamp is malformed or precedes the Unix epoch.
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+    let bytes = timestamp.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || bytes[10] != b'T' {
+        return None;
+    }
+    if bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+
+    let num = |range: std::ops::Range<usize>| -> Option<i64> {
+        let s = timestamp.get(range)?;
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    };
+    let year = num(0..4)?;
+    let month = num(5..7)?;
+    let day = num(8..10)?;
+    let hour = num(11..13)?;
+    let minute = num(14..16)?;
+    let second = num(17..19)?;
+    if !(1..=12).contains(&month) || hour > 23 || minute > 59 {
+        return None;
+    }
+    if day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    if second > 60 {
+        return None;
+    }
+
+    let mut rest = &timestamp[19..];
+    let mut nanos = 0u32;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return None;
+        }
+        for (i, b) in fraction.bytes().take(digits.min(9)).enumerate() {
+            nanos += u32::from(b - b'0') * 10u32.pow(8 - i as u32);
+        }
+        rest = &fraction[digits..];
+    }
+
+    let offset_secs = match rest {
+        "Z" => 0,
+        _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let digits = |range: std::ops::Range<usize>| -> Option<i64> {
+                let s = &rest.as_bytes()[range];
+                s.iter().try_fold(0, |n, b| {
+                    b.is_ascii_digit().then(|| n * 10 + i64::from(b - b'0'))
+                })
+            };
+            let hours = digits(1..3)?;
+            let minutes = digits(4..6)?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+        _ => return None,
+    };
+
+    let secs = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second
+        - offset_secs;
+    let secs = u64::try_from(secs).ok()?;
+    Some(UNIX_EPOCH + Duration::new(secs, nanos))
+}
+
+/// Returns the number of days in the given month of the proleptic Gregorian calendar.
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days between the Unix epoch and the given date in the
+/// proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(timestamp: &str) -> Option<u64> {
+        parse_timestamp(timestamp).map(|time| time.duration_since(UNIX_EPOCH).unwrap().as_secs())
+    }
+
+    #[test]
+    fn timestamps_shorter_than_20_bytes_are_rejected() {
+        assert_eq!(secs("2024-01-01T12:30:00Z"), Some(1704112200));
+        assert_eq!(secs("2024-01-01T12:30:00"), None);
+        assert_eq!(secs(""), None);
+        // Multi-byte characters must not cause slicing inside a character.
+        assert_eq!(secs("2024-01-01T12:30:0日Z"), None);
+        assert_eq!(secs("2024-01-01T12:30:00+日:0"), None);
+    }
+
+    #[test]
+    fn fractional_seconds_are_parsed_to_nanoseconds() {
+        let nanos = |timestamp| {
+            parse_timestamp(timestamp)
+                .unwrap()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .subsec_nanos()
+        };
+        assert_eq!(nanos("2024-01-01T12:30:00.5Z"), 500_000_000);
+        assert_eq!(nanos("2024-01-01T12:30:00.123456+00:00"), 123_456_000);
+        // Digits beyond nanosecond precision are ignored.
+        assert_eq!(nanos("2024-01-01T12:30:00.1234567891Z"), 123_456_789);
+        assert_eq!(secs("2024-01-01T12:30:00.Z"), None);
+    }
+
+    #[test]
+    fn offsets_are_applied() {
+        let utc = secs("2024-01-01T12:30:00Z");
+        assert_eq!(secs("2024-01-01T12:30:00+00:00"), utc);
+        assert_eq!(secs("2024-01-01T14:00:00+01:30"), utc);
+        assert_eq!(secs("2024-01-01T07:30:00-05:00"), utc);
+        assert_eq!(secs("2024-01-01T12:30:00+0000"), None);
+        assert_eq!(secs("2024-01-01T12:30:00+-1:00"), None);
+        assert_eq!(secs("2024-01-01T12:30:00z"), None);
+    }
+
+    #[test]
+    fn invalid_dates_are_rejected() {
+        assert!(secs("2024-02-29T00:00:00Z").is_some());
+        assert_eq!(secs("2023-02-29T00:00:00Z"), None);
+        assert_eq!(secs("2024-04-31T00:00:00Z"), None);
+        assert_eq!(secs("2024-13-01T00:00:00Z"), None);
+        assert_eq!(secs("2024-00-01T00:00:00Z"), None);
+        assert_eq!(secs("2024-01-00T00:00:00Z"), None);
+        assert_eq!(secs("2024-01-01T24:00:00Z"), None);
+        assert_eq!(secs("2024-01-01 12:30:00Z"), None);
+        assert_eq!(secs("1969-12-31T23:59:59Z"), None);
+    }
+}
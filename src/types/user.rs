@@ -0,0 +1,55 @@
+use serde::Deserialize;
+
+use super::Snowflake;
+
+/// Represents a Discord user.
+///
+/// # Fields
+/// - `id`: The user's unique ID.
+/// - `username`: The user's username, not unique across the platform.
+/// - `discriminator`: The user's Discord tag, `"0"` for users on the new username system.
+/// - `global_name`: The user's display name, if set.
+/// - `avatar`: The user's avatar hash, if set.
+/// - `bot`: Whether the user belongs to an OAuth2 application.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct User {
+    id: Snowflake,
+    username: String,
+    discriminator: String,
+    global_name: Option<String>,
+    avatar: Option<String>,
+    bot: Option<bool>,
+}
+
+impl User {
+    /// Returns the user's unique ID.
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    /// Returns the user's username.
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// Returns the user's Discord tag.
+    pub fn discriminator(&self) -> &str {
+        &self.discriminator
+    }
+
+    /// Returns the user's display name, if set.
+    pub fn global_name(&self) -> Option<&str> {
+        self.global_name.as_deref()
+    }
+
+    /// Returns the user's avatar hash, if set.
+    pub fn avatar(&self) -> Option<&str> {
+        self.avatar.as_deref()
+    }
+
+    /// Returns whether the user is a bot.
+    pub fn bot(&self) -> bool {
+        self.bot.unwrap_or(false)
+    }
+}
@@ -0,0 +1,184 @@
+//! Verification of the signatures of incoming interactions.
+//!
+//! Discord signs every interaction it sends to an HTTP endpoint with the application's
+//! Ed25519 key. The signature is sent in the `X-Signature-Ed25519` header and covers the
+//! `X-Signature-Timestamp` header followed by the raw body. Requests must be verified
+//! before their body is parsed, and rejected with `401 Unauthorized` if the signature is
+//! invalid. Discord periodically sends requests with invalid signatures to check this.
+//!
+//! # Example
+//!
+//! ```
+//! use serde_discord::verify::{VerifyingKey, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+//!
+//! /// Returns the status code to respond to a request with.
+//! fn handle<'a>(
+//!     key: &VerifyingKey,
+//!     header: impl Fn(&str) -> Option<&'a str>,
+//!     body: &[u8],
+//! ) -> u16 {
+//!     let (Some(signature), Some(timestamp)) = (header(SIGNATURE_HEADER), header(TIMESTAMP_HEADER))
+//!     else {
+//!         return 401;
+//!     };
+//!     if key.verify(signature, timestamp, body).is_err() {
+//!         return 401;
+//!     }
+//!     // The body can now be parsed, e.g. into an `Interaction`.
+//!     200
+//! }
+//!
+//! // Parse the public key from the developer portal once, at startup.
+//! # let public_key = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+//! let key: VerifyingKey = public_key.parse().unwrap();
+//!
+//! // Then, for every request:
+//! assert_eq!(handle(&key, |_| None, b"{}"), 401);
+//! ```
+
+use std::{error::Error, fmt, str::FromStr};
+
+use ed25519_dalek::Signature;
+
+/// The header holding the hex-encoded signature of a request.
+pub const SIGNATURE_HEADER: &str = "X-Signature-Ed25519";
+/// The header holding the timestamp covered by the signature of a request.
+pub const TIMESTAMP_HEADER: &str = "X-Signature-Timestamp";
+
+/// The length of a public key, in bytes.
+const PUBLIC_KEY_LENGTH: usize = 32;
+/// The length of a signature, in bytes.
+const SIGNATURE_LENGTH: usize = 64;
+
+/// Represents an error encountered while verifying the signature of a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VerifyError {
+    /// The public key is not a hex-encoded Ed25519 public key.
+    InvalidPublicKey,
+    /// The signature is not a hex-encoded Ed25519 signature.
+    InvalidSignature,
+    /// The signature does not match the timestamp and body.
+    Mismatch,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::InvalidPublicKey => {
+                write!(f, "the public key must be 64 hex digits")
+            }
+            VerifyError::InvalidSignature => {
+                write!(f, "the signature must be 128 hex digits")
+            }
+            VerifyError::Mismatch => write!(f, "the signature does not match the request"),
+        }
+    }
+}
+
+impl Error for VerifyError {}
+
+/// Represents the public key of an application, used to verify the requests Discord sends.
+///
+/// Keys are compared in constant time, so comparing them does not leak their contents.
+#[derive(Debug, Clone, Copy)]
+pub struct VerifyingKey(ed25519_dalek::VerifyingKey);
+
+impl VerifyingKey {
+    /// Parses a public key from its hex encoding, as shown in the developer portal.
+    ///
+    /// # Errors
+    /// Returns an error if `public_key` is not a hex-encoded Ed25519 public key.
+    pub fn from_hex(public_key: &str) -> Result<Self, VerifyError> {
+        let bytes = decode_hex::<PUBLIC_KEY_LENGTH>(public_key.trim())
+            .ok_or(VerifyError::InvalidPublicKey)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Creates a public key from its raw bytes.
+    ///
+    /// # Errors
+    /// Returns an error if `bytes` is not an Ed25519 public key.
+    pub fn from_bytes(bytes: &[u8; PUBLIC_KEY_LENGTH]) -> Result<Self, VerifyError> {
+        ed25519_dalek::VerifyingKey::from_bytes(bytes)
+            .map(VerifyingKey)
+            .map_err(|_| VerifyError::InvalidPublicKey)
+    }
+
+    /// Returns the raw bytes of the public key.
+    pub fn as_bytes(&self) -> &[u8; PUBLIC_KEY_LENGTH] {
+        self.0.as_bytes()
+    }
+
+    /// Verifies the signature of a request, given the values of its `X-Signature-Ed25519`
+    /// and `X-Signature-Timestamp` headers and its raw body.
+    ///
+    /// # Errors
+    /// Returns an error if the signature is malformed or does not match the timestamp and
+    /// body, in which case the request must be rejected.
+    pub fn verify(&self, signature: &str, timestamp: &str, body: &[u8]) -> Result<(), VerifyError> {
+        let signature = decode_hex::<SIGNATURE_LENGTH>(signature.trim())
+            .map(|bytes| Signature::from_bytes(&bytes))
+            .ok_or(VerifyError::InvalidSignature)?;
+
+        let mut message = Vec::with_capacity(timestamp.len() + body.len());
+        message.extend_from_slice(timestamp.as_bytes());
+        message.extend_from_slice(body);
+        self.0
+            .verify_strict(&message, &signature)
+            .map_err(|_| VerifyError::Mismatch)
+    }
+}
+
+impl FromStr for VerifyingKey {
+    type Err = VerifyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        VerifyingKey::from_hex(s)
+    }
+}
+
+impl PartialEq for VerifyingKey {
+    /// Compares the keys in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes()
+            .iter()
+            .zip(other.as_bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+    }
+}
+
+impl Eq for VerifyingKey {}
+
+/// Verifies the signature of a request with the hex-encoded public key of the application.
+///
+/// This parses the public key on every call. To verify many requests, parse it once into a
+/// [`VerifyingKey`] instead.
+///
+/// # Errors
+/// Returns an error if the public key or signature is malformed, or if the signature does
+/// not match the timestamp and body.
+pub fn verify_signature(
+    public_key: &str,
+    signature: &str,
+    timestamp: &str,
+    body: &[u8],
+) -> Result<(), VerifyError> {
+    VerifyingKey::from_hex(public_key)?.verify(signature, timestamp, body)
+}
+
+/// Decodes exactly `N` bytes from a hex string, returning `None` if it is malformed or has
+/// a different length.
+fn decode_hex<const N: usize>(hex: &str) -> Option<[u8; N]> {
+    let hex = hex.as_bytes();
+    if hex.len() != N * 2 {
+        return None;
+    }
+    let digit = |c: u8| (c as char).to_digit(16).map(|d| d as u8);
+    let mut bytes = [0; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = digit(pair[0])? << 4 | digit(pair[1])?;
+    }
+    Some(bytes)
+}
@@ -0,0 +1,136 @@
+//! A minimal HTTP server standing in for the Discord API in tests.
+
+#![allow(dead_code)]
+
+use std::sync::{Arc, Mutex};
+
+use serde_discord::http::{HttpClient, HttpClientBuilder, RetryPolicy};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// A request received by the fake server.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    /// The path of the request, including its query string.
+    pub target: String,
+    pub body: String,
+}
+
+type Handler = dyn Fn(&Request) -> (u16, String) + Send + Sync;
+
+/// A fake Discord API answering every request with `handler`.
+pub struct FakeDiscord {
+    base_url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl FakeDiscord {
+    /// Starts a server on a random local port.
+    pub async fn start(
+        handler: impl Fn(&Request) -> (u16, String) + Send + Sync + 'static,
+    ) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/api", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let received = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let received = received.clone();
+                let handler = handler.clone();
+                tokio::spawn(async move { serve(stream, &received, &*handler).await });
+            }
+        });
+
+        Self { base_url, requests }
+    }
+
+    /// Starts a server on a random local port, served by a runtime on a background thread.
+    ///
+    /// This is for testing blocking clients, which must not be used within a runtime.
+    pub fn start_in_background(
+        handler: impl Fn(&Request) -> (u16, String) + Send + Sync + 'static,
+    ) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let server = runtime.block_on(Self::start(handler));
+        std::thread::spawn(move || runtime.block_on(std::future::pending::<()>()));
+        server
+    }
+
+    /// Returns a builder for HTTP clients sending their requests to the server, without
+    /// retries.
+    pub fn builder(&self) -> HttpClientBuilder {
+        HttpClientBuilder::new()
+            .base_url(self.base_url.clone())
+            .retry_policy(RetryPolicy::never())
+    }
+
+    /// Returns an HTTP client sending its requests to the server, without retries.
+    pub fn http(&self) -> HttpClient {
+        self.builder().build().unwrap()
+    }
+
+    /// Returns the requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Reads a single request from `stream` and writes the response of `handler`.
+async fn serve(mut stream: TcpStream, received: &Mutex<Vec<Request>>, handler: &Handler) {
+    let mut buf = Vec::new();
+    let header_end = loop {
+        let mut chunk = [0; 4096];
+        let n = stream.read(&mut chunk).await.unwrap();
+        if n == 0 {
+            return;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let content_length = head
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("content-length")
+                .then(|| value.trim().parse::<usize>().ok())?
+        })
+        .unwrap_or(0);
+    while buf.len() < header_end + content_length {
+        let mut chunk = [0; 4096];
+        let n = stream.read(&mut chunk).await.unwrap();
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let mut request_line = head.lines().next().unwrap_or_default().split(' ');
+    let request = Request {
+        method: request_line.next().unwrap_or_default().to_string(),
+        target: request_line.next().unwrap_or_default().to_string(),
+        body: String::from_utf8_lossy(&buf[header_end..]).to_string(),
+    };
+    let (status, body) = handler(&request);
+    received.lock().unwrap().push(request);
+
+    let response = format!(
+        "HTTP/1.1 {} Fake\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await.unwrap();
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use serde_discord::{
+    command::{Choices, ExtractError, OptionValue, SlashCommand},
+    register::ChoiceValue,
+    types::{CommandOptionKind, MultiTypeValue},
+};
+
+#[derive(Debug, PartialEq, Choices)]
+enum Coin {
+    Heads,
+    #[choice(name = "Tails!", value = "t")]
+    Tails,
+    TwoHeaded,
+}
+
+/// Flip some coins
+#[derive(Debug, SlashCommand)]
+struct FlipCoins {
+    /// The side to bet on
+    side: Coin,
+    /// How many coins to flip
+    #[option(min_value = 1, max_value = 10)]
+    count: Option<i64>,
+    #[option(name = "note", description = "A note", max_length = 20)]
+    text: Option<String>,
+}
+
+#[test]
+fn choices_round_trip_through_choice_value() {
+    let choices = <Coin as Choices>::choices();
+    let names: Vec<&str> = choices.iter().map(|choice| choice.name()).collect();
+    assert_eq!(names, ["Heads", "Tails!", "TwoHeaded"]);
+
+    for (choice, variant) in choices
+        .iter()
+        .zip([Coin::Heads, Coin::Tails, Coin::TwoHeaded])
+    {
+        let ChoiceValue::String(value) = choice.value() else {
+            panic!("expected a string choice, got {:?}", choice.value());
+        };
+        let value = value.as_str();
+        assert_eq!(variant.choice_value(), value);
+        assert_eq!(Coin::from_choice_value(value), Some(variant));
+        let received = MultiTypeValue::String(value.to_string());
+        assert_eq!(
+            <Coin as OptionValue>::from_value(&received).map(|coin| coin.choice_value()),
+            Some(value)
+        );
+    }
+
+    assert_eq!(Coin::from_choice_value("Tails!"), None);
+    assert_eq!(
+        <Coin as OptionValue>::from_value(&MultiTypeValue::Integer(1)),
+        None
+    );
+}
+
+#[test]
+fn slash_command_is_defined_from_fields() {
+    assert_eq!(FlipCoins::NAME, "flip_coins");
+    let cmd = FlipCoins::command().unwrap();
+    assert_eq!(cmd.description(), "Flip some coins");
+
+    let options = cmd.options().unwrap();
+    let names: Vec<&str> = options.iter().map(|opt| opt.name()).collect();
+    assert_eq!(names, ["side", "count", "note"]);
+    assert_eq!(options[0].kind(), &CommandOptionKind::String);
+    assert!(options[0].required());
+    assert_eq!(options[0].choices().unwrap().len(), 3);
+    assert!(!options[1].required());
+    assert_eq!(options[1].description(), "How many coins to flip");
+    assert_eq!(options[1].max_value(), Some(10.into()));
+    assert_eq!(options[2].description(), "A note");
+    assert_eq!(options[2].max_length(), Some(20));
+}
+
+#[test]
+fn slash_command_is_extracted_from_options() {
+    let side = MultiTypeValue::String("t".to_string());
+    let count = MultiTypeValue::Integer(3);
+    let options = HashMap::from([("side", &side), ("count", &count)]);
+    let flip = FlipCoins::from_options(&options).unwrap();
+    assert_eq!(flip.side, Coin::Tails);
+    assert_eq!(flip.count, Some(3));
+    assert_eq!(flip.text, None);
+
+    let options = HashMap::from([("count", &count)]);
+    assert!(matches!(
+        FlipCoins::from_options(&options),
+        Err(ExtractError::MissingOption { name }) if name == "side"
+    ));
+
+    let options = HashMap::from([("side", &count)]);
+    assert!(matches!(
+        FlipCoins::from_options(&options),
+        Err(ExtractError::InvalidType { name, .. }) if name == "side"
+    ));
+}
+
+#[test]
+fn derive_macros_report_invalid_input() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/pass/*.rs");
+    cases.compile_fail("tests/ui/fail/*.rs");
+}
@@ -0,0 +1,68 @@
+use serde_discord::response::pagination::{Page, PaginationError, Paginator};
+use serde_json::Value;
+
+fn paginator() -> Paginator {
+    Paginator::new(
+        "help",
+        vec![
+            Page::new().content("one"),
+            Page::new().content("two"),
+            Page::new().content("three"),
+        ],
+    )
+}
+
+/// Returns the custom IDs and disabled states of the navigation buttons of `msg`.
+fn buttons(msg: &Value) -> Vec<(String, bool)> {
+    msg["components"][0]["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|button| {
+            (
+                button["custom_id"].as_str().unwrap().to_string(),
+                button["disabled"].as_bool().unwrap_or(false),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn buttons_navigate_between_pages() {
+    let paginator = paginator();
+    let first = serde_json::to_value(paginator.message().unwrap()).unwrap();
+    let disabled: Vec<bool> = buttons(&first).into_iter().map(|(_, d)| d).collect();
+    assert_eq!(disabled, [true, true, true, false, false]);
+
+    let (last, _) = &buttons(&first)[4];
+    let response = serde_json::to_value(paginator.update(last).unwrap()).unwrap();
+    assert_eq!(response["type"], 7);
+    assert_eq!(response["data"]["content"], "three");
+    assert_eq!(response["data"]["embeds"], Value::Array(vec![]));
+    let disabled: Vec<bool> = buttons(&response["data"])
+        .into_iter()
+        .map(|(_, d)| d)
+        .collect();
+    assert_eq!(disabled, [false, false, true, true, true]);
+}
+
+#[test]
+fn foreign_buttons_are_reported() {
+    let paginator = paginator();
+    let other = Paginator::new("other", vec![Page::new().content("x")]);
+    let other_msg = serde_json::to_value(other.message().unwrap()).unwrap();
+    let (custom_id, _) = &buttons(&other_msg)[0];
+
+    assert!(matches!(
+        paginator.update(custom_id),
+        Err(PaginationError::OtherPaginator)
+    ));
+    assert!(matches!(
+        paginator.update("vote:1"),
+        Err(PaginationError::OtherPaginator)
+    ));
+    assert!(matches!(
+        Paginator::new("help", vec![]).message(),
+        Err(PaginationError::NoPages)
+    ));
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::FakeDiscord;
+use serde_discord::register::{
+    blocking::{get_commands_with, register_commands_with},
+    CommandBuilder, RegistrationError,
+};
+use serde_discord::types::CommandKind;
+
+const COMMANDS: &str = r#"[{
+    "id": "1", "application_id": "2", "version": "3",
+    "type": 1, "name": "roll", "description": "Roll a die"
+}]"#;
+
+#[test]
+fn blocking_client_uses_the_builder_settings() {
+    let discord = FakeDiscord::start_in_background(|_| (200, COMMANDS.to_string()));
+    let http = discord
+        .builder()
+        .api_version("v9")
+        .build_blocking()
+        .unwrap();
+    assert_eq!(http.api_version(), "v9");
+
+    let cmds = get_commands_with(&http, "2", "token").unwrap();
+    assert_eq!(cmds[0].name(), "roll");
+
+    let roll = CommandBuilder::new()
+        .name("roll")
+        .kind(CommandKind::ChatInput)
+        .description("Roll a die")
+        .build()
+        .unwrap();
+    let outcome = register_commands_with(&http, "2", "token", [roll]).unwrap();
+    assert_eq!(outcome.commands().len(), 1);
+
+    let requests = discord.requests();
+    assert_eq!(
+        requests[0].target,
+        "/api/v9/applications/2/commands?with_localizations=true"
+    );
+    assert_eq!(requests[1].method, "PUT");
+    assert_eq!(requests[1].target, "/api/v9/applications/2/commands");
+}
+
+#[test]
+fn blocking_client_reports_discord_errors() {
+    let discord = FakeDiscord::start_in_background(|request| match request.method.as_str() {
+        "GET" => (
+            429,
+            r#"{"message": "slow down", "retry_after": 1}"#.to_string(),
+        ),
+        _ => (
+            400,
+            r#"{"code": 50035, "message": "Invalid Form Body",
+                "errors": {"0": {"name": {"_errors": [
+                    {"code": "BASE_TYPE_MAX_LENGTH", "message": "Too long"}
+                ]}}}}"#
+                .to_string(),
+        ),
+    });
+    let http = discord.builder().build_blocking().unwrap();
+
+    assert!(matches!(
+        get_commands_with(&http, "2", "token"),
+        Err(RegistrationError::RateLimited { .. })
+    ));
+
+    let roll = CommandBuilder::new()
+        .name("roll")
+        .kind(CommandKind::ChatInput)
+        .description("Roll a die")
+        .build()
+        .unwrap();
+    match register_commands_with(&http, "2", "token", [roll]) {
+        Err(RegistrationError::Api(err)) => {
+            assert_eq!(err.code(), Some(50035));
+            assert_eq!(err.field_errors()[0].path(), "0.name");
+        }
+        Err(err) => panic!("expected an API error, got {:?}", err),
+        Ok(_) => panic!("expected an API error"),
+    }
+}
@@ -0,0 +1,183 @@
+mod common;
+
+use common::FakeDiscord;
+use serde_discord::{
+    register::{
+        register_commands_in_guilds, register_guild_commands_with, sync_commands_dry_run_with,
+        sync_commands_with, ChangeKind, Command, CommandBuilder, CommandOptionBuilder,
+        CommandRegistry, RegistrationError,
+    },
+    types::{ApplicationIntegrationType, CommandKind, CommandOptionKind, Locale},
+};
+use serde_json::json;
+
+/// Answers like Discord does for an application with a single localized `roll` command that
+/// was registered without `integration_types`, `contexts`, or permissions: the defaults
+/// Discord filled in are returned, and localizations are only included when requested with
+/// `with_localizations`.
+fn discord(request: &common::Request) -> (u16, String) {
+    if request.method != "GET" {
+        return (200, "{}".to_string());
+    }
+    let mut cmd = json!({
+        "id": "1",
+        "application_id": "2",
+        "version": "3",
+        "default_member_permissions": null,
+        "type": 1,
+        "name": "roll",
+        "description": "Roll a die",
+        "dm_permission": true,
+        "contexts": [0, 1, 2],
+        "integration_types": [0],
+        "nsfw": false,
+        "options": [{
+            "type": 4,
+            "name": "sides",
+            "description": "The number of sides",
+            "required": false,
+            "min_value": 2,
+        }],
+    });
+    if request.target.contains("with_localizations=true") {
+        cmd["name_localizations"] = json!({ "de": "würfeln" });
+        cmd["description_localizations"] = json!({ "de": "Würfle einen Würfel" });
+        cmd["options"][0]["name_localizations"] = json!({});
+        cmd["options"][0]["description_localizations"] = json!({});
+    }
+    (200, json!([cmd]).to_string())
+}
+
+fn localized_command() -> CommandBuilder {
+    CommandBuilder::new()
+        .name("roll")
+        .name_localized(Locale::German, "würfeln")
+        .kind(CommandKind::ChatInput)
+        .description("Roll a die")
+        .description_localized(Locale::German, "Würfle einen Würfel")
+        .option(
+            CommandOptionBuilder::new()
+                .kind(CommandOptionKind::Integer)
+                .name("sides")
+                .description("The number of sides")
+                .min_value(2)
+                .build()
+                .unwrap(),
+        )
+}
+
+#[tokio::test]
+async fn localized_commands_are_unchanged_after_sync() {
+    let discord = FakeDiscord::start(discord).await;
+    let cmds = [localized_command().build().unwrap()];
+
+    let report = sync_commands_dry_run_with(&discord.http(), "2", "token", &cmds)
+        .await
+        .unwrap();
+    assert!(!report.has_changes(), "{}", report);
+    assert_eq!(
+        report.to_string(),
+        "0 to create, 0 to update, 0 to delete, 1 unchanged"
+    );
+
+    let summary = sync_commands_with(&discord.http(), "2", "token", &cmds)
+        .await
+        .unwrap();
+    assert!(!summary.has_changes(), "{:?}", summary);
+
+    let requests = discord.requests();
+    assert!(requests.iter().all(|request| request.method == "GET"));
+    assert!(requests
+        .iter()
+        .all(|request| request.target.contains("with_localizations=true")));
+}
+
+#[tokio::test]
+async fn fields_set_locally_are_compared_with_the_remote_defaults() {
+    let discord = FakeDiscord::start(discord).await;
+    let cmds = [localized_command()
+        .integration_types(vec![
+            ApplicationIntegrationType::GuildInstall,
+            ApplicationIntegrationType::UserInstall,
+        ])
+        .build()
+        .unwrap()];
+
+    let report = sync_commands_dry_run_with(&discord.http(), "2", "token", &cmds)
+        .await
+        .unwrap();
+    assert_eq!(
+        report.to_string(),
+        "~ update `roll`\n    integration_types.1: (unset) -> 1\n\
+         0 to create, 1 to update, 0 to delete, 0 unchanged"
+    );
+
+    let summary = sync_commands_with(&discord.http(), "2", "token", &cmds)
+        .await
+        .unwrap();
+    assert_eq!(summary.updated(), ["roll"]);
+}
+
+#[tokio::test]
+async fn changed_options_are_reported() {
+    let discord = FakeDiscord::start(discord).await;
+    let sides = CommandOptionBuilder::new()
+        .kind(CommandOptionKind::Integer)
+        .name("sides")
+        .description("The number of sides")
+        .build()
+        .unwrap();
+    let cmds = [localized_command().options(vec![sides]).build().unwrap()];
+
+    let report = sync_commands_dry_run_with(&discord.http(), "2", "token", &cmds)
+        .await
+        .unwrap();
+    let update = report.changes(ChangeKind::Update).next().unwrap();
+    let fields: Vec<String> = update.fields().iter().map(|f| f.to_string()).collect();
+    assert_eq!(fields, ["options.0.min_value: 2 -> (unset)"]);
+}
+
+/// Returns more user commands than Discord allows.
+fn too_many_user_commands() -> Vec<Command> {
+    (0..6)
+        .map(|i| {
+            CommandBuilder::new()
+                .name(format!("User {}", i))
+                .kind(CommandKind::User)
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+fn is_invalid_command<T>(result: Result<T, RegistrationError>) -> bool {
+    matches!(result, Err(RegistrationError::InvalidCommand(_)))
+}
+
+#[tokio::test]
+async fn every_write_path_validates_commands() {
+    let discord = FakeDiscord::start(|_| (200, "[]".to_string())).await;
+    let http = discord.http();
+    let cmds = too_many_user_commands();
+
+    assert!(is_invalid_command(
+        register_guild_commands_with(&http, "2", "token", "3", cmds.clone()).await
+    ));
+    assert!(is_invalid_command(
+        sync_commands_with(&http, "2", "token", &cmds).await
+    ));
+    assert!(is_invalid_command(
+        sync_commands_dry_run_with(&http, "2", "token", &cmds).await
+    ));
+
+    let registry = CommandRegistry::with_http(http.clone(), "2", "token");
+    assert!(is_invalid_command(
+        registry.register_guild("3", cmds.clone()).await
+    ));
+    assert!(is_invalid_command(registry.sync(&cmds).await));
+
+    let report = register_commands_in_guilds(&http, "2", "token", ["3", "4"], &cmds, 2).await;
+    assert_eq!(report.failed().len(), 2);
+
+    assert!(discord.requests().is_empty());
+}
@@ -0,0 +1,144 @@
+use serde_discord::{
+    register::{
+        ChoiceValue, Command, CommandBuilder, CommandOption, CommandOptionBuilder,
+        CommandOptionChoiceBuilder,
+    },
+    types::{
+        ChannelType, CommandKind, CommandOptionKind, InteractionContextType, Locale, Permissions,
+    },
+};
+use serde_json::json;
+
+/// Serializes `value` to JSON and deserializes it back.
+fn roundtrip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn command_roundtrips() {
+    let cmd = CommandBuilder::new()
+        .name("roll")
+        .name_localized(Locale::German, "würfeln")
+        .kind(CommandKind::ChatInput)
+        .description("Roll a die")
+        .contexts(vec![InteractionContextType::Guild])
+        .default_member_permissions(Permissions::SEND_MESSAGES)
+        .option(
+            CommandOptionBuilder::new()
+                .kind(CommandOptionKind::Integer)
+                .name("sides")
+                .description("The number of sides")
+                .required(true)
+                .min_value(2)
+                .max_value(100)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(roundtrip(&cmd), cmd);
+}
+
+#[test]
+fn option_with_choices_roundtrips() {
+    let opt = CommandOptionBuilder::new()
+        .kind(CommandOptionKind::String)
+        .name("coin")
+        .description("Pick a side")
+        .min_length(1)
+        .max_length(10)
+        .choice(
+            CommandOptionChoiceBuilder::new()
+                .name("Heads")
+                .value(ChoiceValue::String("heads".into()))
+                .build()
+                .unwrap(),
+        )
+        .choice(
+            CommandOptionChoiceBuilder::new()
+                .name("Tails")
+                .name_localized(Locale::French, "Pile")
+                .value(ChoiceValue::String("tails".into()))
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(roundtrip(&opt), opt);
+}
+
+#[test]
+fn channel_option_roundtrips() {
+    let opt = CommandOptionBuilder::new()
+        .kind(CommandOptionKind::Channel)
+        .name("channel")
+        .description("A text channel")
+        .channel_types(vec![ChannelType::GuildText, ChannelType::GuildAnnouncement])
+        .build()
+        .unwrap();
+
+    assert_eq!(roundtrip(&opt), opt);
+}
+
+#[test]
+fn numeric_choice_values_roundtrip() {
+    for value in [ChoiceValue::Int(-3), ChoiceValue::Float(2.5)] {
+        let choice = CommandOptionChoiceBuilder::new()
+            .name("value")
+            .value(value)
+            .build()
+            .unwrap();
+        assert_eq!(roundtrip(&choice), choice);
+    }
+}
+
+#[test]
+fn deserializes_discord_response() {
+    let cmd: Command = serde_json::from_value(json!({
+        "id": "1234567890",
+        "application_id": "987654321",
+        "version": "1",
+        "default_member_permissions": null,
+        "type": 1,
+        "name": "config",
+        "description": "Manage the configuration",
+        "dm_permission": true,
+        "nsfw": false,
+        "options": [{
+            "type": 1,
+            "name": "show",
+            "description": "Show the configuration",
+            "options": [{
+                "type": 5,
+                "name": "verbose",
+                "description": "Show everything",
+                "required": false
+            }]
+        }]
+    }))
+    .unwrap();
+
+    assert_eq!(cmd.name(), "config");
+    assert_eq!(*cmd.kind(), CommandKind::ChatInput);
+    let subcommand: &CommandOption = &cmd.options().unwrap()[0];
+    assert_eq!(*subcommand.kind(), CommandOptionKind::SubCommand);
+    assert_eq!(subcommand.options().unwrap()[0].name(), "verbose");
+}
+
+#[test]
+fn command_kind_defaults_to_chat_input() {
+    let cmd: Command = serde_json::from_value(json!({
+        "name": "ping",
+        "description": "Pong"
+    }))
+    .unwrap();
+
+    assert_eq!(*cmd.kind(), CommandKind::ChatInput);
+    assert_eq!(roundtrip(&cmd), cmd);
+}
@@ -0,0 +1,208 @@
+use serde_discord::response::data::{
+    ActionRowBuilder, AllowedMentionsBuilder, ButtonComponentBuilder, ButtonStyle, EmbedBuilder,
+    EmbedField, LabelBuilder, Message, MessageBuilder, MessageComponent, MessageFlags, PollAnswer,
+    PollBuilder, SelectDefaultValue, SelectMenuBuilder, SelectMenuKind, SelectMenuOptionBuilder,
+    TextInputBuilder, TextInputStyle,
+};
+use serde_discord::types::{Emoji, Snowflake};
+use serde_json::{json, Value};
+
+/// Serializes `value` to JSON, deserializes it back, and returns both serializations.
+fn roundtrip<T>(value: &T) -> (Value, Value)
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_value(value).unwrap();
+    let back: T = serde_json::from_value(json.clone()).unwrap();
+    (json, serde_json::to_value(&back).unwrap())
+}
+
+fn button(custom_id: &str) -> MessageComponent {
+    MessageComponent::Button(
+        ButtonComponentBuilder::new()
+            .style(ButtonStyle::Primary)
+            .label("Click")
+            .custom_id(custom_id.to_string())
+            .build()
+            .unwrap(),
+    )
+}
+
+#[test]
+fn message_roundtrips() {
+    let row = ActionRowBuilder::new()
+        .component(button("a"))
+        .component(button("b"))
+        .build()
+        .unwrap();
+    let msg = MessageBuilder::new()
+        .content("Hello")
+        .embed(
+            EmbedBuilder::new()
+                .title("Title")
+                .color(0x57F287)
+                .field(EmbedField::new("Name", "Value").inline(true))
+                .build()
+                .unwrap(),
+        )
+        .allowed_mentions(
+            AllowedMentionsBuilder::new()
+                .user(Snowflake::new(42))
+                .build()
+                .unwrap(),
+        )
+        .poll(
+            PollBuilder::new()
+                .question("Which?")
+                .answer(PollAnswer::new("This").emoji(Emoji::unicode("👍")))
+                .answer(PollAnswer::new("That"))
+                .build()
+                .unwrap(),
+        )
+        .component(MessageComponent::ActionRow(row))
+        .applied_tag(Snowflake::new(7))
+        .ephemeral()
+        .build()
+        .unwrap();
+
+    let (json, back) = roundtrip(&msg);
+    assert_eq!(json, back);
+}
+
+#[test]
+fn button_emoji_roundtrips() {
+    let custom = ButtonComponentBuilder::new()
+        .style(ButtonStyle::Secondary)
+        .emoji(Emoji::custom(Snowflake::new(5), "party").animated(true))
+        .custom_id("party")
+        .build()
+        .unwrap();
+    let (json, back) = roundtrip(&MessageComponent::Button(custom));
+    assert_eq!(
+        json["emoji"],
+        json!({ "id": "5", "name": "party", "animated": true })
+    );
+    assert_eq!(json, back);
+
+    let unicode = ButtonComponentBuilder::new()
+        .style(ButtonStyle::Secondary)
+        .emoji(Emoji::unicode("🎲"))
+        .custom_id("roll")
+        .build()
+        .unwrap();
+    let json = serde_json::to_value(MessageComponent::Button(unicode)).unwrap();
+    assert_eq!(json["emoji"], json!({ "name": "🎲" }));
+
+    let deleted: Emoji = serde_json::from_value(json!({ "id": "5", "name": null })).unwrap();
+    assert!(deleted.is_custom());
+    assert_eq!(deleted.name(), None);
+}
+
+#[test]
+fn select_menus_roundtrip() {
+    let string_select = SelectMenuBuilder::new()
+        .custom_id("color")
+        .option(
+            SelectMenuOptionBuilder::new()
+                .label("Red")
+                .value("red")
+                .emoji(Emoji::custom(Snowflake::new(1), "red"))
+                .default(true)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let user_select = SelectMenuBuilder::new()
+        .kind(SelectMenuKind::User)
+        .custom_id("user")
+        .default_value(SelectDefaultValue::user(Snowflake::new(5)))
+        .build()
+        .unwrap();
+
+    for select in [string_select, user_select] {
+        let (json, back) = roundtrip(&MessageComponent::SelectMenu(select));
+        assert_eq!(json, back);
+    }
+}
+
+#[test]
+fn text_input_roundtrips() {
+    let input = TextInputBuilder::new()
+        .custom_id("feedback")
+        .style(TextInputStyle::Paragraph)
+        .label("Feedback")
+        .build()
+        .unwrap();
+
+    let (json, back) = roundtrip(&MessageComponent::TextInput(input));
+    assert_eq!(json["type"], 4);
+    assert_eq!(json, back);
+}
+
+#[test]
+fn label_roundtrips_and_is_rejected_in_messages() {
+    let input = TextInputBuilder::new()
+        .custom_id("feedback")
+        .style(TextInputStyle::Short)
+        .build()
+        .unwrap();
+    let label = LabelBuilder::new()
+        .label("Feedback")
+        .description("Tell us what you think")
+        .component(MessageComponent::TextInput(input))
+        .build()
+        .unwrap();
+
+    let (json, back) = roundtrip(&MessageComponent::Label(label));
+    assert_eq!(json["type"], 18);
+    assert!(json["component"].get("label").is_none());
+    assert_eq!(json, back);
+
+    let label: MessageComponent = serde_json::from_value(json).unwrap();
+    let result = MessageBuilder::new()
+        .component(label)
+        .flags(MessageFlags::IS_COMPONENTS_V2)
+        .build();
+    assert!(result.is_err_and(|err| err.to_string().contains("modal")));
+}
+
+#[test]
+fn message_sent_by_discord_deserializes() {
+    let msg: Message = serde_json::from_value(json!({
+        "id": "1",
+        "channel_id": "2",
+        "content": "Pick one",
+        "flags": 64,
+        "attachments": [{ "id": "3", "filename": "a.png", "url": "https://cdn" }],
+        "components": [{
+            "type": 1,
+            "id": 1,
+            "components": [{
+                "type": 2,
+                "id": 2,
+                "style": 6,
+                "sku_id": "99"
+            }]
+        }]
+    }))
+    .unwrap();
+
+    assert_eq!(msg.content().as_deref(), Some("Pick one"));
+    assert_eq!(
+        msg.flags().as_ref().map(MessageFlags::bits),
+        Some(MessageFlags::EPHEMERAL.bits())
+    );
+    assert!(msg.files().is_empty());
+    let components = msg.components().as_ref().unwrap();
+    let MessageComponent::ActionRow(row) = &components[0] else {
+        panic!("expected an action row");
+    };
+    assert!(matches!(row.components()[0], MessageComponent::Button(_)));
+}
+
+#[test]
+fn unknown_component_type_is_rejected() {
+    let result = serde_json::from_value::<MessageComponent>(json!({ "type": 99 }));
+    assert!(result.is_err_and(|err| err.to_string().contains("99")));
+}
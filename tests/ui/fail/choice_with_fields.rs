@@ -0,0 +1,9 @@
+use serde_discord::command::Choices;
+
+#[derive(Choices)]
+enum Coin {
+    Heads,
+    Tails(u8),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_discord::command::Choices;
+
+#[derive(Choices)]
+enum Coin {
+    #[choice(name = "")]
+    Heads,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_discord::command::SlashCommand;
+
+/// Roll a die
+#[derive(SlashCommand)]
+struct Roll {
+    sides: i64,
+}
+
+fn main() {}
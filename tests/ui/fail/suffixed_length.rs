@@ -0,0 +1,11 @@
+use serde_discord::command::SlashCommand;
+
+/// Say something
+#[derive(SlashCommand)]
+struct Say {
+    /// The message
+    #[option(max_length = 100u8)]
+    message: String,
+}
+
+fn main() {}
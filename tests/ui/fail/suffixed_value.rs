@@ -0,0 +1,11 @@
+use serde_discord::command::SlashCommand;
+
+/// Roll a die
+#[derive(SlashCommand)]
+struct Roll {
+    /// The number of sides
+    #[option(min_value = 2i32)]
+    sides: i64,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use serde_discord::command::SlashCommand;
+
+/// Roll a die
+#[derive(SlashCommand)]
+struct Roll(i64);
+
+fn main() {}
@@ -0,0 +1,31 @@
+use serde_discord::command::{Choices, SlashCommand};
+
+#[derive(Choices)]
+enum Unit {
+    Metric,
+    #[choice(name = "Imperial units", value = "imperial")]
+    Imperial,
+}
+
+/// Convert a temperature
+#[derive(SlashCommand)]
+#[command(name = "convert")]
+struct Temperature {
+    /// The temperature to convert
+    #[option(min_value = -273.15, max_value = 1000)]
+    degrees: f64,
+    /// The unit to convert to
+    unit: Unit,
+    /// A label for the result
+    #[option(min_length = 1, max_length = 100, autocomplete)]
+    label: Option<String>,
+}
+
+/// Ping the bot
+#[derive(SlashCommand)]
+struct Ping;
+
+fn main() {
+    Temperature::command().unwrap();
+    Ping::command().unwrap();
+}
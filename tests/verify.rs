@@ -0,0 +1,69 @@
+use ed25519_dalek::{Signer, SigningKey};
+use serde_discord::verify::{verify_signature, VerifyError, VerifyingKey};
+
+const TIMESTAMP: &str = "1700000000";
+const BODY: &[u8] = br#"{"type":1}"#;
+
+fn signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7; 32])
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Returns the hex-encoded public key and the signature of `TIMESTAMP` followed by `BODY`.
+fn signed_request() -> (String, String) {
+    let key = signing_key();
+    let message = [TIMESTAMP.as_bytes(), BODY].concat();
+    (
+        hex(key.verifying_key().as_bytes()),
+        hex(&key.sign(&message).to_bytes()),
+    )
+}
+
+#[test]
+fn valid_signature_is_accepted() {
+    let (public_key, signature) = signed_request();
+    assert_eq!(
+        verify_signature(&public_key, &signature, TIMESTAMP, BODY),
+        Ok(())
+    );
+
+    let key: VerifyingKey = public_key.to_uppercase().parse().unwrap();
+    assert_eq!(key.verify(&signature, TIMESTAMP, BODY), Ok(()));
+    assert_eq!(key, VerifyingKey::from_hex(&public_key).unwrap());
+}
+
+#[test]
+fn tampered_requests_are_rejected() {
+    let (public_key, signature) = signed_request();
+    let key = VerifyingKey::from_hex(&public_key).unwrap();
+
+    assert_eq!(
+        key.verify(&signature, "1700000001", BODY),
+        Err(VerifyError::Mismatch)
+    );
+    assert_eq!(
+        key.verify(&signature, TIMESTAMP, br#"{"type":2}"#),
+        Err(VerifyError::Mismatch)
+    );
+    assert_eq!(
+        key.verify(&signature[2..], TIMESTAMP, BODY),
+        Err(VerifyError::InvalidSignature)
+    );
+    assert_eq!(
+        key.verify(&"zz".repeat(64), TIMESTAMP, BODY),
+        Err(VerifyError::InvalidSignature)
+    );
+}
+
+#[test]
+fn malformed_public_key_is_rejected() {
+    let (_, signature) = signed_request();
+    assert_eq!(
+        verify_signature("abcd", &signature, TIMESTAMP, BODY),
+        Err(VerifyError::InvalidPublicKey)
+    );
+    assert!(VerifyingKey::from_hex(&"g".repeat(64)).is_err());
+}